@@ -10,7 +10,9 @@ pkgs.mkShell {
     jq
     poppler_utils
     unar
-    ffmpegthumbnailer
+    ffmpeg
+    imagemagick
+    exiftool
     fd
     ripgrep
     fzf
@@ -15,8 +15,12 @@
 , poppler_utils
 , withUnar ? true
 , unar
-, withFfmpegthumbnailer ? true
-, ffmpegthumbnailer
+, withFfmpeg ? true
+, ffmpeg
+, withImagemagick ? true
+, imagemagick
+, withExiftool ? true
+, exiftool
 , withFd ? true
 , fd
 , withRipgrep ? true
@@ -48,7 +52,9 @@ rustPlatform.buildRustPackage {
         ++ optional withJq jq
         ++ optional withPoppler poppler_utils
         ++ optional withUnar unar
-        ++ optional withFfmpegthumbnailer ffmpegthumbnailer
+        ++ optional withFfmpeg ffmpeg
+        ++ optional withImagemagick imagemagick
+        ++ optional withExiftool exiftool
         ++ optional withFd fd
         ++ optional withRipgrep ripgrep
         ++ optional withFzf fzf
@@ -1,8 +1,8 @@
-use std::{env, path::{Path, PathBuf}, sync::atomic::{AtomicBool, Ordering}};
+use std::{env, path::{Path, PathBuf}, str::FromStr, sync::atomic::{AtomicBool, Ordering}};
 
 use anyhow::{anyhow, Result};
 use ratatui::prelude::Rect;
-use tokio::{fs, sync::mpsc::UnboundedSender};
+use tokio::sync::mpsc::UnboundedSender;
 use tracing::warn;
 use yazi_config::PREVIEW;
 use yazi_shared::{env_exists, RoCell};
@@ -25,10 +25,13 @@ pub enum Adaptor {
 	X11,
 	Wayland,
 	Chafa,
+
+	// Image preview disabled
+	None,
 }
 
-#[derive(Clone, Copy)]
-enum Emulator {
+#[derive(Clone, Copy, Debug)]
+pub(super) enum Emulator {
 	Unknown,
 	Kitty,
 	Konsole,
@@ -39,11 +42,12 @@ enum Emulator {
 	VSCode,
 	Hyper,
 	Mintty,
+	Mlterm,
 	Neovim,
 }
 
 impl Adaptor {
-	fn emulator() -> Emulator {
+	pub(super) fn emulator() -> Emulator {
 		if env_exists("NVIM_LOG_FILE") && env_exists("NVIM") {
 			return Emulator::Neovim;
 		}
@@ -54,6 +58,7 @@ impl Adaptor {
 			("ITERM_SESSION_ID", Emulator::Iterm2),
 			("WEZTERM_EXECUTABLE", Emulator::WezTerm),
 			("VSCODE_INJECTION", Emulator::VSCode),
+			("MLTERM", Emulator::Mlterm),
 		];
 		match vars.into_iter().find(|v| env_exists(v.0)) {
 			Some(var) => return var.1,
@@ -74,12 +79,38 @@ impl Adaptor {
 			"xterm-kitty" => return Emulator::Kitty,
 			"foot" => return Emulator::Foot,
 			"foot-extra" => return Emulator::Foot,
+			"mlterm" => return Emulator::Mlterm,
 			_ => warn!("[Adaptor] Unknown TERM: {term}"),
 		}
 		Emulator::Unknown
 	}
 
+	// The `YAZI_ADAPTOR` environment variable takes precedence over the config,
+	// so a misdetected stack can be worked around for a single session.
+	pub(super) fn forced() -> Option<(Self, &'static str)> {
+		if let Ok(s) = env::var("YAZI_ADAPTOR") {
+			match s.parse() {
+				Ok(adaptor) => return Some((adaptor, "YAZI_ADAPTOR")),
+				Err(_) if s.is_empty() || s == "auto" => {}
+				Err(_) => warn!("[Adaptor] Unknown adaptor in YAZI_ADAPTOR: {s}"),
+			}
+		}
+
+		match PREVIEW.adaptor.parse() {
+			Ok(adaptor) => Some((adaptor, "config")),
+			Err(_) if PREVIEW.adaptor == "auto" => None,
+			Err(_) => {
+				warn!("[Adaptor] Unknown adaptor in config: {}", PREVIEW.adaptor);
+				None
+			}
+		}
+	}
+
 	pub(super) fn detect() -> Self {
+		if let Some((adaptor, _)) = Self::forced() {
+			return adaptor;
+		}
+
 		let mut protocols = match Self::emulator() {
 			Emulator::Unknown => vec![],
 			Emulator::Kitty => vec![Self::Kitty],
@@ -91,6 +122,7 @@ impl Adaptor {
 			Emulator::VSCode => vec![Self::Sixel],
 			Emulator::Hyper => vec![Self::Sixel],
 			Emulator::Mintty => vec![Self::Iterm2],
+			Emulator::Mlterm => vec![Self::Sixel],
 			Emulator::Neovim => vec![],
 		};
 
@@ -153,22 +185,53 @@ impl ToString for Adaptor {
 			Self::X11 => "x11",
 			Self::Wayland => "wayland",
 			Self::Chafa => "chafa",
+			Self::None => "none",
 		}
 		.to_string()
 	}
 }
 
+impl FromStr for Adaptor {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(match s {
+			"kitty" => Self::Kitty,
+			"iterm2" => Self::Iterm2,
+			"sixel" => Self::Sixel,
+			"x11" => Self::X11,
+			"wayland" => Self::Wayland,
+			"chafa" => Self::Chafa,
+			"none" => Self::None,
+			_ => return Err(anyhow!("invalid adaptor: {s}")),
+		})
+	}
+}
+
 impl Adaptor {
 	pub(super) fn start(self) {
 		UEBERZUG.init(if self.needs_ueberzug() { Ueberzug::start(self).ok() } else { None });
 	}
 
-	pub async fn image_show(self, mut path: &Path, rect: Rect) -> Result<()> {
-		let cache = PREVIEW.cache(path, 0);
-		if fs::symlink_metadata(&cache).await.is_ok() {
-			path = cache.as_path();
+	#[inline]
+	pub fn stop(self) {
+		if self.needs_ueberzug() {
+			Ueberzug::stop();
 		}
+	}
+
+	// Whether images can actually be shown, otherwise the preview
+	// falls back to a textual description of them.
+	#[inline]
+	pub fn supported(self) -> bool {
+		match self {
+			Self::Kitty | Self::Iterm2 | Self::Sixel => true,
+			Self::None => false,
+			_ => UEBERZUG.is_some(),
+		}
+	}
 
+	pub async fn image_show(self, path: &Path, rect: Rect) -> Result<()> {
 		self.image_hide(rect).ok();
 		IMAGE_SHOWN.store(true, Ordering::Relaxed);
 
@@ -176,6 +239,7 @@ impl Adaptor {
 			Self::Kitty => Kitty::image_show(path, rect).await,
 			Self::Iterm2 => Iterm2::image_show(path, rect).await,
 			Self::Sixel => Sixel::image_show(path, rect).await,
+			Self::None => Ok(()),
 			_ => Ok(if let Some(tx) = &*UEBERZUG {
 				tx.send(Some((path.to_path_buf(), rect)))?;
 			}),
@@ -191,6 +255,7 @@ impl Adaptor {
 			Self::Kitty => Kitty::image_hide(rect),
 			Self::Iterm2 => Iterm2::image_hide(rect),
 			Self::Sixel => Sixel::image_hide(rect),
+			Self::None => Ok(()),
 			_ => Ok(if let Some(tx) = &*UEBERZUG {
 				tx.send(None)?;
 			}),
@@ -199,6 +264,6 @@ impl Adaptor {
 
 	#[inline]
 	pub(super) fn needs_ueberzug(self) -> bool {
-		!matches!(self, Self::Kitty | Self::Iterm2 | Self::Sixel)
+		!matches!(self, Self::Kitty | Self::Iterm2 | Self::Sixel | Self::None)
 	}
 }
@@ -0,0 +1,141 @@
+use std::{env, io::{stdout, Write}, time::{Duration, Instant}};
+
+use anyhow::Result;
+use image::{ImageFormat, Rgb, RgbImage};
+use ratatui::prelude::Rect;
+use yazi_shared::{env_exists, Term};
+
+use crate::{Adaptor, ADAPTOR, CLOSE, ESCAPE, START, TMUX};
+
+const PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+
+pub struct Diagnosis;
+
+impl Diagnosis {
+	// Everything that went into choosing the adaptor
+	pub fn report() -> Vec<(&'static str, String)> {
+		let env = |name: &str| env::var(name).unwrap_or_else(|_| "<unset>".to_owned());
+		let (term, program) = Adaptor::term_program();
+		let size = Term::size();
+
+		let mut report = vec![
+			("Adaptor", ADAPTOR.to_string()),
+			("Chosen by", Adaptor::forced().map_or("auto-detection", |(_, by)| by).to_owned()),
+			("Emulator", format!("{:?}", Adaptor::emulator())),
+			("TERM", term),
+			("TERM_PROGRAM", program),
+			("tmux", TMUX.to_string()),
+			("Zellij", env_exists("ZELLIJ_SESSION_NAME").to_string()),
+			("XDG_SESSION_TYPE", env("XDG_SESSION_TYPE")),
+			("WAYLAND_DISPLAY", env("WAYLAND_DISPLAY")),
+			("DISPLAY", env("DISPLAY")),
+			("Cells", format!("{}x{}", size.columns, size.rows)),
+			("Pixels", format!("{}x{}", size.width, size.height)),
+		];
+		if ADAPTOR.needs_ueberzug() {
+			let state = if ADAPTOR.supported() { "running" } else { "not found" };
+			report.push(("Überzug++", state.to_owned()));
+		}
+		report
+	}
+
+	// Ask the terminal itself which protocols it speaks. The terminal must be in
+	// raw mode, and nothing else may be reading from `stdin` in the meantime.
+	pub async fn probe() -> Vec<(&'static str, String)> {
+		{
+			let mut stdout = stdout().lock();
+			// Kitty graphics query, window size in pixels, cell size in pixels, and
+			// at last the primary device attributes, which every terminal answers.
+			write!(stdout, "{}_Gi=31,s=1,v=1,a=q,t=d,f=24;AAAA{}\\{}", START, ESCAPE, CLOSE).ok();
+			stdout.write_all(b"\x1b[14t\x1b[16t\x1b[c").ok();
+			stdout.flush().ok();
+		}
+
+		let resp = tokio::task::spawn_blocking(Self::read_until_da1).await.unwrap_or_default();
+		let s = String::from_utf8_lossy(&resp);
+
+		let kitty = s.find("\x1b_Gi=31;").map(|i| {
+			let s = &s[i + 8..];
+			s[..s.find('\x1b').unwrap_or(s.len())].to_owned()
+		});
+		let da1 = Self::csi(&s, "\x1b[?", 'c');
+
+		vec![
+			("Kitty graphics", kitty.unwrap_or_else(|| "no response".to_owned())),
+			("Sixel", match &da1 {
+				Some(da1) => da1.split(';').any(|p| p == "4").to_string(),
+				None => "unknown".to_owned(),
+			}),
+			("Window pixels", Self::csi(&s, "\x1b[4;", 't').map_or("no response".to_owned(), Self::size)),
+			("Cell pixels", Self::csi(&s, "\x1b[6;", 't').map_or("no response".to_owned(), Self::size)),
+			("Device attributes", da1.unwrap_or_else(|| "no response".to_owned())),
+			("Raw responses", s.escape_debug().to_string()),
+		]
+	}
+
+	// Draw a gradient with the current adaptor, to see whether it actually shows up
+	pub async fn test_image(rect: Rect) -> Result<()> {
+		let path = env::temp_dir().join("yazi-graphics-test.png");
+		let img = RgbImage::from_fn(256, 128, |x, y| Rgb([x as u8, (y * 2) as u8, 255 - x as u8]));
+		img.save_with_format(&path, ImageFormat::Png)?;
+
+		ADAPTOR.image_show(&path, rect).await
+	}
+
+	// `stdin` is waited on with a deadline before each read, rather than read
+	// right away, so no read is left behind after the timeout to swallow the
+	// next key meant for the app.
+	fn read_until_da1() -> Vec<u8> {
+		let mut resp = Vec::new();
+		let mut buf = [0; 256];
+		let deadline = Instant::now() + PROBE_TIMEOUT;
+
+		while Self::csi(&String::from_utf8_lossy(&resp), "\x1b[?", 'c').is_none() {
+			match Self::read_before(&mut buf, deadline) {
+				Some(n) if n > 0 => resp.extend_from_slice(&buf[..n]),
+				_ => break,
+			}
+		}
+		resp
+	}
+
+	#[cfg(unix)]
+	fn read_before(buf: &mut [u8], deadline: Instant) -> Option<usize> {
+		let timeout = deadline.saturating_duration_since(Instant::now()).as_millis() as i32;
+		let mut fd = libc::pollfd { fd: libc::STDIN_FILENO, events: libc::POLLIN, revents: 0 };
+		if unsafe { libc::poll(&mut fd, 1, timeout) } <= 0 {
+			return None;
+		}
+
+		let n = unsafe { libc::read(libc::STDIN_FILENO, buf.as_mut_ptr().cast(), buf.len()) };
+		usize::try_from(n).ok()
+	}
+
+	#[cfg(windows)]
+	fn read_before(buf: &mut [u8], deadline: Instant) -> Option<usize> {
+		use std::io::{stdin, Read};
+
+		use windows_sys::Win32::{Foundation::WAIT_OBJECT_0, System::{Console::{GetStdHandle, STD_INPUT_HANDLE}, Threading::WaitForSingleObject}};
+
+		let timeout = deadline.saturating_duration_since(Instant::now()).as_millis() as u32;
+		if unsafe { WaitForSingleObject(GetStdHandle(STD_INPUT_HANDLE), timeout) } != WAIT_OBJECT_0 {
+			return None;
+		}
+		stdin().read(buf).ok()
+	}
+
+	// The parameters of the first `{prefix}...{end}` sequence
+	fn csi(s: &str, prefix: &str, end: char) -> Option<String> {
+		let s = &s[s.find(prefix)? + prefix.len()..];
+		let i = s.find(|c: char| !c.is_ascii_digit() && c != ';')?;
+		(s[i..].starts_with(end)).then(|| s[..i].to_owned())
+	}
+
+	// `height;width` to `widthxheight`
+	fn size(s: String) -> String {
+		match s.split_once(';') {
+			Some((h, w)) => format!("{w}x{h}"),
+			None => s,
+		}
+	}
+}
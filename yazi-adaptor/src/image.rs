@@ -1,7 +1,7 @@
 use std::{fs::File, io::BufReader, path::{Path, PathBuf}};
 
 use anyhow::Result;
-use image::{imageops::FilterType, io::Limits, DynamicImage, ImageFormat};
+use image::{imageops::{self, FilterType}, io::Limits, DynamicImage, ImageFormat, Rgb, RgbImage};
 use yazi_config::{PREVIEW, TASKS};
 use yazi_shared::Term;
 
@@ -23,13 +23,18 @@ impl Image {
 		r
 	}
 
-	pub(super) async fn downscale(path: &Path, size: (u16, u16)) -> Result<DynamicImage> {
-		let (w, h) = Term::ratio()
+	// The maximum size in pixels of an image shown in `size` cells
+	pub(super) fn max_size(size: (u16, u16)) -> (u32, u32) {
+		Term::ratio()
 			.map(|(w, h)| {
 				let (w, h) = ((size.0 as f64 * w) as u32, (size.1 as f64 * h) as u32);
 				(w.min(PREVIEW.max_width), h.min(PREVIEW.max_height))
 			})
-			.unwrap_or((PREVIEW.max_width, PREVIEW.max_height));
+			.unwrap_or((PREVIEW.max_width, PREVIEW.max_height))
+	}
+
+	pub(super) async fn downscale(path: &Path, size: (u16, u16)) -> Result<DynamicImage> {
+		let (w, h) = Self::max_size(size);
 
 		let path = path.to_owned();
 		let img = tokio::task::spawn_blocking(move || {
@@ -47,6 +52,11 @@ impl Image {
 		.await?
 	}
 
+	pub async fn dimensions(path: &Path) -> Result<(u32, u32)> {
+		let path = path.to_owned();
+		Ok(tokio::task::spawn_blocking(move || image::image_dimensions(path)).await??)
+	}
+
 	pub async fn precache(path: &Path, cache: PathBuf) -> Result<()> {
 		let path = path.to_owned();
 		let img = tokio::task::spawn_blocking(move || {
@@ -78,4 +88,31 @@ impl Image {
 		})
 		.await?
 	}
+
+	// Two images side by side, each scaled to half of the preview width
+	pub async fn precache_pair(a: &Path, b: &Path, cache: PathBuf) -> Result<()> {
+		const GAP: u32 = 8;
+
+		let (a, b) = (a.to_owned(), b.to_owned());
+		let (a, b) = tokio::task::spawn_blocking(move || -> Result<_> {
+			let a = Self::set_limits(image::io::Reader::open(a)?.with_guessed_format()?).decode()?;
+			let b = Self::set_limits(image::io::Reader::open(b)?.with_guessed_format()?).decode()?;
+			Ok((a, b))
+		})
+		.await??;
+
+		tokio::task::spawn_blocking(move || {
+			let (w, h) = ((PREVIEW.max_width.saturating_sub(GAP) / 2).max(1), PREVIEW.max_height);
+			let (a, b) = (a.resize(w, h, FilterType::Triangle), b.resize(w, h, FilterType::Triangle));
+
+			let (cw, ch) = (a.width() + GAP + b.width(), a.height().max(b.height()));
+			let (xb, ya, yb) = (a.width() + GAP, (ch - a.height()) / 2, (ch - b.height()) / 2);
+
+			let mut canvas = RgbImage::from_pixel(cw, ch, Rgb([32, 32, 32]));
+			imageops::overlay(&mut canvas, &a.into_rgb8(), 0, ya as i64);
+			imageops::overlay(&mut canvas, &b.into_rgb8(), xb as i64, yb as i64);
+			Ok(canvas.save_with_format(cache, ImageFormat::Jpeg)?)
+		})
+		.await?
+	}
 }
@@ -1,8 +1,8 @@
-use std::{io::{stdout, BufWriter, Write}, path::Path};
+use std::{fs, io::{stdout, BufWriter, Write}, path::Path};
 
 use anyhow::Result;
 use base64::{engine::general_purpose, Engine};
-use image::{codecs::jpeg::JpegEncoder, DynamicImage};
+use image::{codecs::jpeg::JpegEncoder, DynamicImage, ImageFormat};
 use ratatui::prelude::Rect;
 use yazi_shared::Term;
 
@@ -13,8 +13,11 @@ pub(super) struct Iterm2;
 
 impl Iterm2 {
 	pub(super) async fn image_show(path: &Path, rect: Rect) -> Result<()> {
-		let img = Image::downscale(path, (rect.width, rect.height)).await?;
-		let b = Self::encode(img).await?;
+		let b = if let Some((raw, size)) = Self::original(path, rect).await {
+			Self::output(&raw, size)?
+		} else {
+			Self::encode(Image::downscale(path, (rect.width, rect.height)).await?).await?
+		};
 
 		Self::image_hide(rect)?;
 		Term::move_lock(stdout().lock(), (rect.x, rect.y), |stdout| Ok(stdout.write_all(&b)?))
@@ -32,26 +35,52 @@ impl Iterm2 {
 		})
 	}
 
+	// Images that already fit the preview are sent as they are, since iTerm2
+	// decodes these formats natively.
+	async fn original(path: &Path, rect: Rect) -> Option<(Vec<u8>, (u32, u32))> {
+		let (w, h) = Image::max_size((rect.width, rect.height));
+		let path = path.to_owned();
+
+		tokio::task::spawn_blocking(move || {
+			let reader = image::io::Reader::open(&path).ok()?.with_guessed_format().ok()?;
+			if !matches!(reader.format()?, ImageFormat::Png | ImageFormat::Jpeg | ImageFormat::Gif) {
+				return None;
+			}
+
+			let size = reader.into_dimensions().ok()?;
+			if size.0 > w || size.1 > h {
+				return None;
+			}
+			Some((fs::read(&path).ok()?, size))
+		})
+		.await
+		.ok()
+		.flatten()
+	}
+
 	async fn encode(img: DynamicImage) -> Result<Vec<u8>> {
 		tokio::task::spawn_blocking(move || {
 			let size = (img.width(), img.height());
 
 			let mut jpg = vec![];
 			JpegEncoder::new_with_quality(&mut jpg, 75).encode_image(&img)?;
-
-			let mut buf = vec![];
-			write!(
-				buf,
-				"{}]1337;File=inline=1;size={};width={}px;height={}px;doNotMoveCursor=1:{}\x07{}",
-				START,
-				jpg.len(),
-				size.0,
-				size.1,
-				general_purpose::STANDARD.encode(&jpg),
-				CLOSE
-			)?;
-			Ok(buf)
+			Self::output(&jpg, size)
 		})
 		.await?
 	}
+
+	fn output(raw: &[u8], size: (u32, u32)) -> Result<Vec<u8>> {
+		let mut buf = vec![];
+		write!(
+			buf,
+			"{}]1337;File=inline=1;size={};width={}px;height={}px;doNotMoveCursor=1:{}\x07{}",
+			START,
+			raw.len(),
+			size.0,
+			size.1,
+			general_purpose::STANDARD.encode(raw),
+			CLOSE
+		)?;
+		Ok(buf)
+	}
 }
@@ -1,9 +1,10 @@
-use std::{io::{stdout, BufWriter, Write}, path::Path};
+use std::{collections::VecDeque, io::{stdout, BufWriter, Write}, path::{Path, PathBuf}, sync::{Arc, Mutex}, time::SystemTime};
 
 use anyhow::Result;
 use base64::{engine::general_purpose, Engine};
 use image::DynamicImage;
 use ratatui::prelude::Rect;
+use tokio::fs;
 use yazi_shared::Term;
 
 use super::image::Image;
@@ -309,12 +310,36 @@ static DIACRITICS: [char; 297] = [
 	'\u{1D244}',
 ];
 
+// Encoded frames of the recently shown images, so hovering back and forth
+// doesn't have to decode and downscale them again.
+static FRAMES: Mutex<VecDeque<(Frame, Arc<Vec<u8>>)>> = Mutex::new(VecDeque::new());
+const FRAMES_MAX: usize = 10;
+
+#[derive(PartialEq, Eq)]
+struct Frame {
+	path:  PathBuf,
+	mtime: Option<SystemTime>,
+	size:  (u16, u16),
+}
+
 pub(super) struct Kitty;
 
 impl Kitty {
 	pub(super) async fn image_show(path: &Path, rect: Rect) -> Result<()> {
-		let img = Image::downscale(path, (rect.width, rect.height)).await?;
-		let b = Self::encode(img).await?;
+		let frame = Frame {
+			path:  path.to_owned(),
+			mtime: fs::metadata(path).await.ok().and_then(|m| m.modified().ok()),
+			size:  (rect.width, rect.height),
+		};
+
+		let b = if let Some(b) = Self::frame_get(&frame) {
+			b
+		} else {
+			let img = Image::downscale(path, frame.size).await?;
+			let b = Arc::new(Self::encode(img).await?);
+			Self::frame_put(frame, b.clone());
+			b
+		};
 
 		Self::image_hide(rect)?;
 		Term::move_lock(stdout().lock(), (rect.x, rect.y), |stdout| {
@@ -353,6 +378,20 @@ impl Kitty {
 		})
 	}
 
+	fn frame_get(frame: &Frame) -> Option<Arc<Vec<u8>>> {
+		let frames = FRAMES.lock().unwrap();
+		frames.iter().find(|(f, _)| f == frame).map(|(_, b)| b.clone())
+	}
+
+	fn frame_put(frame: Frame, b: Arc<Vec<u8>>) {
+		let mut frames = FRAMES.lock().unwrap();
+		frames.retain(|(f, _)| f.path != frame.path);
+		if frames.len() >= FRAMES_MAX {
+			frames.pop_front();
+		}
+		frames.push_back((frame, b));
+	}
+
 	async fn encode(img: DynamicImage) -> Result<Vec<u8>> {
 		fn output(raw: &[u8], format: u8, size: (u32, u32)) -> Result<Vec<u8>> {
 			let b64 = general_purpose::STANDARD.encode(raw).chars().collect::<Vec<_>>();
@@ -1,6 +1,7 @@
 #![allow(clippy::unit_arg)]
 
 mod adaptor;
+mod diagnosis;
 mod image;
 mod iterm2;
 mod kitty;
@@ -13,7 +14,7 @@ use kitty::*;
 use sixel::*;
 use yazi_shared::{env_exists, RoCell};
 
-pub use crate::image::*;
+pub use crate::{diagnosis::*, image::*};
 
 pub static ADAPTOR: RoCell<Adaptor> = RoCell::new();
 
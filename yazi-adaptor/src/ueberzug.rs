@@ -1,4 +1,4 @@
-use std::{path::PathBuf, process::Stdio};
+use std::{path::PathBuf, process::Stdio, sync::atomic::{AtomicU32, Ordering}};
 
 use anyhow::Result;
 use ratatui::prelude::Rect;
@@ -8,11 +8,13 @@ use yazi_config::PREVIEW;
 
 use crate::Adaptor;
 
+static DEMON: AtomicU32 = AtomicU32::new(0);
+
 pub(super) struct Ueberzug;
 
 impl Ueberzug {
 	pub(super) fn start(adaptor: Adaptor) -> Result<UnboundedSender<Option<(PathBuf, Rect)>>> {
-		let mut child = Self::create_demon(adaptor).ok();
+		let mut child = Some(Self::create_demon(adaptor)?);
 		let (tx, mut rx) = mpsc::unbounded_channel();
 
 		tokio::spawn(async move {
@@ -33,15 +35,28 @@ impl Ueberzug {
 		Ok(tx)
 	}
 
+	// The demon isn't dropped when the app exits, so it has to be killed
+	// explicitly, otherwise the overlay window would stay on the screen.
+	pub(super) fn stop() {
+		let pid = DEMON.swap(0, Ordering::Relaxed);
+		if pid != 0 {
+			#[cfg(unix)]
+			unsafe {
+				libc::kill(pid as libc::pid_t, libc::SIGTERM);
+			}
+		}
+	}
+
 	fn create_demon(adaptor: Adaptor) -> Result<Child> {
-		Ok(
-			Command::new("ueberzug")
-				.args(["layer", "-so", &adaptor.to_string()])
-				.kill_on_drop(true)
-				.stdin(Stdio::piped())
-				.stderr(Stdio::null())
-				.spawn()?,
-		)
+		let child = Command::new("ueberzug")
+			.args(["layer", "-so", &adaptor.to_string()])
+			.kill_on_drop(true)
+			.stdin(Stdio::piped())
+			.stderr(Stdio::null())
+			.spawn()?;
+
+		DEMON.store(child.id().unwrap_or(0), Ordering::Relaxed);
+		Ok(child)
 	}
 
 	fn adjust_rect(mut rect: Rect) -> Rect {
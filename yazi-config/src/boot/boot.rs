@@ -1,20 +1,22 @@
 use std::{ffi::OsString, fs, path::PathBuf, process};
 
-use clap::Parser;
 use yazi_shared::{current_cwd, expand_path};
 
-use super::cli::Args;
-use crate::{Xdg, PREVIEW};
+use crate::{Xdg, ARGS, PREVIEW};
 
 #[derive(Debug)]
 pub struct Boot {
 	pub cwd:  PathBuf,
 	pub file: Option<OsString>,
+	// The entries of the other tabs, in the same form as `cwd` and `file`
+	pub tabs: Vec<(PathBuf, Option<OsString>)>,
 
 	pub state_dir: PathBuf,
 
 	pub cwd_file:     Option<PathBuf>,
 	pub chooser_file: Option<PathBuf>,
+	pub events:       Option<PathBuf>,
+	pub remote:       Option<String>,
 }
 
 impl Boot {
@@ -35,27 +37,31 @@ impl Boot {
 
 impl Default for Boot {
 	fn default() -> Self {
-		let args = Args::parse();
-		let (cwd, file) = Self::parse_entry(args.entry);
+		let mut entries =
+			ARGS.entries.iter().chain(&ARGS.tabs).map(|e| Self::parse_entry(Some(e.clone())));
+		let (cwd, file) = entries.next().unwrap_or_else(|| Self::parse_entry(None));
 
 		let boot = Self {
 			cwd,
 			file,
+			tabs: entries.collect(),
 
 			state_dir: Xdg::state_dir().unwrap(),
 
-			cwd_file: args.cwd_file,
-			chooser_file: args.chooser_file,
+			cwd_file: ARGS.cwd_file.clone(),
+			chooser_file: ARGS.chooser_file.clone(),
+			events: ARGS.events.clone(),
+			remote: ARGS.remote.clone(),
 		};
 
 		if !boot.state_dir.is_dir() {
 			fs::create_dir_all(&boot.state_dir).unwrap();
 		}
 		if !PREVIEW.cache_dir.is_dir() {
-			fs::create_dir(&PREVIEW.cache_dir).unwrap();
+			fs::create_dir_all(&PREVIEW.cache_dir).unwrap();
 		}
 
-		if args.clear_cache {
+		if ARGS.clear_cache {
 			if PREVIEW.cache_dir == Xdg::cache_dir() {
 				println!("Clearing cache directory: \n{:?}", PREVIEW.cache_dir);
 				fs::remove_dir_all(&PREVIEW.cache_dir).unwrap();
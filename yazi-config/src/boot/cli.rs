@@ -4,10 +4,13 @@ use clap::{command, Parser};
 
 #[derive(Debug, Parser)]
 #[command(name = "yazi", version)]
-pub(super) struct Args {
-	/// Set the current working entry
+pub(crate) struct Args {
+	/// Set the current working entry, each of the rest is opened in a new tab
 	#[arg(index = 1)]
-	pub entry: Option<PathBuf>,
+	pub entries: Vec<PathBuf>,
+	/// Open this entry in a new tab, can be given multiple times
+	#[arg(long = "tab", value_name = "ENTRY")]
+	pub tabs:    Vec<PathBuf>,
 
 	/// Write the cwd on exit to this file
 	#[arg(long)]
@@ -15,8 +18,60 @@ pub(super) struct Args {
 	/// Write the selected files on open emitted by the chooser mode
 	#[arg(long)]
 	pub chooser_file: Option<PathBuf>,
+	/// Write events as JSON lines to this file, FIFO or socket
+	#[arg(long)]
+	pub events:       Option<PathBuf>,
+
+	/// Sort by this, with an optional `-asc` or `-desc`, e.g. `mtime-desc`
+	#[arg(long, value_name = "BY", value_parser = Args::parse_sort)]
+	pub sort:        Option<(String, Option<bool>)>,
+	/// Show hidden files
+	#[arg(long, action)]
+	pub show_hidden: bool,
+	/// Use `zen` for the current column only, or the ratios like `1,4,3`
+	#[arg(long, value_name = "LAYOUT", value_parser = Args::parse_layout)]
+	pub layout:      Option<[u16; 3]>,
+
+	/// Send the commands to a running instance, e.g. `cd /tmp`, one per line
+	#[arg(long, value_name = "COMMANDS")]
+	pub remote: Option<String>,
 
 	/// Clear the cache directory
 	#[arg(long, action)]
 	pub clear_cache: bool,
 }
+
+// Kept free of the crate's own types, since it's also used by `build.rs`
+impl Args {
+	// The direction is the `sort_reverse`, which is kept as is if not given
+	fn parse_sort(s: &str) -> Result<(String, Option<bool>), String> {
+		let (by, reverse) = match s.rsplit_once('-') {
+			Some((by, "asc")) => (by, Some(false)),
+			Some((by, "desc")) => (by, Some(true)),
+			_ => (s, None),
+		};
+
+		let by = match by {
+			"mtime" => "modified",
+			"btime" => "created",
+			// The inode change time, which isn't the `created` birth time
+			"ctime" => return Err("invalid sort: ctime, use `btime` for the creation time".to_owned()),
+			"name" => "alphabetical",
+			"none" | "alphabetical" | "created" | "modified" | "natural" | "size" => by,
+			_ => return Err(format!("invalid sort: {by}")),
+		};
+		Ok((by.to_owned(), reverse))
+	}
+
+	fn parse_layout(s: &str) -> Result<[u16; 3], String> {
+		if s == "zen" {
+			return Ok([0, 1, 0]);
+		}
+
+		let ratio: Vec<u16> = s.split(',').filter_map(|r| r.trim().parse().ok()).collect();
+		match ratio[..] {
+			[p, c, v] if s.split(',').count() == 3 && p | c | v != 0 => Ok([p, c, v]),
+			_ => Err("expected `zen`, or three ratios with at least one non-zero".to_owned()),
+		}
+	}
+}
@@ -2,3 +2,4 @@ mod boot;
 mod cli;
 
 pub use boot::*;
+pub(crate) use cli::*;
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Deserializer};
+
+use crate::MERGED_YAZI;
+
+#[derive(Debug, Deserialize)]
+pub struct Hooks {
+	pub cd:   Vec<Hook>,
+	pub open: Vec<Hook>,
+	pub task: Vec<Hook>,
+	pub quit: Vec<Hook>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Hook {
+	// A shell command, run in the background
+	Exec(String),
+	// A command registered by a Lua plugin
+	Plugin(String),
+}
+
+impl Default for Hooks {
+	fn default() -> Self {
+		#[derive(Deserialize)]
+		struct Outer {
+			hooks: Hooks,
+		}
+
+		toml::from_str::<Outer>(&MERGED_YAZI).unwrap().hooks
+	}
+}
+
+impl<'de> Deserialize<'de> for Hook {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		#[derive(Deserialize)]
+		struct Shadow {
+			exec:   Option<String>,
+			plugin: Option<String>,
+		}
+
+		let shadow = Shadow::deserialize(deserializer)?;
+		match (shadow.exec, shadow.plugin) {
+			(Some(exec), None) if !exec.is_empty() => Ok(Self::Exec(exec)),
+			(None, Some(plugin)) if !plugin.is_empty() => Ok(Self::Plugin(plugin)),
+			_ => Err(serde::de::Error::custom("either `exec` or `plugin` must be given")),
+		}
+	}
+}
@@ -2,14 +2,16 @@ use std::borrow::Cow;
 
 use serde::Deserialize;
 
-use super::{Exec, Key};
+use super::{Exec, Key, Region};
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct Control {
-	pub on:   Vec<Key>,
+	pub on:     Vec<Key>,
 	#[serde(deserialize_with = "Exec::deserialize")]
-	pub exec: Vec<Exec>,
-	pub desc: Option<String>,
+	pub exec:   Vec<Exec>,
+	pub desc:   Option<String>,
+	// Only for mouse events, limits the binding to a pane of the manager
+	pub region: Option<Region>,
 }
 
 impl Control {
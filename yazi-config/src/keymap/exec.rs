@@ -70,7 +70,7 @@ impl Exec {
 			{
 				let mut execs = Vec::new();
 				while let Some(value) = &seq.next_element::<String>()? {
-					execs.push(Exec::try_from(value.as_str()).map_err(de::Error::custom)?);
+					execs.extend(Exec::chain(value).map_err(de::Error::custom)?);
 				}
 				Ok(execs)
 			}
@@ -79,7 +79,7 @@ impl Exec {
 			where
 				E: de::Error,
 			{
-				Ok(vec![Exec::try_from(value).map_err(de::Error::custom)?])
+				Exec::chain(value).map_err(de::Error::custom)
 			}
 		}
 
@@ -88,6 +88,36 @@ impl Exec {
 }
 
 impl Exec {
+	// Split the commands separated by `;`, unless it's quoted or escaped
+	pub fn chain(s: &str) -> anyhow::Result<Vec<Self>> {
+		let (mut parts, mut start) = (vec![], 0);
+		let (mut quote, mut escaped) = (None, false);
+		for (i, c) in s.char_indices() {
+			match c {
+				_ if escaped => escaped = false,
+				'\\' if quote != Some('\'') => escaped = true,
+				'\'' | '"' if quote.is_none() => quote = Some(c),
+				_ if quote == Some(c) => quote = None,
+				';' if quote.is_none() => {
+					parts.push(&s[start..i]);
+					start = i + 1;
+				}
+				_ => {}
+			}
+		}
+		parts.push(&s[start..]);
+
+		let execs: Vec<_> = parts
+			.into_iter()
+			.filter(|p| !p.trim().is_empty())
+			.map(Self::try_from)
+			.collect::<Result<_, _>>()?;
+		if execs.is_empty() {
+			bail!("`exec` cannot be empty");
+		}
+		Ok(execs)
+	}
+
 	#[inline]
 	pub fn call(cwd: &str, args: Vec<String>) -> Self {
 		Exec { cmd: cwd.to_owned(), args, named: Default::default() }
@@ -115,3 +145,57 @@ impl Exec {
 		self
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::Exec;
+
+	fn chain(s: &str) -> Vec<(String, Vec<String>)> {
+		Exec::chain(s).unwrap().into_iter().map(|e| (e.cmd, e.args)).collect()
+	}
+
+	#[test]
+	fn test_chain() {
+		assert_eq!(chain("escape; arrow 1"), [
+			("escape".to_owned(), vec![]),
+			("arrow".to_owned(), vec!["1".to_owned()])
+		]);
+		assert_eq!(chain("escape;;arrow 1; "), chain("escape; arrow 1"));
+
+		// Quoted `;` stays in the argument
+		assert_eq!(chain(r#"shell "a; b""#), [("shell".to_owned(), vec!["a; b".to_owned()])]);
+		assert_eq!(chain("shell 'a; b'"), [("shell".to_owned(), vec!["a; b".to_owned()])]);
+		assert_eq!(chain(r#"shell "it's; ok""#), [("shell".to_owned(), vec!["it's; ok".to_owned()])]);
+
+		// So does an escaped one
+		assert_eq!(chain(r"shell a\;b"), [("shell".to_owned(), vec!["a;b".to_owned()])]);
+	}
+
+	#[test]
+	fn test_chain_escaped_quotes() {
+		assert_eq!(chain(r#"shell "say \"hi; there\"""#), [("shell".to_owned(), vec![
+			r#"say "hi; there""#.to_owned()
+		])]);
+
+		// An escaped quote doesn't open a quoted part
+		assert_eq!(chain(r#"shell \"a; b"#), [
+			("shell".to_owned(), vec![r#""a"#.to_owned()]),
+			("b".to_owned(), vec![])
+		]);
+
+		// A backslash in single quotes is kept as it is, and doesn't escape the quote
+		assert_eq!(chain(r"shell 'a\'; b"), [
+			("shell".to_owned(), vec![r"a\".to_owned()]),
+			("b".to_owned(), vec![])
+		]);
+	}
+
+	#[test]
+	fn test_chain_unterminated() {
+		assert!(Exec::chain(r#"shell "a; b"#).is_err());
+		assert!(Exec::chain("shell 'a; b").is_err());
+
+		assert!(Exec::chain("").is_err());
+		assert!(Exec::chain(" ; ").is_err());
+	}
+}
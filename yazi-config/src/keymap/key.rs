@@ -1,5 +1,5 @@
 use anyhow::bail;
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use serde::Deserialize;
 
 #[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Hash)]
@@ -9,6 +9,16 @@ pub struct Key {
 	pub shift: bool,
 	pub ctrl:  bool,
 	pub alt:   bool,
+	pub mouse: Option<Mouse>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Mouse {
+	LeftClick,
+	RightClick,
+	MiddleClick,
+	ScrollUp,
+	ScrollDown,
 }
 
 impl Key {
@@ -22,12 +32,34 @@ impl Key {
 
 	#[inline]
 	pub fn is_enter(&self) -> bool {
-		matches!(self, Key { code: KeyCode::Enter, shift: false, ctrl: false, alt: false })
+		matches!(self, Key { code: KeyCode::Enter, shift: false, ctrl: false, alt: false, .. })
+	}
+
+	// Only presses and scrolls are bindable, the rest of mouse events are ignored
+	pub fn from_mouse(event: MouseEvent) -> Option<Self> {
+		let mouse = match event.kind {
+			MouseEventKind::Down(MouseButton::Left) => Mouse::LeftClick,
+			MouseEventKind::Down(MouseButton::Right) => Mouse::RightClick,
+			MouseEventKind::Down(MouseButton::Middle) => Mouse::MiddleClick,
+			MouseEventKind::ScrollUp => Mouse::ScrollUp,
+			MouseEventKind::ScrollDown => Mouse::ScrollDown,
+			_ => return None,
+		};
+
+		Some(Self {
+			code:  KeyCode::Null,
+			shift: event.modifiers.contains(KeyModifiers::SHIFT),
+			ctrl:  event.modifiers.contains(KeyModifiers::CONTROL),
+			alt:   event.modifiers.contains(KeyModifiers::ALT),
+			mouse: Some(mouse),
+		})
 	}
 }
 
 impl Default for Key {
-	fn default() -> Self { Self { code: KeyCode::Null, shift: false, ctrl: false, alt: false } }
+	fn default() -> Self {
+		Self { code: KeyCode::Null, shift: false, ctrl: false, alt: false, mouse: None }
+	}
 }
 
 impl From<KeyEvent> for Key {
@@ -54,6 +86,7 @@ impl From<KeyEvent> for Key {
 			shift,
 			ctrl: value.modifiers.contains(KeyModifiers::CONTROL),
 			alt: value.modifiers.contains(KeyModifiers::ALT),
+			mouse: None,
 		}
 	}
 }
@@ -110,6 +143,12 @@ impl TryFrom<String> for Key {
 				"F12" => key.code = KeyCode::F(12),
 				"Esc" => key.code = KeyCode::Esc,
 
+				"LeftClick" => key.mouse = Some(Mouse::LeftClick),
+				"RightClick" => key.mouse = Some(Mouse::RightClick),
+				"MiddleClick" => key.mouse = Some(Mouse::MiddleClick),
+				"ScrollUp" => key.mouse = Some(Mouse::ScrollUp),
+				"ScrollDown" => key.mouse = Some(Mouse::ScrollDown),
+
 				c if it.peek().is_none() => {
 					key.code = KeyCode::Char(c.chars().next().unwrap());
 				}
@@ -117,7 +156,7 @@ impl TryFrom<String> for Key {
 			}
 		}
 
-		if key.code == KeyCode::Null {
+		if key.code == KeyCode::Null && key.mouse.is_none() {
 			bail!("empty key")
 		}
 		Ok(key)
@@ -142,6 +181,18 @@ impl ToString for Key {
 			s += "S-";
 		}
 
+		let code = match self.mouse {
+			Some(Mouse::LeftClick) => "LeftClick",
+			Some(Mouse::RightClick) => "RightClick",
+			Some(Mouse::MiddleClick) => "MiddleClick",
+			Some(Mouse::ScrollUp) => "ScrollUp",
+			Some(Mouse::ScrollDown) => "ScrollDown",
+			None => "",
+		};
+		if !code.is_empty() {
+			return s + code + ">";
+		}
+
 		let code = match self.code {
 			KeyCode::Backspace => "Backspace",
 			KeyCode::Enter => "Enter",
@@ -10,6 +10,7 @@ pub struct Keymap {
 	pub manager:    Vec<Control>,
 	pub tasks:      Vec<Control>,
 	pub select:     Vec<Control>,
+	pub confirm:    Vec<Control>,
 	pub input:      Vec<Control>,
 	pub help:       Vec<Control>,
 	pub completion: Vec<Control>,
@@ -25,6 +26,7 @@ impl<'de> Deserialize<'de> for Keymap {
 			manager:    Inner,
 			tasks:      Inner,
 			select:     Inner,
+			confirm:    Inner,
 			input:      Inner,
 			help:       Inner,
 			completion: Inner,
@@ -39,6 +41,7 @@ impl<'de> Deserialize<'de> for Keymap {
 			manager:    shadow.manager.keymap,
 			tasks:      shadow.tasks.keymap,
 			select:     shadow.select.keymap,
+			confirm:    shadow.confirm.keymap,
 			input:      shadow.input.keymap,
 			help:       shadow.help.keymap,
 			completion: shadow.completion.keymap,
@@ -57,12 +60,20 @@ impl Keymap {
 			KeymapLayer::Manager => &self.manager,
 			KeymapLayer::Tasks => &self.tasks,
 			KeymapLayer::Select => &self.select,
+			KeymapLayer::Confirm => &self.confirm,
 			KeymapLayer::Input => &self.input,
 			KeymapLayer::Help => &self.help,
 			KeymapLayer::Completion => &self.completion,
 			KeymapLayer::Which => unreachable!(),
 		}
 	}
+
+	// Mouse events are only captured when turned on, and there is something
+	// bound to them
+	#[inline]
+	pub fn has_mouse(&self) -> bool {
+		self.manager.iter().any(|c| c.on.first().is_some_and(|k| k.mouse.is_some()))
+	}
 }
 
 #[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
@@ -71,6 +82,7 @@ pub enum KeymapLayer {
 	Manager,
 	Tasks,
 	Select,
+	Confirm,
 	Input,
 	Help,
 	Completion,
@@ -83,6 +95,7 @@ impl Display for KeymapLayer {
 			KeymapLayer::Manager => write!(f, "manager"),
 			KeymapLayer::Tasks => write!(f, "tasks"),
 			KeymapLayer::Select => write!(f, "select"),
+			KeymapLayer::Confirm => write!(f, "confirm"),
 			KeymapLayer::Input => write!(f, "input"),
 			KeymapLayer::Help => write!(f, "help"),
 			KeymapLayer::Completion => write!(f, "completion"),
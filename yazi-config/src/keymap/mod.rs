@@ -2,8 +2,10 @@ mod control;
 mod exec;
 mod key;
 mod keymap;
+mod region;
 
 pub use control::*;
 pub use exec::*;
 pub use key::*;
 pub use keymap::*;
+pub use region::*;
@@ -0,0 +1,10 @@
+use serde::Deserialize;
+
+// The pane of the manager a mouse event happened in
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Region {
+	Parent,
+	Current,
+	Preview,
+}
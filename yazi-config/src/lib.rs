@@ -3,21 +3,24 @@
 use yazi_shared::RoCell;
 
 mod boot;
+pub mod hooks;
 pub mod keymap;
 mod log;
 pub mod manager;
+pub mod mimetype;
 pub mod open;
 mod pattern;
 pub mod plugins;
 pub mod popup;
 mod preset;
 pub mod preview;
-mod tasks;
+pub mod previewers;
+pub mod tasks;
 pub mod theme;
 mod validation;
 mod xdg;
 
-pub(crate) use pattern::*;
+pub use pattern::*;
 pub(crate) use preset::*;
 pub(crate) use xdg::*;
 
@@ -25,34 +28,46 @@ static MERGED_KEYMAP: RoCell<String> = RoCell::new();
 static MERGED_THEME: RoCell<String> = RoCell::new();
 static MERGED_YAZI: RoCell<String> = RoCell::new();
 
+static ARGS: RoCell<boot::Args> = RoCell::new();
+
+pub static HOOKS: RoCell<hooks::Hooks> = RoCell::new();
 pub static KEYMAP: RoCell<keymap::Keymap> = RoCell::new();
 pub static LOG: RoCell<log::Log> = RoCell::new();
 pub static MANAGER: RoCell<manager::Manager> = RoCell::new();
+pub static MIMETYPE: RoCell<mimetype::Mimetype> = RoCell::new();
 pub static OPEN: RoCell<open::Open> = RoCell::new();
 pub static PLUGINS: RoCell<plugins::Plugins> = RoCell::new();
 pub static PREVIEW: RoCell<preview::Preview> = RoCell::new();
+pub static PREVIEWERS: RoCell<previewers::Previewers> = RoCell::new();
 pub static TASKS: RoCell<tasks::Tasks> = RoCell::new();
 pub static THEME: RoCell<theme::Theme> = RoCell::new();
 pub static INPUT: RoCell<popup::Input> = RoCell::new();
 pub static SELECT: RoCell<popup::Select> = RoCell::new();
+pub static CONFIRM: RoCell<popup::Confirm> = RoCell::new();
 
 pub static BOOT: RoCell<boot::Boot> = RoCell::new();
 
 pub fn init() {
+	ARGS.with(clap::Parser::parse);
+
 	MERGED_KEYMAP.with(Preset::keymap);
 	MERGED_THEME.with(Preset::theme);
 	MERGED_YAZI.with(Preset::yazi);
 
+	HOOKS.with(Default::default);
 	KEYMAP.with(Default::default);
 	LOG.with(Default::default);
 	MANAGER.with(Default::default);
+	MIMETYPE.with(Default::default);
 	OPEN.with(Default::default);
 	PLUGINS.with(Default::default);
 	PREVIEW.with(Default::default);
+	PREVIEWERS.with(Default::default);
 	TASKS.with(Default::default);
 	THEME.with(Default::default);
 	INPUT.with(Default::default);
 	SELECT.with(Default::default);
+	CONFIRM.with(Default::default);
 
 	BOOT.with(Default::default);
 }
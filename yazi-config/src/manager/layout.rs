@@ -1,18 +1,22 @@
+use std::sync::atomic::{AtomicU16, Ordering};
+
 use anyhow::bail;
 use crossterm::terminal::WindowSize;
 use ratatui::{prelude::Rect, widgets::{Block, Padding}};
-use serde::{Deserialize, Serialize};
+use serde::{ser::SerializeStruct, Deserialize, Serialize, Serializer};
 use yazi_shared::Term;
 
-use crate::{PREVIEW, THEME};
+use crate::{keymap::Region, PREVIEW, THEME};
 
-#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+// The ratios can be adjusted at runtime, and are kept until the app exits
+#[derive(Debug, Default, Deserialize)]
 #[serde(try_from = "Vec<u16>")]
 pub struct ManagerLayout {
-	pub parent:  u16,
-	pub current: u16,
-	pub preview: u16,
-	pub all:     u16,
+	parent:  AtomicU16,
+	current: AtomicU16,
+	preview: AtomicU16,
+
+	default: [u16; 3],
 }
 
 impl TryFrom<Vec<u16>> for ManagerLayout {
@@ -27,20 +31,69 @@ impl TryFrom<Vec<u16>> for ManagerLayout {
 		}
 
 		Ok(Self {
-			parent:  ratio[0],
-			current: ratio[1],
-			preview: ratio[2],
-			all:     ratio[0] + ratio[1] + ratio[2],
+			parent:  AtomicU16::new(ratio[0]),
+			current: AtomicU16::new(ratio[1]),
+			preview: AtomicU16::new(ratio[2]),
+
+			default: [ratio[0], ratio[1], ratio[2]],
 		})
 	}
 }
 
+impl Serialize for ManagerLayout {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let mut s = serializer.serialize_struct("ManagerLayout", 4)?;
+		s.serialize_field("parent", &self.parent())?;
+		s.serialize_field("current", &self.current())?;
+		s.serialize_field("preview", &self.preview())?;
+		s.serialize_field("all", &self.all())?;
+		s.end()
+	}
+}
+
+impl ManagerLayout {
+	#[inline]
+	pub fn parent(&self) -> u16 { self.parent.load(Ordering::Relaxed) }
+
+	#[inline]
+	pub fn current(&self) -> u16 { self.current.load(Ordering::Relaxed) }
+
+	#[inline]
+	pub fn preview(&self) -> u16 { self.preview.load(Ordering::Relaxed) }
+
+	#[inline]
+	pub fn all(&self) -> u16 { self.parent() + self.current() + self.preview() }
+
+	pub fn resize_parent(&self, step: isize) -> bool { self.resize(&self.parent, step) }
+
+	pub fn resize_preview(&self, step: isize) -> bool { self.resize(&self.preview, step) }
+
+	fn resize(&self, ratio: &AtomicU16, step: isize) -> bool {
+		let old = ratio.load(Ordering::Relaxed);
+		let new = (old as isize + step).clamp(0, u16::MAX as isize) as u16;
+		if new == old || self.all() - old + new == 0 {
+			return false;
+		}
+
+		ratio.store(new, Ordering::Relaxed);
+		true
+	}
+
+	pub fn reset(&self) -> bool {
+		let old = [self.parent(), self.current(), self.preview()];
+		self.parent.store(self.default[0], Ordering::Relaxed);
+		self.current.store(self.default[1], Ordering::Relaxed);
+		self.preview.store(self.default[2], Ordering::Relaxed);
+		old != self.default
+	}
+}
+
 impl ManagerLayout {
 	pub fn preview_rect(&self) -> Rect {
 		let WindowSize { columns, rows, .. } = Term::size();
 		let (top, right, bottom, left) = THEME.manager.preview_offset;
 
-		let w = (columns * self.preview) as f64 / self.all as f64;
+		let w = (columns * self.preview()) as f64 / self.all() as f64;
 		let w = if w.fract() > 0.5 { w.ceil() as u16 } else { w.floor() as u16 };
 
 		Rect {
@@ -71,13 +124,31 @@ impl ManagerLayout {
 
 		let offset = THEME.manager.folder_offset;
 		Block::default().padding(Padding::new(offset.3, offset.1, offset.0, offset.2)).inner(Rect {
-			x:      columns * self.parent / self.all,
+			x:      columns * self.parent() / self.all(),
 			y:      0,
-			width:  columns * self.current / self.all,
+			width:  columns * self.current() / self.all(),
 			height: rows,
 		})
 	}
 
 	#[inline]
 	pub fn folder_height(&self) -> usize { self.folder_rect().height as usize }
+
+	// The pane at the cell, none for the rows of the header and the status bar
+	pub fn region(&self, x: u16, y: u16) -> Option<Region> {
+		let rect = self.folder_rect();
+		if y < rect.y || y >= rect.bottom() {
+			return None;
+		}
+
+		let columns = Term::size().columns;
+		let parent = columns * self.parent() / self.all();
+		Some(if x < parent {
+			Region::Parent
+		} else if x < parent + columns * self.current() / self.all() {
+			Region::Current
+		} else {
+			Region::Preview
+		})
+	}
 }
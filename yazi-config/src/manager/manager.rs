@@ -1,8 +1,11 @@
+use std::{collections::HashMap, path::{Path, PathBuf}};
+
 use serde::{Deserialize, Serialize};
 use validator::Validate;
+use yazi_shared::expand_path;
 
-use super::{ManagerLayout, SortBy};
-use crate::{validation::check_validation, MERGED_YAZI};
+use super::{ManagerLayout, OpenDir, Sanitize, SortBy, YankScope};
+use crate::{validation::check_validation, xdg::Xdg, Pattern, ARGS, MERGED_YAZI};
 
 #[derive(Debug, Deserialize, Serialize, Validate)]
 pub struct Manager {
@@ -13,12 +16,56 @@ pub struct Manager {
 	pub sort_sensitive: bool,
 	pub sort_reverse:   bool,
 	pub sort_dir_first: bool,
+	pub sort_translit:  bool,
 
 	// Display
 	#[validate(length(min = 1, max = 20, message = "must be between 1 and 20 characters"))]
 	pub linemode:     String,
 	pub show_hidden:  bool,
+	pub show_ignored: bool,
 	pub show_symlink: bool,
+
+	// Opening
+	pub open_dir: OpenDir,
+
+	// Creating, the umask applies if not given
+	#[validate(range(max = 0o7777, message = "must be a valid mode, e.g. 0o644"))]
+	pub create_file_mode: Option<u32>,
+	#[validate(range(max = 0o7777, message = "must be a valid mode, e.g. 0o755"))]
+	pub create_dir_mode:  Option<u32>,
+	#[serde(default)]
+	pub templates:        String,
+
+	// Yanking
+	pub yank_scope: YankScope,
+
+	// Naming, by the type of the destination filesystem
+	pub sanitize:    Sanitize,
+	#[serde(default)]
+	pub sanitize_fs: HashMap<String, Sanitize>,
+
+	// Tabs
+	pub sync_tabs: bool,
+
+	// Mouse, captured for the bindings in the keymap only if on
+	pub mouse: bool,
+
+	// Watching, by polling on the filesystems that don't notify of changes
+	#[serde(default)]
+	pub poll_fs:       Vec<String>,
+	#[validate(range(min = 100, message = "must be at least 100 milliseconds"))]
+	pub poll_interval: u64,
+
+	// Gathering the changes before refreshing, and the paths whose changes are ignored
+	pub watch_debounce: u64,
+	#[serde(default, skip_serializing)]
+	pub watch_ignore:   Vec<Pattern>,
+
+	// Caching, the least recently used entries are given up beyond these
+	#[validate(range(min = 1, message = "must be at least 1"))]
+	pub history_max:  usize,
+	#[validate(range(min = 1, message = "must be at least 1"))]
+	pub mimetype_max: usize,
 }
 
 impl Default for Manager {
@@ -28,9 +75,49 @@ impl Default for Manager {
 			manager: Manager,
 		}
 
-		let manager = toml::from_str::<Outer>(&MERGED_YAZI).unwrap().manager;
+		let mut manager = toml::from_str::<Outer>(&MERGED_YAZI).unwrap().manager;
+
+		// Overridden by the command-line arguments, for this run only
+		if let Some((by, reverse)) = &ARGS.sort {
+			manager.sort_by = by.parse().unwrap();
+			manager.sort_reverse = reverse.unwrap_or(manager.sort_reverse);
+		}
+		if ARGS.show_hidden {
+			manager.show_hidden = true;
+		}
+		if let Some(ratio) = ARGS.layout {
+			manager.layout = ManagerLayout::try_from(ratio.to_vec()).unwrap();
+		}
 
 		check_validation(manager.validate());
 		manager
 	}
 }
+
+impl Manager {
+	// The templates for `create`, in the config directory if not given
+	pub fn templates_dir(&self) -> Option<PathBuf> {
+		if self.templates.is_empty() {
+			Xdg::config_dir().map(|p| p.join("templates"))
+		} else {
+			Some(expand_path(&self.templates))
+		}
+	}
+
+	#[inline]
+	pub fn is_polled(&self, fs: Option<&str>) -> bool {
+		fs.is_some_and(|f| self.poll_fs.iter().any(|p| p == f))
+	}
+
+	// Only the path itself is matched, not its parents, so the changes inside an
+	// ignored directory still show up while it's being viewed
+	#[inline]
+	pub fn is_watch_ignored(&self, path: &Path) -> bool {
+		self.watch_ignore.iter().any(|i| i.match_path(path, None))
+	}
+
+	#[inline]
+	pub fn sanitize_for(&self, fs: Option<&str>) -> Sanitize {
+		fs.and_then(|f| self.sanitize_fs.get(f)).copied().unwrap_or(self.sanitize)
+	}
+}
@@ -1,7 +1,13 @@
 mod layout;
 mod manager;
+mod open_dir;
+mod sanitize;
 mod sorting;
+mod yank;
 
 pub use layout::*;
 pub use manager::*;
+pub use open_dir::*;
+pub use sanitize::*;
 pub use sorting::*;
+pub use yank::*;
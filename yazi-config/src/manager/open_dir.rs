@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum OpenDir {
+	// Follow the `[open]` rules, like any other file
+	#[default]
+	Rules,
+	// Enter the directory
+	Enter,
+	// Hand it over to the system, usually a GUI file manager
+	System,
+}
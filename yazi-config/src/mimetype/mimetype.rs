@@ -0,0 +1,34 @@
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::{Pattern, MERGED_YAZI};
+
+#[derive(Debug, Deserialize)]
+pub struct Mimetype {
+	rules: Vec<MimetypeRule>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MimetypeRule {
+	name: Pattern,
+	mime: String,
+}
+
+impl Default for Mimetype {
+	fn default() -> Self {
+		#[derive(Deserialize)]
+		struct Outer {
+			mimetype: Mimetype,
+		}
+
+		toml::from_str::<Outer>(&MERGED_YAZI).unwrap().mimetype
+	}
+}
+
+impl Mimetype {
+	// The mimetype forced on `path` by the first matching rule, if any
+	pub fn matches(&self, path: &Path) -> Option<&str> {
+		self.rules.iter().find(|r| r.name.match_path(path, None)).map(|r| r.mime.as_str())
+	}
+}
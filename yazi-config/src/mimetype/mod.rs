@@ -0,0 +1,3 @@
+mod mimetype;
+
+pub use mimetype::*;
@@ -0,0 +1,12 @@
+use serde::Deserialize;
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum Extract {
+	// Into the directory where the archive is
+	Here,
+	// Into a subdirectory named after the archive
+	Subdir,
+	// Ask for the destination every time
+	Prompt,
+}
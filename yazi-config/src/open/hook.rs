@@ -0,0 +1,24 @@
+use std::path::Path;
+
+use serde::Deserialize;
+use yazi_shared::MIME_DIR;
+
+use crate::pattern::Pattern;
+
+#[derive(Debug, Deserialize)]
+pub struct OpenHook {
+	name:     Option<Pattern>,
+	mime:     Option<Pattern>,
+	pub exec: String,
+	#[serde(default)]
+	pub ask:  bool,
+}
+
+impl OpenHook {
+	#[inline]
+	pub fn matches(&self, path: impl AsRef<Path>, mime: impl AsRef<str>) -> bool {
+		let is_folder = Some(mime.as_ref() == MIME_DIR);
+		self.mime.as_ref().is_some_and(|m| m.matches(&mime))
+			|| self.name.as_ref().is_some_and(|n| n.match_path(&path, is_folder))
+	}
+}
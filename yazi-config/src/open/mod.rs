@@ -1,7 +1,11 @@
+mod extract;
+mod hook;
 mod open;
 mod opener;
 mod rule;
 
+pub use extract::*;
+pub use hook::*;
 pub use open::*;
 pub use opener::*;
 use rule::*;
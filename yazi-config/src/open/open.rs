@@ -4,13 +4,14 @@ use indexmap::IndexSet;
 use serde::{Deserialize, Deserializer};
 use yazi_shared::MIME_DIR;
 
-use super::Opener;
+use super::{OpenHook, Opener};
 use crate::{open::OpenRule, MERGED_YAZI};
 
 #[derive(Debug)]
 pub struct Open {
 	rules:   Vec<OpenRule>,
 	openers: BTreeMap<String, IndexSet<Opener>>,
+	hooks:   Vec<OpenHook>,
 }
 
 impl Default for Open {
@@ -42,6 +43,11 @@ impl Open {
 		})
 	}
 
+	#[inline]
+	pub fn opener(&self, name: &str) -> Option<&Opener> {
+		self.openers.get(name).and_then(|o| o.first())
+	}
+
 	#[inline]
 	pub fn block_opener<P, M>(&self, path: P, mime: M) -> Option<&Opener>
 	where
@@ -51,6 +57,11 @@ impl Open {
 		self.openers(path, mime).and_then(|o| o.into_iter().find(|o| o.block))
 	}
 
+	#[inline]
+	pub fn hooks<'a>(&'a self, path: &'a Path, mime: &'a str) -> impl Iterator<Item = &'a OpenHook> {
+		self.hooks.iter().filter(move |h| h.matches(path, mime))
+	}
+
 	pub fn common_openers(&self, targets: &[(impl AsRef<Path>, impl AsRef<str>)]) -> Vec<&Opener> {
 		let grouped: Vec<_> = targets.iter().filter_map(|(p, m)| self.openers(p, m)).collect();
 		let flat: IndexSet<_> = grouped.iter().flatten().copied().collect();
@@ -71,6 +82,7 @@ impl<'de> Deserialize<'de> for Open {
 		#[derive(Deserialize)]
 		struct OuterOpen {
 			rules: Vec<OpenRule>,
+			hooks: Vec<OpenHook>,
 		}
 
 		let outer = Outer::deserialize(deserializer)?;
@@ -79,6 +91,6 @@ impl<'de> Deserialize<'de> for Open {
 			.into_iter()
 			.map(|(k, v)| (k, v.into_iter().filter_map(|o| o.take()).collect::<IndexSet<_>>()))
 			.collect();
-		Ok(Self { rules: outer.open.rules, openers })
+		Ok(Self { rules: outer.open.rules, openers, hooks: outer.open.hooks })
 	}
 }
@@ -1,13 +1,21 @@
+use std::ffi::OsString;
+
 use serde::{Deserialize, Deserializer};
 
+use super::Extract;
+
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Opener {
-	pub exec:   String,
-	pub block:  bool,
-	pub orphan: bool,
-	pub desc:   String,
-	pub for_:   Option<String>,
-	pub spread: bool,
+	pub exec:    String,
+	pub block:   bool,
+	pub orphan:  bool,
+	pub desc:    String,
+	pub for_:    Option<String>,
+	pub spread:  bool,
+	pub extract: Option<Extract>,
+
+	// `$0` of the command, only given at runtime, e.g. the hovered file of `shell`
+	pub arg0: Option<OsString>,
 }
 
 impl Opener {
@@ -32,14 +40,16 @@ impl<'de> Deserialize<'de> for Opener {
 	{
 		#[derive(Deserialize)]
 		pub struct Shadow {
-			exec:   String,
 			#[serde(default)]
-			block:  bool,
+			exec:    String,
+			extract: Option<Extract>,
+			#[serde(default)]
+			block:   bool,
 			#[serde(default)]
-			orphan: bool,
-			desc:   Option<String>,
+			orphan:  bool,
+			desc:    Option<String>,
 			#[serde(rename = "for")]
-			for_:   Option<String>,
+			for_:    Option<String>,
 
 			// TODO: remove this when v1.0.5 is released --
 			display_name: Option<String>,
@@ -47,8 +57,10 @@ impl<'de> Deserialize<'de> for Opener {
 		}
 
 		let mut shadow = Shadow::deserialize(deserializer)?;
-		if shadow.exec.is_empty() {
+		if shadow.exec.is_empty() && shadow.extract.is_none() {
 			return Err(serde::de::Error::custom("`exec` cannot be empty"));
+		} else if !shadow.exec.is_empty() && shadow.extract.is_some() {
+			return Err(serde::de::Error::custom("`exec` and `extract` cannot be used together"));
 		}
 
 		// TODO: remove this when v0.1.6 is released --
@@ -63,8 +75,10 @@ impl<'de> Deserialize<'de> for Opener {
 		}
 		// TODO: -- remove this when v0.1.6 is released
 
-		let desc =
-			shadow.desc.unwrap_or_else(|| shadow.exec.split_whitespace().next().unwrap().to_string());
+		let desc = shadow.desc.unwrap_or_else(|| match shadow.extract {
+			Some(_) => "Extract".to_string(),
+			None => shadow.exec.split_whitespace().next().unwrap().to_string(),
+		});
 
 		let spread =
 			shadow.exec.contains("$@") || shadow.exec.contains("%*") || shadow.exec.contains("$*");
@@ -75,6 +89,8 @@ impl<'de> Deserialize<'de> for Opener {
 			desc,
 			for_: shadow.for_,
 			spread,
+			extract: shadow.extract,
+			arg0: None,
 		})
 	}
 }
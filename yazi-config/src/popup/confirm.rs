@@ -0,0 +1,70 @@
+use serde::Deserialize;
+
+use super::{Offset, Origin};
+use crate::MERGED_YAZI;
+
+#[derive(Deserialize)]
+pub struct Confirm {
+	// trash
+	pub trash_title:  String,
+	pub trash_origin: Origin,
+	pub trash_offset: Offset,
+
+	// delete
+	pub delete_title:  String,
+	pub delete_origin: Origin,
+	pub delete_offset: Offset,
+
+	// replace
+	pub replace_title:  String,
+	pub replace_origin: Origin,
+	pub replace_offset: Offset,
+
+	// overwrite
+	pub overwrite_title:  String,
+	pub overwrite_origin: Origin,
+	pub overwrite_offset: Offset,
+
+	// quit
+	pub quit_title:  String,
+	pub quit_origin: Origin,
+	pub quit_offset: Offset,
+}
+
+impl Default for Confirm {
+	fn default() -> Self {
+		#[derive(Deserialize)]
+		struct Outer {
+			confirm: Confirm,
+			input:   Legacy,
+		}
+
+		// These titles were under `[input]` before, so the ones a user had set there
+		// are still taken, without the ` (y/N)` which is for an input
+		#[derive(Deserialize)]
+		struct Legacy {
+			trash_title:     Option<String>,
+			delete_title:    Option<String>,
+			overwrite_title: Option<String>,
+			quit_title:      Option<String>,
+		}
+
+		let Outer { mut confirm, input } = toml::from_str(&MERGED_YAZI).unwrap();
+		for (title, old) in [
+			(&mut confirm.trash_title, input.trash_title),
+			(&mut confirm.delete_title, input.delete_title),
+			(&mut confirm.overwrite_title, input.overwrite_title),
+			(&mut confirm.quit_title, input.quit_title),
+		] {
+			if let Some(old) = old {
+				*title = old.trim_end().trim_end_matches("(y/N)").trim_end().to_owned();
+			}
+		}
+		confirm
+	}
+}
+
+impl Confirm {
+	#[inline]
+	pub const fn border(&self) -> u16 { 2 }
+}
@@ -15,26 +15,51 @@ pub struct Input {
 	pub create_origin: Origin,
 	pub create_offset: Offset,
 
+	// download
+	pub download_title:  String,
+	pub download_origin: Origin,
+	pub download_offset: Offset,
+
+	// extract
+	pub extract_title:  String,
+	pub extract_origin: Origin,
+	pub extract_offset: Offset,
+
 	// rename
 	pub rename_title:  String,
 	pub rename_origin: Origin,
 	pub rename_offset: Offset,
 
-	// trash
-	pub trash_title:  String,
-	pub trash_origin: Origin,
-	pub trash_offset: Offset,
+	// replace
+	pub replace_title:  [String; 2],
+	pub replace_origin: Origin,
+	pub replace_offset: Offset,
+
+	// edit_link
+	pub edit_link_title:  String,
+	pub edit_link_origin: Origin,
+	pub edit_link_offset: Offset,
+
+	// dangling
+	pub dangling_title:  String,
+	pub dangling_origin: Origin,
+	pub dangling_offset: Offset,
 
-	// delete
-	pub delete_title:  String,
-	pub delete_origin: Origin,
-	pub delete_offset: Offset,
+	// link
+	pub link_title:  String,
+	pub link_origin: Origin,
+	pub link_offset: Offset,
 
 	// find
 	pub find_title:  [String; 2],
 	pub find_origin: Origin,
 	pub find_offset: Offset,
 
+	// select
+	pub select_title:  [String; 2],
+	pub select_origin: Origin,
+	pub select_offset: Offset,
+
 	// search
 	pub search_title:  String,
 	pub search_origin: Origin,
@@ -45,15 +70,30 @@ pub struct Input {
 	pub shell_origin: Origin,
 	pub shell_offset: Offset,
 
-	// overwrite
-	pub overwrite_title:  String,
-	pub overwrite_origin: Origin,
-	pub overwrite_offset: Offset,
+	// export
+	pub export_title:  [String; 2],
+	pub export_origin: Origin,
+	pub export_offset: Offset,
+
+	// sanitize
+	pub sanitize_title:  String,
+	pub sanitize_origin: Origin,
+	pub sanitize_offset: Offset,
+
+	// suspicious
+	pub suspicious_title:  String,
+	pub suspicious_origin: Origin,
+	pub suspicious_offset: Offset,
+
+	// xattr
+	pub xattr_title:  [String; 2],
+	pub xattr_origin: Origin,
+	pub xattr_offset: Offset,
 
-	// quit
-	pub quit_title:  String,
-	pub quit_origin: Origin,
-	pub quit_offset: Offset,
+	// own
+	pub own_title:  String,
+	pub own_origin: Origin,
+	pub own_offset: Offset,
 }
 
 impl Default for Input {
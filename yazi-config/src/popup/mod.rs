@@ -1,3 +1,4 @@
+mod confirm;
 mod input;
 mod offset;
 mod options;
@@ -5,6 +6,7 @@ mod origin;
 mod position;
 mod select;
 
+pub use confirm::*;
 pub use input::*;
 pub use offset::*;
 pub use options::*;
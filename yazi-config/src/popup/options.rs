@@ -1,5 +1,9 @@
-use super::{Offset, Position};
-use crate::{INPUT, SELECT};
+use std::{ops::Range, path::Path};
+
+use yazi_shared::Url;
+
+use super::{Offset, Origin, Position};
+use crate::{CONFIRM, INPUT, SELECT};
 
 #[derive(Default)]
 pub struct InputOpt {
@@ -9,6 +13,9 @@ pub struct InputOpt {
 	pub realtime:   bool,
 	pub completion: bool,
 	pub highlight:  bool,
+	pub history:    Option<&'static str>,
+	// Where the cursor starts, in chars, a non-empty range gets selected
+	pub cursor:     Option<Range<usize>>,
 }
 
 #[derive(Default)]
@@ -18,6 +25,14 @@ pub struct SelectOpt {
 	pub position: Position,
 }
 
+#[derive(Default)]
+pub struct ConfirmOpt {
+	pub title:    String,
+	pub content:  Vec<String>,
+	pub marked:   Vec<usize>,
+	pub position: Position,
+}
+
 impl InputOpt {
 	#[inline]
 	pub fn cd() -> Self {
@@ -25,15 +40,46 @@ impl InputOpt {
 			title: INPUT.cd_title.to_owned(),
 			position: Position::new(INPUT.cd_origin, INPUT.cd_offset),
 			completion: true,
+			history: Some("cd"),
 			..Default::default()
 		}
 	}
 
 	#[inline]
-	pub fn create() -> Self {
+	pub fn create(file: &str, dir: &str) -> Self {
 		Self {
-			title: INPUT.create_title.to_owned(),
+			title: INPUT.create_title.replace("{file}", file).replace("{dir}", dir),
 			position: Position::new(INPUT.create_origin, INPUT.create_offset),
+			completion: true,
+			..Default::default()
+		}
+	}
+
+	#[inline]
+	pub fn download() -> Self {
+		Self {
+			title: INPUT.download_title.to_owned(),
+			position: Position::new(INPUT.download_origin, INPUT.download_offset),
+			..Default::default()
+		}
+	}
+
+	#[inline]
+	pub fn extract() -> Self {
+		Self {
+			title: INPUT.extract_title.to_owned(),
+			position: Position::new(INPUT.extract_origin, INPUT.extract_offset),
+			completion: true,
+			..Default::default()
+		}
+	}
+
+	#[inline]
+	pub fn replace(pattern: bool) -> Self {
+		Self {
+			title: INPUT.replace_title[!pattern as usize].to_owned(),
+			position: Position::new(INPUT.replace_origin, INPUT.replace_offset),
+			history: if pattern { Some("replace") } else { None },
 			..Default::default()
 		}
 	}
@@ -43,26 +89,37 @@ impl InputOpt {
 		Self {
 			title: INPUT.rename_title.to_owned(),
 			position: Position::new(INPUT.rename_origin, INPUT.rename_offset),
+			completion: true,
 			..Default::default()
 		}
 	}
 
 	#[inline]
-	pub fn trash(n: usize) -> Self {
-		let title = INPUT.trash_title.replace("{n}", &n.to_string());
+	pub fn edit_link() -> Self {
 		Self {
-			title: title.replace("{s}", if n > 1 { "s" } else { "" }),
-			position: Position::new(INPUT.trash_origin, INPUT.trash_offset),
+			title: INPUT.edit_link_title.to_owned(),
+			position: Position::new(INPUT.edit_link_origin, INPUT.edit_link_offset),
 			..Default::default()
 		}
 	}
 
 	#[inline]
-	pub fn delete(n: usize) -> Self {
-		let title = INPUT.delete_title.replace("{n}", &n.to_string());
+	pub fn dangling() -> Self {
 		Self {
-			title: title.replace("{s}", if n > 1 { "s" } else { "" }),
-			position: Position::new(INPUT.delete_origin, INPUT.delete_offset),
+			title: INPUT.dangling_title.to_owned(),
+			position: Position::new(INPUT.dangling_origin, INPUT.dangling_offset),
+			..Default::default()
+		}
+	}
+
+	#[inline]
+	pub fn link(n: usize, target: &Path) -> Self {
+		let title = INPUT.link_title.replace("{n}", &n.to_string());
+		Self {
+			title: title
+				.replace("{s}", if n > 1 { "s" } else { "" })
+				.replace("{target}", &target.to_string_lossy()),
+			position: Position::new(INPUT.link_origin, INPUT.link_offset),
 			..Default::default()
 		}
 	}
@@ -73,6 +130,17 @@ impl InputOpt {
 			title: INPUT.find_title[prev as usize].to_owned(),
 			position: Position::new(INPUT.find_origin, INPUT.find_offset),
 			realtime: true,
+			history: Some("find"),
+			..Default::default()
+		}
+	}
+
+	#[inline]
+	pub fn select(deselect: bool) -> Self {
+		Self {
+			title: INPUT.select_title[deselect as usize].to_owned(),
+			position: Position::new(INPUT.select_origin, INPUT.select_offset),
+			history: Some("select"),
 			..Default::default()
 		}
 	}
@@ -82,6 +150,7 @@ impl InputOpt {
 		Self {
 			title: INPUT.search_title.to_owned(),
 			position: Position::new(INPUT.search_origin, INPUT.search_offset),
+			history: Some("search"),
 			..Default::default()
 		}
 	}
@@ -92,52 +161,189 @@ impl InputOpt {
 			title: INPUT.shell_title[block as usize].to_owned(),
 			position: Position::new(INPUT.shell_origin, INPUT.shell_offset),
 			highlight: true,
+			history: Some("shell"),
 			..Default::default()
 		}
 	}
 
 	#[inline]
-	pub fn overwrite() -> Self {
+	pub fn export(pipe: bool) -> Self {
 		Self {
-			title: INPUT.overwrite_title.to_owned(),
-			position: Position::new(INPUT.overwrite_origin, INPUT.overwrite_offset),
+			title: INPUT.export_title[pipe as usize].to_owned(),
+			position: Position::new(INPUT.export_origin, INPUT.export_offset),
+			highlight: pipe,
+			..Default::default()
+		}
+	}
+
+	#[inline]
+	pub fn sanitize(fs: &str, name: &str) -> Self {
+		let title = INPUT.sanitize_title.replace("{fs}", fs);
+		Self {
+			title: title.replace("{name}", name),
+			position: Position::new(INPUT.sanitize_origin, INPUT.sanitize_offset),
+			..Default::default()
+		}
+	}
+
+	#[inline]
+	pub fn suspicious(name: &str) -> Self {
+		Self {
+			title: INPUT.suspicious_title.replace("{name}", name),
+			position: Position::new(INPUT.suspicious_origin, INPUT.suspicious_offset),
+			..Default::default()
+		}
+	}
+
+	#[inline]
+	pub fn own() -> Self {
+		Self {
+			title: INPUT.own_title.to_owned(),
+			position: Position::new(INPUT.own_origin, INPUT.own_offset),
+			completion: true,
 			..Default::default()
 		}
 	}
 
+	// Setting the value of `attr`, or adding a new attribute if `None`
 	#[inline]
-	pub fn quit(n: usize) -> Self {
-		let title = INPUT.quit_title.replace("{n}", &n.to_string());
+	pub fn xattr(attr: Option<&str>) -> Self {
 		Self {
-			title: title.replace("{s}", if n > 1 { "s" } else { "" }),
-			position: Position::new(INPUT.quit_origin, INPUT.quit_offset),
+			title: match attr {
+				Some(attr) => INPUT.xattr_title[0].replace("{attr}", attr),
+				None => INPUT.xattr_title[1].to_owned(),
+			},
+			position: Position::new(INPUT.xattr_origin, INPUT.xattr_offset),
 			..Default::default()
 		}
 	}
 
+	#[inline]
+	pub fn with_title(mut self, title: impl Into<String>) -> Self {
+		self.title = title.into();
+		self
+	}
+
 	#[inline]
 	pub fn with_value(mut self, value: impl Into<String>) -> Self {
 		self.value = value.into();
 		self
 	}
+
+	#[inline]
+	pub fn with_cursor(mut self, cursor: Range<usize>) -> Self {
+		self.cursor = Some(cursor);
+		self
+	}
 }
 
 impl SelectOpt {
+	// Any list of candidates can be picked from, the popup shrinks to fit the
+	// items but never grows beyond the height of the offset
 	#[inline]
-	fn max_height(len: usize) -> u16 {
-		SELECT.open_offset.height.min(SELECT.border().saturating_add(len as u16))
+	pub fn new(title: impl Into<String>, items: Vec<String>, origin: Origin, offset: Offset) -> Self {
+		let height = offset.height.min(SELECT.border().saturating_add(items.len() as u16));
+		Self {
+			title: title.into(),
+			items,
+			position: Position::new(origin, Offset { height, ..offset }),
+		}
 	}
 
 	#[inline]
 	pub fn open(items: Vec<String>) -> Self {
-		let max_height = Self::max_height(items.len());
+		Self::new(&SELECT.open_title, items, SELECT.open_origin, SELECT.open_offset)
+	}
+
+	#[inline]
+	pub fn tab(items: Vec<String>) -> Self {
+		Self::new(&SELECT.tab_title, items, SELECT.tab_origin, SELECT.tab_offset)
+	}
+
+	#[inline]
+	pub fn zoxide(items: Vec<String>) -> Self {
+		Self::new(&SELECT.zoxide_title, items, SELECT.zoxide_origin, SELECT.zoxide_offset)
+	}
+
+	#[inline]
+	pub fn info(name: &str, items: Vec<String>) -> Self {
+		let title = SELECT.info_title.replace("{name}", name);
+		Self::new(title, items, SELECT.info_origin, SELECT.info_offset)
+	}
+
+	#[inline]
+	pub fn xattr(name: &str, items: Vec<String>) -> Self {
+		let title = SELECT.xattr_title.replace("{name}", name);
+		Self::new(title, items, SELECT.xattr_origin, SELECT.xattr_offset)
+	}
+
+	#[inline]
+	pub fn xattr_action(attr: &str) -> Self {
+		let items = SELECT.xattr_action.to_vec();
+		Self::new(attr, items, SELECT.xattr_origin, SELECT.xattr_offset)
+	}
+}
+
+impl ConfirmOpt {
+	#[inline]
+	fn plural(title: &str, n: usize) -> String {
+		title.replace("{n}", &n.to_string()).replace("{s}", if n > 1 { "s" } else { "" })
+	}
+
+	#[inline]
+	pub fn trash(urls: &[Url]) -> Self {
 		Self {
-			title: SELECT.open_title.to_owned(),
-			items,
-			position: Position::new(SELECT.open_origin, Offset {
-				height: max_height,
-				..SELECT.open_offset
-			}),
+			title:    Self::plural(&CONFIRM.trash_title, urls.len()),
+			content:  urls.iter().map(|u| u.to_string_lossy().into_owned()).collect(),
+			marked:   Default::default(),
+			position: Position::new(CONFIRM.trash_origin, CONFIRM.trash_offset),
+		}
+	}
+
+	#[inline]
+	pub fn delete(urls: &[Url]) -> Self {
+		Self {
+			title:    Self::plural(&CONFIRM.delete_title, urls.len()),
+			content:  urls.iter().map(|u| u.to_string_lossy().into_owned()).collect(),
+			marked:   Default::default(),
+			position: Position::new(CONFIRM.delete_origin, CONFIRM.delete_offset),
+		}
+	}
+
+	// Lines at the `marked` indices are the conflicting ones, which get skipped
+	#[inline]
+	pub fn replace(content: Vec<String>, marked: Vec<usize>) -> Self {
+		Self {
+			title: Self::plural(&CONFIRM.replace_title, content.len() - marked.len()),
+			content,
+			marked,
+			position: Position::new(CONFIRM.replace_origin, CONFIRM.replace_offset),
 		}
 	}
+
+	#[inline]
+	pub fn overwrite(path: &Path) -> Self {
+		Self {
+			title:    CONFIRM.overwrite_title.to_owned(),
+			content:  vec![path.to_string_lossy().into_owned()],
+			marked:   Default::default(),
+			position: Position::new(CONFIRM.overwrite_origin, CONFIRM.overwrite_offset),
+		}
+	}
+
+	#[inline]
+	pub fn quit(names: Vec<String>) -> Self {
+		Self {
+			title:    Self::plural(&CONFIRM.quit_title, names.len()),
+			content:  names,
+			marked:   Default::default(),
+			position: Position::new(CONFIRM.quit_origin, CONFIRM.quit_offset),
+		}
+	}
+
+	#[inline]
+	pub fn with_marked(mut self, marked: Vec<usize>) -> Self {
+		self.marked = marked;
+		self
+	}
 }
@@ -9,6 +9,29 @@ pub struct Select {
 	pub open_title:  String,
 	pub open_origin: Origin,
 	pub open_offset: Offset,
+	pub open_custom: String,
+
+	// tab
+	pub tab_title:  String,
+	pub tab_origin: Origin,
+	pub tab_offset: Offset,
+
+	// zoxide
+	pub zoxide_title:  String,
+	pub zoxide_origin: Origin,
+	pub zoxide_offset: Offset,
+
+	// info
+	pub info_title:  String,
+	pub info_origin: Origin,
+	pub info_offset: Offset,
+
+	// xattr
+	pub xattr_title:  String,
+	pub xattr_origin: Origin,
+	pub xattr_offset: Offset,
+	pub xattr_custom: String,
+	pub xattr_action: [String; 2],
 }
 
 impl Default for Select {
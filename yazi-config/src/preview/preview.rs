@@ -1,21 +1,35 @@
-use std::{path::{Path, PathBuf}, time::{self, SystemTime}};
+use std::{path::{Path, PathBuf}, time::{self, Duration, SystemTime}};
 
 use md5::{Digest, Md5};
 use serde::Deserialize;
 use yazi_shared::expand_path;
 
-use crate::{xdg::Xdg, MERGED_YAZI};
+use crate::{xdg::Xdg, Pattern, MERGED_YAZI};
 
 #[derive(Debug)]
 pub struct Preview {
-	pub tab_size:   u8,
+	pub tab_size:        u8,
+	pub highlight_lines: Option<usize>,
+	pub json_fold:       Option<usize>,
+	pub huge_size:       Option<u64>,
+	pub hexdump_size:    Option<u64>,
+
 	pub max_width:  u32,
 	pub max_height: u32,
 
-	pub cache_dir: PathBuf,
+	pub cache_dir:  PathBuf,
+	pub cache_size: Option<u64>,
 
+	pub adaptor:         String,
 	pub ueberzug_scale:  f32,
 	pub ueberzug_offset: (f32, f32, f32, f32),
+
+	pub video_seek: u8,
+	pub pdf_jump:   usize,
+
+	pub debounce: Option<Duration>,
+	pub timeout:  Option<Duration>,
+	slow_paths:   Vec<Pattern>,
 }
 
 impl Default for Preview {
@@ -26,14 +40,28 @@ impl Default for Preview {
 		}
 		#[derive(Deserialize)]
 		struct Shadow {
-			tab_size:   u8,
+			tab_size:        u8,
+			highlight_lines: usize,
+			json_fold:       usize,
+			huge_size:       u64,
+			hexdump_size:    u64,
+
 			max_width:  u32,
 			max_height: u32,
 
-			cache_dir: Option<String>,
+			cache_dir:  Option<String>,
+			cache_size: u64,
 
+			adaptor:         String,
 			ueberzug_scale:  f32,
 			ueberzug_offset: (f32, f32, f32, f32),
+
+			video_seek: u8,
+			pdf_jump:   usize,
+
+			debounce:   u64,
+			timeout:    u64,
+			slow_paths: Vec<Pattern>,
 		}
 
 		let preview = toml::from_str::<Outer>(&MERGED_YAZI).unwrap().preview;
@@ -43,23 +71,41 @@ impl Default for Preview {
 
 		Preview {
 			tab_size: preview.tab_size,
+			highlight_lines: Some(preview.highlight_lines).filter(|&n| n > 0),
+			json_fold: Some(preview.json_fold).filter(|&n| n > 0),
+			huge_size: Some(preview.huge_size).filter(|&n| n > 0),
+			hexdump_size: Some(preview.hexdump_size).filter(|&n| n > 0),
+
 			max_width: preview.max_width,
 			max_height: preview.max_height,
 
 			cache_dir,
+			cache_size: Some(preview.cache_size).filter(|&n| n > 0),
 
+			adaptor: preview.adaptor,
 			ueberzug_scale: preview.ueberzug_scale,
 			ueberzug_offset: preview.ueberzug_offset,
+
+			video_seek: preview.video_seek.min(95),
+			pdf_jump: preview.pdf_jump.max(1),
+
+			debounce: Some(preview.debounce).filter(|&t| t > 0).map(Duration::from_millis),
+			timeout: Some(preview.timeout).filter(|&t| t > 0).map(Duration::from_millis),
+			slow_paths: preview.slow_paths,
 		}
 	}
 }
 
 impl Preview {
+	// Keyed by a hash of the contents, see `Cache::path()` in the core
+	pub fn cache(&self, hash: &str, skip: usize) -> PathBuf {
+		let key = format!("{hash}///{skip}");
+		self.cache_dir.join(format!("{:x}", Md5::new_with_prefix(key).finalize()))
+	}
+
 	#[inline]
-	pub fn cache(&self, path: &Path, skip: usize) -> PathBuf {
-		self
-			.cache_dir
-			.join(format!("{:x}", Md5::new_with_prefix(format!("{:?}///{}", path, skip)).finalize()))
+	pub fn is_slow(&self, path: &Path) -> bool {
+		self.slow_paths.iter().any(|p| p.match_path(path, None))
 	}
 
 	#[inline]
@@ -0,0 +1,5 @@
+mod previewer;
+mod previewers;
+
+pub use previewer::*;
+pub use previewers::*;
@@ -0,0 +1,82 @@
+use std::{path::Path, time::Duration};
+
+use serde::{Deserialize, Deserializer};
+use yazi_shared::{MimeKind, MIME_DIR};
+
+use crate::pattern::Pattern;
+
+#[derive(Debug)]
+pub struct Previewer {
+	name:     Option<Pattern>,
+	mime:     Option<Pattern>,
+	pub use_: PreviewerUse,
+	// In milliseconds, and 0 for no limit, instead of the `[preview] timeout`
+	timeout:  Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PreviewerUse {
+	// One of the built-in previewers
+	Builtin(MimeKind),
+	// A shell command, whose output is shown as text
+	Exec(String),
+	// A previewer registered by a Lua plugin, whose returned text is shown
+	Plugin(String),
+}
+
+impl Previewer {
+	#[inline]
+	pub fn matches(&self, path: impl AsRef<Path>, mime: impl AsRef<str>) -> bool {
+		let is_folder = Some(mime.as_ref() == MIME_DIR);
+		self.mime.as_ref().is_some_and(|m| m.matches(&mime))
+			|| self.name.as_ref().is_some_and(|n| n.match_path(&path, is_folder))
+	}
+
+	#[inline]
+	pub fn timeout(&self) -> Option<Option<Duration>> {
+		self.timeout.map(|t| Some(t).filter(|&t| t > 0).map(Duration::from_millis))
+	}
+}
+
+impl<'de> Deserialize<'de> for Previewer {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		#[derive(Deserialize)]
+		struct Shadow {
+			name:    Option<Pattern>,
+			mime:    Option<Pattern>,
+			exec:    Option<String>,
+			builtin: Option<String>,
+			plugin:  Option<String>,
+			timeout: Option<u64>,
+		}
+
+		let shadow = Shadow::deserialize(deserializer)?;
+		let use_ = match (shadow.exec, shadow.builtin, shadow.plugin) {
+			(Some(exec), None, None) if !exec.is_empty() => PreviewerUse::Exec(exec),
+			(None, None, Some(plugin)) if !plugin.is_empty() => PreviewerUse::Plugin(plugin),
+			(None, Some(builtin), None) => PreviewerUse::Builtin(match builtin.as_str() {
+				"archive" => MimeKind::Archive,
+				"image" => MimeKind::Image,
+				"video" => MimeKind::Video,
+				"audio" => MimeKind::Audio,
+				"font" => MimeKind::Font,
+				"json" => MimeKind::JSON,
+				"pdf" => MimeKind::PDF,
+				"sqlite" => MimeKind::SQLite,
+				"table" => MimeKind::Table,
+				"text" => MimeKind::Text,
+				"hex" => MimeKind::Others,
+				"none" => MimeKind::Empty,
+				s => return Err(serde::de::Error::custom(format!("unknown builtin previewer: {s}"))),
+			}),
+			_ => {
+				return Err(serde::de::Error::custom("one of `exec`, `builtin` or `plugin` must be given"));
+			}
+		};
+
+		Ok(Self { name: shadow.name, mime: shadow.mime, use_, timeout: shadow.timeout })
+	}
+}
@@ -0,0 +1,35 @@
+use std::{path::Path, time::Duration};
+
+use serde::Deserialize;
+
+use super::{Previewer, PreviewerUse};
+use crate::{MERGED_YAZI, PREVIEW};
+
+#[derive(Debug, Deserialize)]
+pub struct Previewers {
+	rules: Vec<Previewer>,
+}
+
+impl Default for Previewers {
+	fn default() -> Self {
+		#[derive(Deserialize)]
+		struct Outer {
+			previewers: Previewers,
+		}
+
+		toml::from_str::<Outer>(&MERGED_YAZI).unwrap().previewers
+	}
+}
+
+impl Previewers {
+	#[inline]
+	pub fn matches(&self, path: impl AsRef<Path>, mime: impl AsRef<str>) -> Option<&PreviewerUse> {
+		self.rules.iter().find(|r| r.matches(&path, &mime)).map(|r| &r.use_)
+	}
+
+	// The timeout of the matching rule, or the `[preview] timeout` if it has none
+	pub fn timeout(&self, path: impl AsRef<Path>, mime: impl AsRef<str>) -> Option<Duration> {
+		let rule = self.rules.iter().find(|r| r.matches(&path, &mime));
+		rule.and_then(|r| r.timeout()).unwrap_or(PREVIEW.timeout)
+	}
+}
@@ -1,3 +1,7 @@
+mod normalize;
+mod paste_order;
 mod tasks;
 
+pub use normalize::*;
+pub use paste_order::*;
 pub use tasks::*;
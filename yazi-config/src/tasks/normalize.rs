@@ -0,0 +1,39 @@
+use serde::{Deserialize, Deserializer};
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Normalize {
+	pub name:         String,
+	#[serde(default, deserialize_with = "Normalize::deserialize_mode")]
+	pub dir_mode:     Option<u32>,
+	#[serde(default, deserialize_with = "Normalize::deserialize_mode")]
+	pub file_mode:    Option<u32>,
+	#[serde(default)]
+	pub strip_setuid: bool,
+	// "user", or "user:group"
+	pub owner:        Option<String>,
+}
+
+impl Normalize {
+	fn deserialize_mode<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		let Some(s) = Option::<String>::deserialize(deserializer)? else {
+			return Ok(None);
+		};
+
+		match u32::from_str_radix(&s, 8) {
+			Ok(m) if m <= 0o7777 => Ok(Some(m)),
+			_ => Err(serde::de::Error::custom(format!("invalid octal mode: {s:?}"))),
+		}
+	}
+
+	#[inline]
+	pub fn owner(&self) -> (Option<&str>, Option<&str>) {
+		match self.owner.as_deref().map(|s| s.split_once(':')) {
+			Some(Some((u, g))) => ((!u.is_empty()).then_some(u), (!g.is_empty()).then_some(g)),
+			Some(None) => (self.owner.as_deref(), None),
+			None => (None, None),
+		}
+	}
+}
@@ -0,0 +1,32 @@
+use std::str::FromStr;
+
+use anyhow::bail;
+use serde::Deserialize;
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "String")]
+pub enum PasteOrder {
+	#[default]
+	Dir,
+	Smallest,
+	Largest,
+}
+
+impl FromStr for PasteOrder {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(match s {
+			"dir" => Self::Dir,
+			"smallest" => Self::Smallest,
+			"largest" => Self::Largest,
+			_ => bail!("invalid paste_order value: {s}"),
+		})
+	}
+}
+
+impl TryFrom<String> for PasteOrder {
+	type Error = anyhow::Error;
+
+	fn try_from(s: String) -> Result<Self, Self::Error> { Self::from_str(&s) }
+}
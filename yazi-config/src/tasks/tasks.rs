@@ -1,6 +1,7 @@
 use serde::Deserialize;
 use validator::Validate;
 
+use super::{Normalize, PasteOrder};
 use crate::{validation::check_validation, MERGED_YAZI};
 
 #[derive(Debug, Deserialize, Validate)]
@@ -12,8 +13,18 @@ pub struct Tasks {
 	#[validate(range(min = 1, message = "Cannot be less than 1"))]
 	pub bizarre_retry: u8,
 
+	#[validate(range(min = 1, message = "Cannot be less than 1"))]
+	pub parallel_copy:      u8,
+	pub parallel_copy_size: u64,
+
+	pub paste_order: PasteOrder,
+	pub paste_batch: usize,
+
 	pub image_alloc: u32,
 	pub image_bound: [u16; 2],
+
+	#[serde(default)]
+	pub normalize: Vec<Normalize>,
 }
 
 impl Default for Tasks {
@@ -1,19 +1,60 @@
 use std::path::Path;
 
 use serde::{Deserialize, Deserializer};
+use yazi_shared::Cha;
 
 use super::{Color, Style, StyleShadow};
 use crate::Pattern;
 
 pub struct Filetype {
+	pub is:    Option<FiletypeIs>,
 	pub name:  Option<Pattern>,
 	pub mime:  Option<Pattern>,
 	pub style: Style,
 }
 
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum FiletypeIs {
+	Block,
+	Char,
+	Exec,
+	Fifo,
+	Link,
+	Orphan,
+	Sock,
+}
+
+impl FiletypeIs {
+	pub fn check(self, cha: &Cha) -> bool {
+		match self {
+			#[cfg(unix)]
+			Self::Block => cha.is_block_device(),
+			#[cfg(unix)]
+			Self::Char => cha.is_char_device(),
+			#[cfg(unix)]
+			Self::Exec => cha.is_exec(),
+			#[cfg(unix)]
+			Self::Fifo => cha.is_fifo(),
+			#[cfg(unix)]
+			Self::Sock => cha.is_socket(),
+			#[cfg(windows)]
+			Self::Block | Self::Char | Self::Exec | Self::Fifo | Self::Sock => false,
+			Self::Link => cha.is_link(),
+			Self::Orphan => cha.is_bad_link(),
+		}
+	}
+}
+
 impl Filetype {
-	pub fn matches(&self, path: &Path, mime: Option<impl AsRef<str>>, is_dir: bool) -> bool {
-		if self.name.as_ref().is_some_and(|e| e.match_path(path, Some(is_dir))) {
+	pub fn matches(&self, path: &Path, mime: Option<impl AsRef<str>>, cha: &Cha) -> bool {
+		if self.is.is_some_and(|i| !i.check(cha)) {
+			return false;
+		} else if self.is.is_some() && self.name.is_none() && self.mime.is_none() {
+			return true;
+		}
+
+		if self.name.as_ref().is_some_and(|e| e.match_path(path, Some(cha.is_dir()))) {
 			return true;
 		}
 		if let Some(mime) = mime {
@@ -34,6 +75,7 @@ impl Filetype {
 		}
 		#[derive(Deserialize)]
 		struct FiletypeRule {
+			is:   Option<FiletypeIs>,
 			name: Option<Pattern>,
 			mime: Option<Pattern>,
 
@@ -64,6 +106,7 @@ impl Filetype {
 				.rules
 				.into_iter()
 				.map(|r| Filetype {
+					is:    r.is,
 					name:  r.name,
 					mime:  r.mime,
 					style: StyleShadow {
@@ -1,13 +1,27 @@
-use std::fmt;
+use std::{fmt, path::Path};
 
-use serde::{de::{self, Visitor}, Deserializer};
+use serde::{de::{self, Visitor}, Deserialize, Deserializer};
 
+use super::{Style, StyleShadow};
 use crate::Pattern;
 
-#[derive(Debug)]
 pub struct Icon {
-	pub name:    Pattern,
+	pub name:    Option<Pattern>,
+	pub mime:    Option<Pattern>,
 	pub display: String,
+	pub style:   Option<Style>,
+}
+
+impl Icon {
+	pub fn matches(&self, path: &Path, mime: Option<impl AsRef<str>>, is_dir: bool) -> bool {
+		if self.name.as_ref().is_some_and(|n| n.match_path(path, Some(is_dir))) {
+			return true;
+		}
+		if let Some(mime) = mime.filter(|_| !is_dir) {
+			return self.mime.as_ref().is_some_and(|m| m.matches(mime));
+		}
+		false
+	}
 }
 
 impl Icon {
@@ -15,13 +29,24 @@ impl Icon {
 	where
 		D: Deserializer<'de>,
 	{
+		#[derive(Deserialize)]
+		#[serde(untagged)]
+		enum IconValue {
+			Text(String),
+			Styled {
+				text:  String,
+				#[serde(flatten)]
+				style: StyleShadow,
+			},
+		}
+
 		struct IconVisitor;
 
 		impl<'de> Visitor<'de> for IconVisitor {
 			type Value = Vec<Icon>;
 
 			fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-				formatter.write_str("a icon rule, e.g. \"*.md\"  = \"\"")
+				formatter.write_str("a icon rule, e.g. \"*.md\"  = \"\"")
 			}
 
 			fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
@@ -29,11 +54,28 @@ impl Icon {
 				A: de::MapAccess<'de>,
 			{
 				let mut icons = Vec::new();
-				while let Some((key, value)) = &map.next_entry::<String, String>()? {
+				while let Some((key, value)) = map.next_entry::<String, IconValue>()? {
+					let (name, mime) = match key.strip_prefix("mime:") {
+						Some(m) => (None, Some(m.to_owned())),
+						None => (Some(key), None),
+					};
+
+					let (display, style) = match value {
+						IconValue::Text(text) => (text, None),
+						IconValue::Styled { text, style } => (text, Some(style.into())),
+					};
+
 					icons.push(Icon {
-						name:    Pattern::try_from(key.clone())
+						name: name
+							.map(Pattern::try_from)
+							.transpose()
+							.map_err(|e| de::Error::custom(e.to_string()))?,
+						mime: mime
+							.map(Pattern::try_from)
+							.transpose()
 							.map_err(|e| de::Error::custom(e.to_string()))?,
-						display: value.clone(),
+						display,
+						style,
 					});
 				}
 				Ok(icons)
@@ -24,6 +24,16 @@ pub struct Manager {
 	marker_copied:   Style,
 	marker_cut:      Style,
 
+	// Yanked
+	yanked_copied: Style,
+	yanked_cut:    Style,
+
+	// Git
+	git_modified:  Style,
+	git_staged:    Style,
+	git_untracked: Style,
+	git_ignored:   Style,
+
 	// Tab
 	tab_active:   Style,
 	tab_inactive: Style,
@@ -64,6 +74,9 @@ struct Status {
 	pub permissions_w: Style,
 	pub permissions_x: Style,
 	pub permissions_s: Style,
+
+	// Watcher
+	pub stale: Style,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -81,6 +94,16 @@ pub struct Select {
 	pub inactive: Style,
 }
 
+#[derive(Deserialize, Serialize)]
+pub struct Confirm {
+	pub border:       Style,
+	pub title:        Style,
+	pub content:      Style,
+	pub marked:       Style,
+	pub btn_active:   Style,
+	pub btn_inactive: Style,
+}
+
 #[derive(Deserialize, Serialize)]
 pub struct Completion {
 	pub border:   Style,
@@ -126,6 +149,7 @@ pub struct Theme {
 	status:         Status,
 	pub input:      Input,
 	pub select:     Select,
+	pub confirm:    Confirm,
 	pub completion: Completion,
 	pub tasks:      Tasks,
 	pub which:      Which,
@@ -39,6 +39,15 @@ impl Xdg {
 		}
 	}
 
-	#[inline]
-	pub(super) fn cache_dir() -> PathBuf { env::temp_dir().join("yazi") }
+	pub(super) fn cache_dir() -> PathBuf {
+		#[cfg(windows)]
+		let dir = dirs::cache_dir();
+		#[cfg(unix)]
+		let dir = env::var_os("XDG_CACHE_HOME")
+			.map(PathBuf::from)
+			.filter(|p| p.is_absolute())
+			.or_else(|| dirs::home_dir().map(|h| h.join(".cache")));
+
+		dir.unwrap_or_else(env::temp_dir).join("yazi")
+	}
 }
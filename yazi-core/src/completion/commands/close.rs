@@ -18,8 +18,11 @@ impl Completion {
 
 	pub fn close(&mut self, opt: impl Into<Opt>) -> bool {
 		let opt = opt.into() as Opt;
+		// Like a shell, complete the common prefix of the candidates first,
+		// unless one of them has been picked
 		if opt.submit {
-			Input::_complete(self.selected(), self.ticket);
+			let word = self.common_prefix().filter(|_| self.cursor == 0).unwrap_or(self.selected());
+			Input::_complete(word, self.ticket);
 		}
 
 		self.caches.clear();
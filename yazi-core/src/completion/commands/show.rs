@@ -69,6 +69,7 @@ impl Completion {
 
 		self.ticket = opt.ticket;
 		self.cands = Self::match_candidates(opt.word, cache);
+		self.word = opt.word.to_owned();
 		if self.cands.is_empty() {
 			return mem::replace(&mut self.visible, false);
 		}
@@ -2,6 +2,7 @@ use std::{mem, path::{MAIN_SEPARATOR, MAIN_SEPARATOR_STR}};
 
 use tokio::fs;
 use yazi_config::keymap::{Exec, KeymapLayer};
+use yazi_shared::expand_path;
 
 use crate::{completion::Completion, emit};
 
@@ -48,7 +49,7 @@ impl Completion {
 
 		let ticket = self.ticket;
 		tokio::spawn(async move {
-			let mut dir = fs::read_dir(&parent).await?;
+			let mut dir = fs::read_dir(expand_path(&parent)).await?;
 			let mut cache = Vec::new();
 			while let Ok(Some(f)) = dir.next_entry().await {
 				let Ok(meta) = f.metadata().await else {
@@ -4,6 +4,7 @@ use std::collections::BTreeMap;
 pub struct Completion {
 	pub(super) caches: BTreeMap<String, Vec<String>>,
 	pub(super) cands:  Vec<String>,
+	pub(super) word:   String,
 	pub(super) offset: usize,
 	pub cursor:        usize,
 
@@ -25,6 +26,20 @@ impl Completion {
 	#[inline]
 	pub fn selected(&self) -> &String { &self.cands[self.cursor] }
 
+	// The longest prefix shared by all the candidates, if it's longer than the word
+	pub(super) fn common_prefix(&self) -> Option<&str> {
+		let first = self.cands.first()?;
+		let mut end = self.cands.iter().skip(1).fold(first.len(), |end, s| {
+			first.bytes().zip(s.bytes()).take(end).take_while(|(a, b)| a == b).count()
+		});
+		while !first.is_char_boundary(end) {
+			end -= 1;
+		}
+
+		let prefix = &first[..end];
+		(prefix.len() > self.word.len() && prefix.starts_with(&self.word)).then_some(prefix)
+	}
+
 	// --- Cursor
 	#[inline]
 	pub fn rel_cursor(&self) -> usize { self.cursor - self.offset }
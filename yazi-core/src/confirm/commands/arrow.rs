@@ -0,0 +1,26 @@
+use yazi_config::keymap::Exec;
+
+use crate::confirm::Confirm;
+
+pub struct Opt {
+	step: isize,
+}
+
+impl From<&Exec> for Opt {
+	fn from(e: &Exec) -> Self {
+		Self { step: e.args.first().and_then(|s| s.parse().ok()).unwrap_or(0) }
+	}
+}
+
+impl Confirm {
+	// There are only two buttons, so any odd step switches the focus
+	pub fn arrow(&mut self, opt: impl Into<Opt>) -> bool {
+		let opt = opt.into() as Opt;
+		if opt.step % 2 == 0 {
+			return false;
+		}
+
+		self.yes = !self.yes;
+		true
+	}
+}
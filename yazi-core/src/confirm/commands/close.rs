@@ -0,0 +1,30 @@
+use yazi_config::keymap::Exec;
+
+use crate::confirm::Confirm;
+
+pub struct Opt {
+	submit:  bool,
+	focused: bool,
+}
+
+impl From<&Exec> for Opt {
+	fn from(e: &Exec) -> Self {
+		Self { submit: e.named.contains_key("submit"), focused: e.named.contains_key("focused") }
+	}
+}
+impl From<bool> for Opt {
+	fn from(submit: bool) -> Self { Self { submit, focused: false } }
+}
+
+impl Confirm {
+	pub fn close(&mut self, opt: impl Into<Opt>) -> bool {
+		let opt = opt.into() as Opt;
+		if let Some(cb) = self.callback.take() {
+			_ = cb.send(opt.submit || (opt.focused && self.yes));
+		}
+
+		self.yes = false;
+		self.visible = false;
+		true
+	}
+}
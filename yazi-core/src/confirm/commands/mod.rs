@@ -0,0 +1,2 @@
+mod arrow;
+mod close;
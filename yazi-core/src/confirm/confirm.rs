@@ -0,0 +1,58 @@
+use tokio::sync::oneshot::Sender;
+use yazi_config::{popup::{ConfirmOpt, Position}, CONFIRM};
+
+#[derive(Default)]
+pub struct Confirm {
+	title:        String,
+	content:      Vec<String>,
+	marked:       Vec<usize>,
+	pub position: Position,
+
+	// Whether the "Yes" button is focused, the "No" one is by default
+	pub(super) yes:      bool,
+	pub(super) callback: Option<Sender<bool>>,
+
+	pub visible: bool,
+}
+
+impl Confirm {
+	pub fn show(&mut self, opt: ConfirmOpt, tx: Sender<bool>) {
+		self.close(false);
+
+		self.title = opt.title;
+		self.content = opt.content;
+		self.marked = opt.marked;
+		self.position = opt.position;
+
+		self.callback = Some(tx);
+		self.visible = true;
+	}
+
+	// The lines of the body that fit, the last one saying how many are left out,
+	// each along with whether it's marked
+	pub fn window(&self) -> Vec<(String, bool)> {
+		let limit = self.position.offset.height.saturating_sub(CONFIRM.border() + 2) as usize;
+		let take = if self.content.len() <= limit { limit } else { limit.saturating_sub(1) };
+
+		let mut lines: Vec<_> = self
+			.content
+			.iter()
+			.take(take)
+			.enumerate()
+			.map(|(i, s)| (s.clone(), self.marked.contains(&i)))
+			.collect();
+
+		if self.content.len() > limit {
+			lines.push((format!("... and {} more", self.content.len() - take), false));
+		}
+		lines
+	}
+}
+
+impl Confirm {
+	#[inline]
+	pub fn title(&self) -> String { self.title.clone() }
+
+	#[inline]
+	pub fn yes(&self) -> bool { self.yes }
+}
@@ -0,0 +1,4 @@
+mod commands;
+mod confirm;
+
+pub use confirm::*;
@@ -1,12 +1,13 @@
 use ratatui::prelude::Rect;
 use yazi_config::popup::{Origin, Position};
 
-use crate::{completion::Completion, help::Help, input::Input, manager::Manager, select::Select, tasks::Tasks, which::Which};
+use crate::{completion::Completion, confirm::Confirm, help::Help, input::Input, manager::Manager, select::Select, tasks::Tasks, which::Which};
 
 pub struct Ctx {
 	pub manager:    Manager,
 	pub tasks:      Tasks,
 	pub select:     Select,
+	pub confirm:    Confirm,
 	pub input:      Input,
 	pub help:       Help,
 	pub completion: Completion,
@@ -19,6 +20,7 @@ impl Ctx {
 			manager:    Manager::make(),
 			tasks:      Tasks::start(),
 			select:     Default::default(),
+			confirm:    Default::default(),
 			input:      Default::default(),
 			help:       Default::default(),
 			completion: Default::default(),
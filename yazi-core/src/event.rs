@@ -1,12 +1,12 @@
 use std::{collections::BTreeMap, ffi::OsString};
 
 use anyhow::Result;
-use crossterm::event::KeyEvent;
+use crossterm::event::{KeyEvent, MouseEvent};
 use tokio::sync::{mpsc::{self, UnboundedSender}, oneshot};
-use yazi_config::{keymap::{Exec, KeymapLayer}, open::Opener, popup::{InputOpt, SelectOpt}};
+use yazi_config::{keymap::{Exec, KeymapLayer}, open::Opener, popup::{ConfirmOpt, InputOpt, SelectOpt}};
 use yazi_shared::{InputError, RoCell, Url};
 
-use super::files::FilesOp;
+use super::files::{File, FilesOp};
 use crate::{preview::PreviewLock, tasks::TasksProgress};
 
 static TX: RoCell<UnboundedSender<Event>> = RoCell::new();
@@ -14,6 +14,7 @@ static TX: RoCell<UnboundedSender<Event>> = RoCell::new();
 pub enum Event {
 	Quit(bool), // no-cwd-file
 	Key(KeyEvent),
+	Mouse(MouseEvent),
 	Paste(String),
 	Render(String),
 	Resize(u16, u16),
@@ -26,10 +27,12 @@ pub enum Event {
 	Mimetype(BTreeMap<Url, String>),
 	Peek(Option<(usize, Url)>),
 	Preview(PreviewLock),
+	PluginPreview(String, File, String, oneshot::Sender<Result<String>>),
 
 	// Input
 	Select(SelectOpt, oneshot::Sender<Result<usize>>),
 	Input(InputOpt, mpsc::UnboundedSender<Result<String, InputError>>),
+	Confirm(ConfirmOpt, oneshot::Sender<bool>),
 
 	// Tasks
 	Open(Vec<(OsString, String)>, Option<Opener>),
@@ -89,6 +92,10 @@ macro_rules! emit {
 	(Preview($lock:expr)) => {
 		$crate::Event::Preview($lock).emit();
 	};
+	(PluginPreview($name:expr, $file:expr, $mime:expr)) => {{
+		let (tx, rx) = tokio::sync::oneshot::channel();
+		$crate::Event::PluginPreview($name, $file, $mime, tx).wait(rx)
+	}};
 
 	(Select($opt:expr)) => {{
 		let (tx, rx) = tokio::sync::oneshot::channel();
@@ -99,6 +106,10 @@ macro_rules! emit {
 		$crate::Event::Input($opt, tx).emit();
 		rx
 	}};
+	(Confirm($opt:expr)) => {{
+		let (tx, rx) = tokio::sync::oneshot::channel();
+		$crate::Event::Confirm($opt, tx).wait(rx)
+	}};
 
 	(Open($targets:expr, $opener:expr)) => {
 		$crate::Event::Open($targets, $opener).emit();
@@ -0,0 +1,117 @@
+use std::{fs::OpenOptions, io::{self, Write}, path::Path, sync::mpsc::{self, SyncSender}, thread, time::{SystemTime, UNIX_EPOCH}};
+
+use parking_lot::Mutex;
+use serde_json::{json, Value};
+use yazi_config::BOOT;
+use yazi_shared::{RoCell, Url};
+
+static TX: RoCell<Option<SyncSender<String>>> = RoCell::new();
+
+static LAST_CD: Mutex<Option<Url>> = Mutex::new(None);
+static LAST_HOVER: Mutex<Option<Url>> = Mutex::new(None);
+
+pub struct EventLog;
+
+impl EventLog {
+	pub(super) fn init() {
+		let Some(path) = BOOT.events.clone() else {
+			return TX.init(None);
+		};
+
+		// Events are dropped rather than queued up when nobody is reading them
+		let (tx, rx) = mpsc::sync_channel::<String>(1024);
+		thread::spawn(move || {
+			let mut out = None;
+			for line in rx {
+				for _ in 0..2 {
+					if out.is_none() {
+						out = Self::connect(&path).ok();
+					}
+					let Some(w) = &mut out else { break };
+					if w.write_all(line.as_bytes()).and_then(|_| w.flush()).is_ok() {
+						break;
+					}
+					out = None;
+				}
+			}
+		});
+
+		TX.init(Some(tx));
+	}
+
+	fn connect(path: &Path) -> io::Result<Box<dyn Write>> {
+		#[cfg(unix)]
+		{
+			use std::os::unix::{fs::FileTypeExt, net::UnixStream};
+			if std::fs::metadata(path).is_ok_and(|m| m.file_type().is_socket()) {
+				return Ok(Box::new(UnixStream::connect(path)?));
+			}
+		}
+		Ok(Box::new(OpenOptions::new().create(true).append(true).open(path)?))
+	}
+
+	fn emit(kind: &str, mut body: Value) {
+		let Some(tx) = &*TX else {
+			return;
+		};
+
+		body["type"] = json!(kind);
+		body["time"] = json!(SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_millis()));
+		tx.try_send(format!("{body}\n")).ok();
+	}
+
+	#[inline]
+	fn enabled() -> bool { TX.is_some() }
+}
+
+impl EventLog {
+	pub fn cd(tab: usize, url: &Url) {
+		if !Self::enabled() {
+			return;
+		}
+
+		let mut last = LAST_CD.lock();
+		if last.as_ref() != Some(url) {
+			*last = Some(url.clone());
+			Self::emit("cd", json!({ "tab": tab, "url": url.to_string() }));
+		}
+	}
+
+	pub fn hover(tab: usize, url: Option<&Url>) {
+		if !Self::enabled() {
+			return;
+		}
+
+		let mut last = LAST_HOVER.lock();
+		if last.as_ref() != url {
+			*last = url.cloned();
+			Self::emit("hover", json!({ "tab": tab, "url": url.map(|u| u.to_string()) }));
+		}
+	}
+
+	pub fn open(urls: &[impl AsRef<Path>], interactive: bool) {
+		if Self::enabled() {
+			let urls: Vec<_> = urls.iter().map(|u| u.as_ref().to_string_lossy()).collect();
+			Self::emit("open", json!({ "urls": urls, "interactive": interactive }));
+		}
+	}
+
+	pub fn yank<'a>(urls: impl Iterator<Item = &'a Url>, cut: bool) {
+		if Self::enabled() {
+			let urls: Vec<_> = urls.map(|u| u.to_string()).collect();
+			Self::emit("yank", json!({ "urls": urls, "cut": cut }));
+		}
+	}
+
+	pub fn task_start(id: usize, name: &str) {
+		if Self::enabled() {
+			Self::emit("task_start", json!({ "id": id, "name": name }));
+		}
+	}
+
+	pub fn task_end(id: usize, name: &str, fail: u32, canceled: bool) {
+		if Self::enabled() {
+			Self::emit("task_end", json!({ "id": id, "name": name, "fail": fail, "canceled": canceled }));
+		}
+	}
+}
@@ -0,0 +1,46 @@
+use std::path::Path;
+
+use anyhow::Result;
+use tokio::process::Command;
+
+// Test the integrity of an archive, returns the reported problems, which are
+// usually the corrupt members, or an empty list if the archive is intact.
+pub async fn archive_test(path: &Path) -> Result<Vec<String>> {
+	let ext = path.extension().map(|e| e.to_string_lossy().to_lowercase()).unwrap_or_default();
+	let (bin, args): (_, &[_]) = match ext.as_str() {
+		"gz" | "tgz" => ("gzip", &["-t"]),
+		"zip" | "jar" => ("unzip", &["-tqq"]),
+		_ => ("7z", &["t", "-bd", "-y", "-p"]),
+	};
+
+	let output = Command::new(bin).args(args).arg(path).kill_on_drop(true).output().await?;
+	if output.status.success() {
+		return Ok(vec![]);
+	}
+
+	let stdout = String::from_utf8_lossy(&output.stdout);
+	let stderr = String::from_utf8_lossy(&output.stderr);
+	let mut problems: Vec<_> = stdout
+		.lines()
+		.chain(stderr.lines())
+		.map(str::trim)
+		.filter(|l| {
+			l.starts_with("ERROR:")
+				|| l.contains("bad CRC")
+				|| l.contains("CRC Failed")
+				|| l.contains("Data Error")
+				|| l.contains("crc error")
+				|| l.contains("unexpected end of file")
+		})
+		.map(ToOwned::to_owned)
+		.collect();
+
+	if problems.is_empty() {
+		let lines = stderr.lines().chain(stdout.lines()).map(str::trim).filter(|l| !l.is_empty());
+		problems.extend(lines.take(5).map(ToOwned::to_owned));
+	}
+	if problems.is_empty() {
+		problems.push(format!("Exited with {}", output.status));
+	}
+	Ok(problems)
+}
@@ -0,0 +1,55 @@
+use std::{path::Path, process::Stdio};
+
+use anyhow::{bail, Result};
+use tokio::process::{Child, Command};
+
+pub struct CurlHead {
+	pub name:   Option<String>,
+	pub length: Option<u64>,
+}
+
+// Follow the redirects, and take the headers from the final response
+pub async fn curl_head(url: &str) -> Result<CurlHead> {
+	let output = Command::new("curl").args(["-sSIL", "--", url]).kill_on_drop(true).output().await?;
+
+	if !output.status.success() {
+		bail!("{}", String::from_utf8_lossy(&output.stderr).trim());
+	}
+
+	let stdout = String::from_utf8_lossy(&output.stdout);
+	let last = stdout.rsplit("\r\n\r\n").find(|s| !s.trim().is_empty()).unwrap_or_default();
+
+	let mut head = CurlHead { name: None, length: None };
+	for line in last.lines() {
+		let Some((k, v)) = line.split_once(':') else {
+			continue;
+		};
+
+		let v = v.trim();
+		if k.eq_ignore_ascii_case("content-length") {
+			head.length = v.parse().ok();
+		} else if k.eq_ignore_ascii_case("content-disposition") {
+			head.name = v
+				.split(';')
+				.filter_map(|p| p.trim().strip_prefix("filename="))
+				.map(|n| n.trim_matches('"').to_owned())
+				.find(|n| !n.is_empty());
+		}
+	}
+	Ok(head)
+}
+
+// Resume if `dest` exists, which must be a partial file of the same `url`
+pub fn curl(url: &str, dest: &Path) -> Result<Child> {
+	Ok(
+		Command::new("curl")
+			.args(["-sSfL", "-C", "-", "-o"])
+			.arg(dest)
+			.args(["--", url])
+			.stdin(Stdio::null())
+			.stdout(Stdio::null())
+			.stderr(Stdio::piped())
+			.kill_on_drop(true)
+			.spawn()?,
+	)
+}
@@ -0,0 +1,66 @@
+use std::{collections::HashMap, fmt::{self, Display}, path::Path};
+
+use anyhow::anyhow;
+use serde_json::Value;
+use tokio::process::Command;
+use yazi_shared::PeekError;
+
+const TAGS: [(&str, &str); 9] = [
+	("Make", "Make"),
+	("Model", "Model"),
+	("Lens", "LensModel"),
+	("Size", "ImageSize"),
+	("Taken", "DateTimeOriginal"),
+	("Exposure", "ExposureTime"),
+	("Aperture", "FNumber"),
+	("ISO", "ISO"),
+	("GPS", "GPSPosition"),
+];
+
+#[derive(Clone, Debug, Default)]
+pub struct ExifMeta {
+	pub fields: Vec<(&'static str, String)>,
+}
+
+impl Display for ExifMeta {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if self.fields.is_empty() {
+			return write!(f, "No EXIF data");
+		}
+		for (i, (label, value)) in self.fields.iter().enumerate() {
+			let nl = if i + 1 < self.fields.len() { "\n" } else { "" };
+			write!(f, "{label:<9}{value}{nl}")?;
+		}
+		Ok(())
+	}
+}
+
+pub async fn exiftool(src: &Path) -> Result<ExifMeta, PeekError> {
+	let output = Command::new("exiftool")
+		.arg("-json")
+		.args(TAGS.map(|(_, tag)| format!("-{tag}")))
+		.arg(src)
+		.kill_on_drop(true)
+		.output()
+		.await?;
+
+	if !output.status.success() {
+		return Err(String::from_utf8_lossy(&output.stderr).to_string().into());
+	}
+
+	let mut outer: Vec<HashMap<String, Value>> =
+		serde_json::from_slice(&output.stdout).map_err(|e| anyhow!(e))?;
+	let Some(mut tags) = outer.pop() else {
+		return Ok(Default::default());
+	};
+
+	let fields = TAGS
+		.iter()
+		.filter_map(|&(label, tag)| match tags.remove(tag)? {
+			Value::String(s) => Some((label, s)),
+			Value::Null => None,
+			v => Some((label, v.to_string())),
+		})
+		.collect();
+	Ok(ExifMeta { fields })
+}
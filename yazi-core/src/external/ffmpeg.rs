@@ -0,0 +1,208 @@
+use std::{collections::HashMap, fmt::{self, Display}, path::Path, time::Duration};
+
+use anyhow::anyhow;
+use serde::Deserialize;
+use tokio::process::Command;
+use yazi_config::PREVIEW;
+use yazi_shared::PeekError;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct VideoMeta {
+	pub duration: Duration,
+	pub width:    u32,
+	pub height:   u32,
+}
+
+impl Display for VideoMeta {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}x{} {}", self.width, self.height, clock(self.duration))
+	}
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct AudioMeta {
+	pub title:    Option<String>,
+	pub artist:   Option<String>,
+	pub album:    Option<String>,
+	pub duration: Duration,
+	pub bitrate:  Option<u64>,
+	pub cover:    bool,
+}
+
+impl Display for AudioMeta {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let unknown = || "Unknown".to_owned();
+		writeln!(f, "Title    {}", self.title.clone().unwrap_or_else(unknown))?;
+		writeln!(f, "Artist   {}", self.artist.clone().unwrap_or_else(unknown))?;
+		writeln!(f, "Album    {}", self.album.clone().unwrap_or_else(unknown))?;
+		writeln!(f, "Length   {}", clock(self.duration))?;
+		match self.bitrate {
+			Some(b) => write!(f, "Bitrate  {} kbps", b / 1000),
+			None => write!(f, "Bitrate  {}", unknown()),
+		}
+	}
+}
+
+fn clock(d: Duration) -> String {
+	let secs = d.as_secs();
+	if secs >= 3600 {
+		format!("{}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+	} else {
+		format!("{:02}:{:02}", secs / 60 % 60, secs % 60)
+	}
+}
+
+pub async fn ffprobe(src: &Path) -> Result<VideoMeta, PeekError> {
+	let output = Command::new("ffprobe")
+		.args(["-v", "error", "-select_streams", "v:0", "-of", "json"])
+		.args(["-show_entries", "stream=width,height:format=duration"])
+		.arg(src)
+		.kill_on_drop(true)
+		.output()
+		.await?;
+
+	if !output.status.success() {
+		return Err(String::from_utf8_lossy(&output.stderr).to_string().into());
+	}
+
+	#[derive(Deserialize)]
+	struct Outer {
+		streams: Vec<Stream>,
+		format:  Format,
+	}
+	#[derive(Deserialize)]
+	struct Stream {
+		width:  u32,
+		height: u32,
+	}
+	#[derive(Deserialize)]
+	struct Format {
+		duration: Option<String>,
+	}
+
+	let outer = serde_json::from_slice::<Outer>(&output.stdout).map_err(|e| anyhow!(e))?;
+	let Some(stream) = outer.streams.first() else {
+		return Err("No video stream".into());
+	};
+
+	let duration = outer.format.duration.and_then(|s| s.parse::<f64>().ok()).unwrap_or_default();
+	Ok(VideoMeta {
+		duration: Duration::from_secs_f64(duration.max(0.0)),
+		width:    stream.width,
+		height:   stream.height,
+	})
+}
+
+// Take a frame at `skip` percent past the seek of the preset, with the
+// `duration` from an earlier `ffprobe` if there's one at hand
+pub async fn ffmpeg(
+	src: &Path,
+	dest: &Path,
+	skip: usize,
+	duration: Option<Duration>,
+) -> Result<(), PeekError> {
+	let start = PREVIEW.video_seek as usize;
+	let percentage = start + skip;
+	if percentage > 95 {
+		return Err(PeekError::Exceed(95usize.saturating_sub(start)));
+	}
+
+	let duration = match duration {
+		Some(d) => d,
+		None => ffprobe(src).await?.duration,
+	};
+
+	let seek = duration.mul_f64(percentage as f64 / 100.0);
+	let scale = format!(
+		"scale='min({},iw)':'min({},ih)':force_original_aspect_ratio=decrease",
+		PREVIEW.max_width, PREVIEW.max_height
+	);
+
+	let output = Command::new("ffmpeg")
+		.args(["-v", "error", "-nostdin", "-y"])
+		.args(["-ss", &format!("{:.3}", seek.as_secs_f64())])
+		.arg("-i")
+		.arg(src)
+		.args(["-an", "-frames:v", "1", "-vf", &scale])
+		.args(["-q:v", "6", "-c:v", "mjpeg", "-f", "image2"])
+		.arg(dest)
+		.kill_on_drop(true)
+		.output()
+		.await?;
+
+	if !output.status.success() {
+		return Err(String::from_utf8_lossy(&output.stderr).to_string().into());
+	}
+	Ok(())
+}
+
+pub async fn ffprobe_audio(src: &Path) -> Result<AudioMeta, PeekError> {
+	let output = Command::new("ffprobe")
+		.args(["-v", "error", "-of", "json"])
+		.args(["-show_entries", "stream=codec_type:format=duration,bit_rate:format_tags"])
+		.arg(src)
+		.kill_on_drop(true)
+		.output()
+		.await?;
+
+	if !output.status.success() {
+		return Err(String::from_utf8_lossy(&output.stderr).to_string().into());
+	}
+
+	#[derive(Deserialize)]
+	struct Outer {
+		streams: Vec<Stream>,
+		format:  Format,
+	}
+	#[derive(Deserialize)]
+	struct Stream {
+		codec_type: String,
+	}
+	#[derive(Deserialize)]
+	struct Format {
+		duration: Option<String>,
+		bit_rate: Option<String>,
+		#[serde(default)]
+		tags:     HashMap<String, String>,
+	}
+
+	let outer = serde_json::from_slice::<Outer>(&output.stdout).map_err(|e| anyhow!(e))?;
+	let Format { duration, bit_rate, tags } = outer.format;
+
+	// Tag names differ in case between containers, e.g. "title" and "TITLE"
+	let tag = |name: &str| {
+		tags.iter().find(|(k, _)| k.eq_ignore_ascii_case(name)).map(|(_, v)| v.trim().to_owned())
+	};
+	let duration = duration.and_then(|s| s.parse::<f64>().ok()).unwrap_or_default();
+	Ok(AudioMeta {
+		title:    tag("title"),
+		artist:   tag("artist"),
+		album:    tag("album"),
+		duration: Duration::from_secs_f64(duration.max(0.0)),
+		bitrate:  bit_rate.and_then(|s| s.parse().ok()),
+		cover:    outer.streams.iter().any(|s| s.codec_type == "video"),
+	})
+}
+
+pub async fn ffmpeg_cover(src: &Path, dest: &Path) -> Result<(), PeekError> {
+	let scale = format!(
+		"scale='min({},iw)':'min({},ih)':force_original_aspect_ratio=decrease",
+		PREVIEW.max_width, PREVIEW.max_height
+	);
+
+	let output = Command::new("ffmpeg")
+		.args(["-v", "error", "-nostdin", "-y"])
+		.arg("-i")
+		.arg(src)
+		.args(["-an", "-map", "0:v:0", "-frames:v", "1", "-vf", &scale])
+		.args(["-q:v", "6", "-c:v", "mjpeg", "-f", "image2"])
+		.arg(dest)
+		.kill_on_drop(true)
+		.output()
+		.await?;
+
+	if !output.status.success() {
+		return Err(String::from_utf8_lossy(&output.stderr).to_string().into());
+	}
+	Ok(())
+}
@@ -2,9 +2,10 @@ use std::collections::BTreeMap;
 
 use anyhow::{bail, Result};
 use futures::TryFutureExt;
-use tokio::process::Command;
+use tokio::{fs, io::AsyncReadExt, process::Command};
 use tracing::error;
-use yazi_shared::{MimeKind, Url};
+use yazi_config::MIMETYPE;
+use yazi_shared::{mime_sniff, MimeKind, Url, MIME_DIR};
 
 async fn _file(files: &[&Url]) -> Result<BTreeMap<Url, String>> {
 	if files.is_empty() {
@@ -35,6 +36,41 @@ async fn _file(files: &[&Url]) -> Result<BTreeMap<Url, String>> {
 	Ok(mimes)
 }
 
+// Without spawning `file` for the directories, those forced by the `[mimetype]`
+// rules, empty files, and those whose type is known from their first bytes
+async fn builtin(url: &Url) -> Option<&'static str> {
+	let meta = fs::metadata(url).await.ok()?;
+	if meta.is_dir() {
+		return Some(MIME_DIR);
+	} else if let Some(mime) = MIMETYPE.matches(url) {
+		return Some(mime);
+	} else if meta.len() == 0 {
+		return Some("inode/x-empty");
+	} else if !meta.is_file() {
+		return None;
+	}
+
+	let mut head = Vec::with_capacity(512);
+	fs::File::open(url).await.ok()?.take(512).read_to_end(&mut head).await.ok()?;
+	mime_sniff(&head, url.extension().and_then(|e| e.to_str()))
+}
+
 pub async fn file(files: &[impl AsRef<Url>]) -> Result<BTreeMap<Url, String>> {
-	_file(&files.iter().map(AsRef::as_ref).collect::<Vec<_>>()).await
+	let mut mimes = BTreeMap::new();
+	let mut unknown = Vec::new();
+	for f in files.iter().map(AsRef::as_ref) {
+		match builtin(f).await {
+			Some(m) => _ = mimes.insert(f.clone(), m.to_owned()),
+			None => unknown.push(f),
+		}
+	}
+
+	if !unknown.is_empty() {
+		match _file(&unknown).await {
+			Ok(m) => mimes.extend(m),
+			Err(e) if mimes.is_empty() => return Err(e),
+			Err(_) => {}
+		}
+	}
+	Ok(mimes)
 }
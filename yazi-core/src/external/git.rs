@@ -0,0 +1,172 @@
+use std::{collections::BTreeMap, hash::{DefaultHasher, Hash, Hasher}, path::{Component, Path, PathBuf}};
+
+use anyhow::{bail, Result};
+use tokio::{fs, process::Command};
+use yazi_shared::Url;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum GitStatus {
+	Ignored,
+	Untracked,
+	Staged,
+	Modified,
+}
+
+impl GitStatus {
+	#[inline]
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::Ignored => "ignored",
+			Self::Untracked => "untracked",
+			Self::Staged => "staged",
+			Self::Modified => "modified",
+		}
+	}
+
+	fn from_xy(x: u8, y: u8) -> Option<Self> {
+		Some(match (x, y) {
+			(b'!', b'!') => Self::Ignored,
+			(b'?', b'?') => Self::Untracked,
+			(_, b' ') if x != b' ' => Self::Staged,
+			(_, b' ') => return None,
+			_ => Self::Modified,
+		})
+	}
+}
+
+pub struct GitRepo {
+	pub root: String,
+	index:    PathBuf,
+}
+
+impl GitRepo {
+	pub async fn new(cwd: &Url) -> Result<Self> {
+		let output = Command::new("git")
+			.arg("-C")
+			.arg(cwd)
+			.args(["rev-parse", "--show-toplevel", "--git-path", "index"])
+			.kill_on_drop(true)
+			.output()
+			.await?;
+
+		if !output.status.success() {
+			bail!("not a git repository");
+		}
+
+		let stdout = String::from_utf8_lossy(&output.stdout);
+		let mut lines = stdout.lines();
+		let (Some(root), Some(index)) = (lines.next(), lines.next()) else {
+			bail!("not a git repository");
+		};
+		Ok(Self { root: root.to_owned(), index: cwd.join(index).to_path_buf() })
+	}
+
+	/// A fingerprint of the index and the direct children of `cwd`, which is
+	/// the same as long as their statuses can't have changed.
+	pub async fn stamp(&self, cwd: &Url) -> u64 {
+		let mut hasher = DefaultHasher::new();
+		let stat = |m: std::fs::Metadata| (m.len(), m.modified().ok());
+		fs::metadata(&self.index).await.ok().map(stat).hash(&mut hasher);
+
+		if let Ok(mut it) = fs::read_dir(cwd).await {
+			let mut children = Vec::new();
+			while let Ok(Some(entry)) = it.next_entry().await {
+				children.push((entry.file_name(), entry.metadata().await.ok().map(stat)));
+			}
+			children.sort_unstable();
+			children.hash(&mut hasher);
+		}
+		hasher.finish()
+	}
+}
+
+/// Collect the git status of the direct children of `cwd`, where the status
+/// of a directory is the most significant one of all the entries inside it.
+pub async fn git_status(cwd: &Url, repo: &GitRepo) -> Result<BTreeMap<Url, GitStatus>> {
+	let output = Command::new("git")
+		.arg("-C")
+		.arg(cwd)
+		.args(["status", "--porcelain", "-z", "--ignored", "--", "."])
+		.kill_on_drop(true)
+		.output()
+		.await?;
+
+	if !output.status.success() {
+		bail!("failed to get git status");
+	}
+
+	let base = fs::canonicalize(cwd).await?;
+	let output = String::from_utf8_lossy(&output.stdout);
+	Ok(parse_status(&output, Path::new(&repo.root), &base, cwd))
+}
+
+// An ignored entry only counts for the child it is, since the clean tracked
+// files next to it in a directory never show up in the output
+fn parse_status(output: &str, root: &Path, base: &Path, cwd: &Url) -> BTreeMap<Url, GitStatus> {
+	let mut statuses = BTreeMap::new();
+	let mut it = output.split('\0');
+	while let Some(entry) = it.next() {
+		let b = entry.as_bytes();
+		if b.len() < 4 {
+			continue;
+		}
+		if matches!(b[0], b'R' | b'C') {
+			it.next(); // Skip the original path of renames and copies
+		}
+
+		let Some(status) = GitStatus::from_xy(b[0], b[1]) else {
+			continue;
+		};
+
+		let path = root.join(entry[3..].trim_end_matches('/'));
+		let Ok(rel) = path.strip_prefix(base) else {
+			continue;
+		};
+
+		let mut components = rel.components();
+		let Some(Component::Normal(name)) = components.next() else {
+			continue;
+		};
+		if status == GitStatus::Ignored && components.next().is_some() {
+			continue;
+		}
+
+		let s = statuses.entry(cwd.join(name)).or_insert(status);
+		*s = (*s).max(status);
+	}
+	statuses
+}
+
+#[cfg(test)]
+mod tests {
+	use std::path::Path;
+
+	use yazi_shared::Url;
+
+	use super::{parse_status, GitStatus};
+
+	fn parse(output: &str) -> Vec<(String, GitStatus)> {
+		let (root, cwd) = (Path::new("/repo"), Url::from("/repo"));
+		parse_status(output, root, root, &cwd)
+			.into_iter()
+			.map(|(u, s)| (u.file_name().unwrap().to_string_lossy().into_owned(), s))
+			.collect()
+	}
+
+	#[test]
+	fn test_parse_status() {
+		// A tracked directory with only an ignored file in it isn't ignored itself
+		assert_eq!(parse("!! src/main.pyc\0"), []);
+		assert_eq!(parse("!! target/\0!! .env\0"), [
+			(".env".to_owned(), GitStatus::Ignored),
+			("target".to_owned(), GitStatus::Ignored)
+		]);
+
+		// The other statuses add up to the most significant one
+		assert_eq!(parse("?? src/new.rs\0!! src/main.pyc\0 M src/lib.rs\0"), [(
+			"src".to_owned(),
+			GitStatus::Modified
+		)]);
+		assert_eq!(parse("R  src/b.rs\0src/a.rs\0"), [("src".to_owned(), GitStatus::Staged)]);
+	}
+}
@@ -6,8 +6,16 @@ use yazi_config::PREVIEW;
 use yazi_shared::PeekError;
 
 pub async fn jq(path: &Path, skip: usize, limit: usize) -> Result<String, PeekError> {
+	// Huge arrays are folded so they don't flood the preview
+	let filter = match PREVIEW.json_fold {
+		Some(n) => format!(
+			r#"walk(if type == "array" and length > {n} then .[:{n}] + ["… \(length - {n}) more items"] else . end)"#
+		),
+		None => ".".to_owned(),
+	};
+
 	let mut child = Command::new("jq")
-		.args(["-C", "--tab", "."])
+		.args(["-C", "--tab", &filter])
 		.arg(path)
 		.stdout(Stdio::piped())
 		.stderr(Stdio::piped())
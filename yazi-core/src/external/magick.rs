@@ -0,0 +1,36 @@
+use std::{io::ErrorKind, path::Path};
+
+use tokio::process::Command;
+use yazi_adaptor::Image;
+use yazi_shared::PeekError;
+
+const SPECIMEN: &str =
+	"ABCDEFGHIJKLMNOPQRSTUVWXYZ\nabcdefghijklmnopqrstuvwxyz\n0123456789 .,:;!?&@#()[]{}";
+const PANGRAM: &str = "The quick brown fox jumps over the lazy dog";
+
+// Render a specimen of the font, the alphabet followed by a pangram in
+// increasing sizes.
+pub async fn magick_font(src: &Path, dest: &Path) -> Result<(), PeekError> {
+	let command = |bin: &str| {
+		let mut cmd = Command::new(bin);
+		cmd.args(["-background", "white", "-fill", "black", "-font"]).arg(src);
+		cmd.args(["-pointsize", "32"]).arg(format!("label:{SPECIMEN}\n "));
+		for size in [12, 18, 24, 36, 48] {
+			cmd.args(["-pointsize", &size.to_string()]).arg(format!("label:{PANGRAM}"));
+		}
+		cmd.args(["-append", "-bordercolor", "white", "-border", "20", "png:-"]).kill_on_drop(true);
+		cmd
+	};
+
+	// ImageMagick 7 ships `magick`, while 6 only has `convert`
+	let mut output = command("magick").output().await;
+	if output.as_ref().is_err_and(|e| e.kind() == ErrorKind::NotFound) {
+		output = command("convert").output().await;
+	}
+
+	let output = output?;
+	if !output.status.success() {
+		return Err(String::from_utf8_lossy(&output.stderr).to_string().into());
+	}
+	Ok(Image::precache_vec(output.stdout, dest.to_owned()).await?)
+}
@@ -1,23 +1,33 @@
+mod archive_test;
 mod clipboard;
+mod curl;
+mod exiftool;
 mod fd;
-mod ffmpegthumbnailer;
+mod ffmpeg;
 mod file;
 mod fzf;
+mod git;
 mod jq;
 mod lsar;
+mod magick;
 mod pdftoppm;
 mod rg;
 mod shell;
 mod unar;
 mod zoxide;
 
+pub use archive_test::*;
 pub use clipboard::*;
+pub use curl::*;
+pub use exiftool::*;
 pub use fd::*;
-pub use ffmpegthumbnailer::*;
+pub use ffmpeg::*;
 pub use file::*;
 pub use fzf::*;
+pub use git::*;
 pub use jq::*;
 pub use lsar::*;
+pub use magick::*;
 pub use pdftoppm::*;
 pub use rg::*;
 pub use shell::*;
@@ -5,7 +5,9 @@ use tokio::process::{Child, Command};
 
 pub struct ShellOpt {
 	pub cmd:    OsString,
+	pub arg0:   OsString,
 	pub args:   Vec<OsString>,
+	pub env:    Vec<(String, OsString)>,
 	pub piped:  bool,
 	pub orphan: bool,
 }
@@ -37,8 +39,9 @@ pub fn shell(opt: ShellOpt) -> Result<Child> {
 			.stdout(opt.stdio())
 			.stderr(opt.stdio())
 			.arg(opt.cmd)
-			.arg("") // $0 is the command name
+			.arg(opt.arg0) // $0, which is the command name by default
 			.args(opt.args)
+			.envs(opt.env)
 			.kill_on_drop(!opt.orphan)
 			.pre_exec(move || {
 				if opt.orphan && libc::setpgid(0i32, 0i32) < 0 {
@@ -53,11 +56,13 @@ pub fn shell(opt: ShellOpt) -> Result<Child> {
 	{
 		let args: Vec<String> = opt.args.iter().map(|s| s.to_string_lossy().to_string()).collect();
 		let args_: Vec<&str> = args.iter().map(|s| s.as_ref()).collect();
-		let expanded = parser::parse(opt.cmd.to_string_lossy().as_ref(), &args_);
+		let expanded =
+			parser::parse_with(opt.cmd.to_string_lossy().as_ref(), &opt.arg0.to_string_lossy(), &args_);
 		Ok(
 			Command::new("cmd")
 				.arg("/C")
 				.args(&expanded)
+				.envs(opt.env)
 				.stdin(opt.stdio())
 				.stdout(opt.stdio())
 				.stderr(opt.stdio())
@@ -71,7 +76,11 @@ pub fn shell(opt: ShellOpt) -> Result<Child> {
 mod parser {
 	use std::{iter::Peekable, str::Chars};
 
-	pub(super) fn parse(cmd: &str, args: &[&str]) -> Vec<String> {
+	#[cfg(test)]
+	pub(super) fn parse(cmd: &str, args: &[&str]) -> Vec<String> { parse_with(cmd, "", args) }
+
+	// `%0` expands to `arg0`, and the others to `args`
+	pub(super) fn parse_with(cmd: &str, arg0: &str, args: &[&str]) -> Vec<String> {
 		let mut it = cmd.chars().peekable();
 		let mut expanded = Vec::new();
 
@@ -86,7 +95,7 @@ mod parser {
 					if c == '\'' {
 						break;
 					}
-					next_string(&mut it, args, &mut s, c);
+					next_string(&mut it, arg0, args, &mut s, c);
 				}
 				expanded.push(s);
 			} else if c == '"' {
@@ -94,7 +103,7 @@ mod parser {
 					if c == '"' {
 						break;
 					}
-					next_string(&mut it, args, &mut s, c);
+					next_string(&mut it, arg0, args, &mut s, c);
 				}
 				expanded.push(s);
 			} else if c == '%' && it.peek().is_some_and(|&c| c == '*') {
@@ -103,13 +112,13 @@ mod parser {
 					expanded.push(arg.to_string());
 				}
 			} else {
-				next_string(&mut it, args, &mut s, c);
+				next_string(&mut it, arg0, args, &mut s, c);
 
 				while let Some(c) = it.next() {
 					if c.is_whitespace() {
 						break;
 					}
-					next_string(&mut it, args, &mut s, c);
+					next_string(&mut it, arg0, args, &mut s, c);
 				}
 				expanded.push(s);
 			}
@@ -118,7 +127,7 @@ mod parser {
 		expanded
 	}
 
-	fn next_string(it: &mut Peekable<Chars<'_>>, args: &[&str], s: &mut String, c: char) {
+	fn next_string(it: &mut Peekable<Chars<'_>>, arg0: &str, args: &[&str], s: &mut String, c: char) {
 		if c == '\\' {
 			match it.next() {
 				Some('\\') => s.push('\\'), // \\  ==>  \
@@ -155,6 +164,8 @@ mod parser {
 					let pos = pos.parse::<usize>().unwrap();
 					if pos > 0 {
 						s.push_str(args.get(pos - 1).unwrap_or(&""));
+					} else {
+						s.push_str(arg0);
 					}
 				}
 				_ => s.push('%'),
@@ -215,5 +226,14 @@ mod parser {
 			let args = parse(" %EDITOR% %* xyz", &["111", "222"]);
 			assert_eq!(args, ["%EDITOR%", "111", "222", "xyz"]);
 		}
+
+		#[test]
+		fn test_arg0() {
+			let args = parse_with("echo %0 %1", "yazi", &["111", "222"]);
+			assert_eq!(args, ["echo", "yazi", "111"]);
+
+			let args = parse_with("echo '%0-%1' %*", "yazi", &["111", "222"]);
+			assert_eq!(args, ["echo", "yazi-111", "111", "222"]);
+		}
 	}
 }
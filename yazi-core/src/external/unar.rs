@@ -21,3 +21,24 @@ pub async fn unar_head(path: &Path, target: &Path) -> Result<Vec<u8>> {
 	}
 	Ok(buf)
 }
+
+// Extract the archive into `dest`, and whether to wrap the contents in a
+// subdirectory named after it, or leave it to unar if `None`. Conflicting
+// files are renamed rather than overwritten.
+pub async fn unar(path: &Path, dest: &Path, subdir: Option<bool>) -> Result<()> {
+	let output = Command::new("unar")
+		.args(["-r", "-o"])
+		.arg(dest)
+		.args(subdir.map(|b| if b { "-d" } else { "-D" }))
+		.arg(path)
+		.stdin(Stdio::null())
+		.kill_on_drop(true)
+		.output()
+		.await?;
+
+	if !output.status.success() {
+		let stderr = String::from_utf8_lossy(&output.stderr);
+		bail!("{}", stderr.lines().rev().find(|l| !l.trim().is_empty()).unwrap_or("unar failed"));
+	}
+	Ok(())
+}
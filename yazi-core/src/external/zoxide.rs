@@ -24,3 +24,18 @@ pub async fn zoxide(opt: ZoxideOpt) -> Result<Url> {
 	}
 	bail!("No match")
 }
+
+// All the directories zoxide knows of, ordered by their score
+pub async fn zoxide_list(opt: ZoxideOpt) -> Result<Vec<Url>> {
+	let output = Command::new("zoxide")
+		.args(["query", "-l", "--exclude"])
+		.arg(&opt.cwd)
+		.kill_on_drop(true)
+		.output()
+		.await?;
+
+	if !output.status.success() {
+		bail!("zoxide exited with {}", output.status);
+	}
+	Ok(String::from_utf8_lossy(&output.stdout).lines().map(Url::from).collect())
+}
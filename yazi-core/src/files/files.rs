@@ -6,18 +6,27 @@ use yazi_config::{manager::SortBy, MANAGER};
 use yazi_shared::Url;
 
 use super::{File, FilesSorter, FILES_TICKET};
+use crate::external::GitStatus;
 
 pub struct Files {
 	items:              Vec<File>,
+	// Concealed as they're hidden or ignored by git
 	hidden:             Vec<File>,
 	ticket:             u64,
 	pub(crate) version: u64,
 
+	// Coming part by part, gathered in `staging` for a folder that already has
+	// files, so they stay on screen until the new ones are all there
+	loading: bool,
+	staging: Option<Vec<File>>,
+
 	pub sizes: BTreeMap<Url, u64>,
+	pub git:   BTreeMap<Url, GitStatus>,
 	selected:  BTreeSet<Url>,
 
-	sorter:      FilesSorter,
-	show_hidden: bool,
+	sorter:       FilesSorter,
+	show_hidden:  bool,
+	show_ignored: bool,
 }
 
 impl Default for Files {
@@ -28,11 +37,16 @@ impl Default for Files {
 			ticket:  Default::default(),
 			version: Default::default(),
 
+			loading: false,
+			staging: None,
+
 			sizes:    Default::default(),
+			git:      Default::default(),
 			selected: Default::default(),
 
-			sorter:      Default::default(),
-			show_hidden: MANAGER.show_hidden,
+			sorter:       Default::default(),
+			show_hidden:  MANAGER.show_hidden,
+			show_ignored: MANAGER.show_ignored,
 		}
 	}
 }
@@ -82,6 +96,15 @@ impl Files {
 		true
 	}
 
+	pub fn select_as(&mut self, other: &Files) -> bool {
+		if self.selected == other.selected {
+			return false;
+		}
+
+		self.selected = other.selected.clone();
+		true
+	}
+
 	pub fn select_all(&mut self, state: Option<bool>) -> bool {
 		match state {
 			Some(true) => {
@@ -127,10 +150,10 @@ impl Files {
 	}
 
 	pub fn update_full(&mut self, mut items: Vec<File>) -> bool {
-		if !self.show_hidden {
-			(self.hidden, items) = items.into_iter().partition(|f| f.is_hidden());
-		}
+		(self.hidden, items) = items.into_iter().partition(|f| self.conceals(f));
 		self.ticket = FILES_TICKET.fetch_add(1, Ordering::Relaxed);
+		self.loading = false;
+		self.staging = None;
 		self.sorter.sort(&mut items, &self.sizes);
 		self.items = items;
 		self.version += 1;
@@ -142,28 +165,44 @@ impl Files {
 			if version != self.ticket {
 				return false;
 			}
-
-			if self.show_hidden {
-				self.items.extend(items);
-			} else {
-				let (hidden, items): (Vec<_>, Vec<_>) = items.into_iter().partition(|f| f.is_hidden());
-				self.items.extend(items);
-				self.hidden.extend(hidden);
+			if let Some(staging) = &mut self.staging {
+				staging.extend(items);
+				return false;
 			}
 
-			self.sorter.sort(&mut self.items, &self.sizes);
+			let (hidden, items): (Vec<_>, Vec<_>) = items.into_iter().partition(|f| self.conceals(f));
+			self.sorter.merge(&mut self.items, items, &self.sizes);
+			self.hidden.extend(hidden);
 			self.version += 1;
 			return true;
 		}
 
 		self.ticket = version;
-		if self.items.is_empty() && self.hidden.is_empty() {
+		self.loading = true;
+		self.staging = (!self.items.is_empty() || !self.hidden.is_empty()).then(Vec::new);
+		true
+	}
+
+	pub fn update_done(&mut self, version: u64) -> bool {
+		if version != self.ticket || !self.loading {
 			return false;
 		}
 
-		self.items.clear();
-		self.hidden.clear();
-		self.version += 1;
+		match self.staging.take() {
+			Some(items) => _ = self.update_full(items),
+			None => self.loading = false,
+		}
+		true
+	}
+
+	// What's on screen is kept, with the parts staged so far thrown away
+	pub fn update_abort(&mut self, version: u64) -> bool {
+		if version != self.ticket || !self.loading {
+			return false;
+		}
+
+		self.loading = false;
+		self.staging = None;
 		true
 	}
 
@@ -176,10 +215,20 @@ impl Files {
 		true
 	}
 
-	pub fn update_creating(&mut self, mut todo: BTreeMap<Url, File>) -> bool {
-		if !self.show_hidden {
-			todo.retain(|_, f| !f.is_hidden());
+	pub fn update_git(&mut self, items: BTreeMap<Url, GitStatus>) -> bool {
+		if self.git == items {
+			return false;
+		}
+
+		self.git = items;
+		if !self.show_ignored {
+			self.refilter();
 		}
+		true
+	}
+
+	pub fn update_creating(&mut self, mut todo: BTreeMap<Url, File>) -> bool {
+		todo.retain(|_, f| !self.conceals(f));
 
 		let b = self.update_replacing(&mut todo);
 		if todo.is_empty() {
@@ -309,6 +358,9 @@ impl Files {
 		self.iter().any(|f| self.selected.contains(&f.url))
 	}
 
+	#[inline]
+	pub fn is_loading(&self) -> bool { self.loading }
+
 	// --- Sorter
 	#[inline]
 	pub fn sorter(&self) -> &FilesSorter { &self.sorter }
@@ -326,19 +378,40 @@ impl Files {
 	pub fn set_show_hidden(&mut self, state: bool) -> bool {
 		if state == self.show_hidden {
 			return false;
-		} else if state && self.hidden.is_empty() {
+		}
+
+		self.show_hidden = state;
+		self.refilter()
+	}
+
+	// --- Show ignored
+	pub fn set_show_ignored(&mut self, state: bool) -> bool {
+		if state == self.show_ignored {
 			return false;
 		}
 
-		if state {
-			self.items.append(&mut self.hidden);
-			self.sorter.sort(&mut self.items, &self.sizes);
-		} else {
-			let items = mem::take(&mut self.items);
-			(self.hidden, self.items) = items.into_iter().partition(|f| f.is_hidden());
+		self.show_ignored = state;
+		self.refilter()
+	}
+
+	#[inline]
+	fn conceals(&self, f: &File) -> bool {
+		(!self.show_hidden && f.is_hidden())
+			|| (!self.show_ignored && self.git.get(&f.url) == Some(&GitStatus::Ignored))
+	}
+
+	// Move the files between the shown and the concealed ones, after the
+	// filters or the git statuses have changed
+	fn refilter(&mut self) -> bool {
+		if !self.items.iter().any(|f| self.conceals(f))
+			&& !self.hidden.iter().any(|f| !self.conceals(f))
+		{
+			return false;
 		}
 
-		self.show_hidden = state;
+		let all = mem::take(&mut self.items).into_iter().chain(mem::take(&mut self.hidden));
+		(self.hidden, self.items) = all.partition(|f| self.conceals(f));
+		self.sorter.sort(&mut self.items, &self.sizes);
 		self.version += 1;
 		true
 	}
@@ -3,15 +3,18 @@ use std::{collections::{BTreeMap, BTreeSet}, sync::atomic::{AtomicU64, Ordering}
 use yazi_shared::Url;
 
 use super::File;
-use crate::emit;
+use crate::{emit, external::GitStatus};
 
 pub(super) static FILES_TICKET: AtomicU64 = AtomicU64::new(0);
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum FilesOp {
 	Full(Url, Vec<File>),
 	Part(Url, u64, Vec<File>),
+	Done(Url, u64),
+	Abort(Url, u64),
 	Size(Url, BTreeMap<Url, u64>),
+	Git(Url, BTreeMap<Url, GitStatus>),
 	IOErr(Url),
 
 	Creating(Url, BTreeMap<Url, File>),
@@ -25,7 +28,10 @@ impl FilesOp {
 		match self {
 			Self::Full(url, _) => url,
 			Self::Part(url, ..) => url,
+			Self::Done(url, _) => url,
+			Self::Abort(url, _) => url,
 			Self::Size(url, _) => url,
+			Self::Git(url, _) => url,
 			Self::IOErr(url) => url,
 
 			Self::Creating(url, _) => url,
@@ -33,11 +39,40 @@ impl FilesOp {
 			Self::Replacing(url, _) => url,
 		}
 	}
+}
 
-	#[inline]
-	pub fn prepare(url: &Url) -> u64 {
+// Files coming part by part. It's ended with `Done` once `done()` is called,
+// or with `Abort` when dropped before that, e.g. the task sending them has been
+// aborted, so the folder doesn't stay loading forever.
+pub struct FilesStream {
+	pub url: Url,
+	ticket:  u64,
+	done:    bool,
+}
+
+impl FilesStream {
+	pub fn new(url: &Url) -> Self {
 		let ticket = FILES_TICKET.fetch_add(1, Ordering::Relaxed);
-		emit!(Files(Self::Part(url.clone(), ticket, Vec::new())));
-		ticket
+		emit!(Files(FilesOp::Part(url.clone(), ticket, Vec::new())));
+		Self { url: url.clone(), ticket, done: false }
+	}
+
+	#[inline]
+	pub fn part(&self, items: Vec<File>) {
+		emit!(Files(FilesOp::Part(self.url.clone(), self.ticket, items)));
+	}
+
+	#[inline]
+	pub fn done(mut self) { self.done = true; }
+}
+
+impl Drop for FilesStream {
+	fn drop(&mut self) {
+		let url = self.url.clone();
+		if self.done {
+			emit!(Files(FilesOp::Done(url, self.ticket)));
+		} else {
+			emit!(Files(FilesOp::Abort(url, self.ticket)));
+		}
 	}
 }
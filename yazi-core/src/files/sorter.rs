@@ -1,7 +1,7 @@
-use std::{cmp::Ordering, collections::BTreeMap, mem};
+use std::{borrow::Cow, cmp::Ordering, collections::BTreeMap, mem};
 
 use yazi_config::manager::SortBy;
-use yazi_shared::{natsort, Url};
+use yazi_shared::{natsort, translit, Url};
 
 use super::File;
 
@@ -11,6 +11,7 @@ pub struct FilesSorter {
 	pub sensitive: bool,
 	pub reverse:   bool,
 	pub dir_first: bool,
+	pub translit:  bool,
 }
 
 impl FilesSorter {
@@ -21,6 +22,7 @@ impl FilesSorter {
 
 		match self.by {
 			SortBy::None => return false,
+			SortBy::Alphabetical if self.translit => self.sort_by_names(items, false),
 			SortBy::Alphabetical => items.sort_unstable_by(|a, b| {
 				if self.sensitive {
 					return self.cmp(&*a.url, &*b.url, self.promote(a, b));
@@ -44,7 +46,7 @@ impl FilesSorter {
 				}
 				Ordering::Equal
 			}),
-			SortBy::Natural => self.sort_naturally(items),
+			SortBy::Natural => self.sort_by_names(items, true),
 			SortBy::Size => items.sort_unstable_by(|a, b| {
 				let aa = if a.is_dir() { sizes.get(&a.url).copied() } else { None };
 				let bb = if b.is_dir() { sizes.get(&b.url).copied() } else { None };
@@ -54,12 +56,76 @@ impl FilesSorter {
 		true
 	}
 
-	fn sort_naturally(&self, items: &mut Vec<File>) {
+	// Merges the new files into the sorted ones, with the new ones sorted by
+	// themselves first, so a folder loading part by part isn't sorted all over
+	// again on every part
+	pub(super) fn merge(
+		&self,
+		items: &mut Vec<File>,
+		mut new: Vec<File>,
+		sizes: &BTreeMap<Url, u64>,
+	) {
+		if !self.sort(&mut new, sizes) {
+			items.extend(new);
+			return;
+		}
+
+		let mut merged = Vec::with_capacity(items.len() + new.len());
+		let (mut a, mut b) = (mem::take(items).into_iter().peekable(), new.into_iter().peekable());
+		while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+			if self.compare(y, x, sizes) == Ordering::Less {
+				merged.extend(b.next());
+			} else {
+				merged.extend(a.next());
+			}
+		}
+
+		merged.extend(a);
+		merged.extend(b);
+		*items = merged;
+	}
+
+	// The same order as `sort()`, but for a single pair
+	fn compare(&self, a: &File, b: &File, sizes: &BTreeMap<Url, u64>) -> Ordering {
+		let promote = self.promote(a, b);
+		match self.by {
+			SortBy::None => Ordering::Equal,
+			SortBy::Alphabetical if self.translit => {
+				self.cmp(self.name_key(a), self.name_key(b), promote)
+			}
+			SortBy::Alphabetical if self.sensitive => self.cmp(&*a.url, &*b.url, promote),
+			SortBy::Alphabetical => self.cmp(
+				a.url.as_os_str().to_ascii_uppercase(),
+				b.url.as_os_str().to_ascii_uppercase(),
+				promote,
+			),
+			SortBy::Created => match (a.created, b.created) {
+				(Some(aa), Some(bb)) => self.cmp(aa, bb, promote),
+				_ => Ordering::Equal,
+			},
+			SortBy::Modified => match (a.modified, b.modified) {
+				(Some(aa), Some(bb)) => self.cmp(aa, bb, promote),
+				_ => Ordering::Equal,
+			},
+			SortBy::Natural if promote != Ordering::Equal => promote,
+			SortBy::Natural => {
+				let ordering = natsort(&self.name_key(a), &self.name_key(b), !self.sensitive);
+				if self.reverse { ordering.reverse() } else { ordering }
+			}
+			SortBy::Size => {
+				let aa = if a.is_dir() { sizes.get(&a.url).copied() } else { None };
+				let bb = if b.is_dir() { sizes.get(&b.url).copied() } else { None };
+				self.cmp(aa.unwrap_or(a.len), bb.unwrap_or(b.len), promote)
+			}
+		}
+	}
+
+	fn sort_by_names(&self, items: &mut Vec<File>, natural: bool) {
 		let mut indices = Vec::with_capacity(items.len());
 		let mut entities = Vec::with_capacity(items.len());
 		for (i, file) in items.iter().enumerate() {
 			indices.push(i);
-			entities.push((file.url.to_string_lossy(), file));
+			entities.push((self.name_key(file), file));
 		}
 
 		indices.sort_unstable_by(|&a, &b| {
@@ -68,7 +134,11 @@ impl FilesSorter {
 				return promote;
 			}
 
-			let ordering = natsort(&entities[a].0, &entities[b].0, !self.sensitive);
+			let ordering = if natural {
+				natsort(&entities[a].0, &entities[b].0, !self.sensitive)
+			} else {
+				entities[a].0.cmp(&entities[b].0)
+			};
 			if self.reverse { ordering.reverse() } else { ordering }
 		});
 
@@ -79,6 +149,22 @@ impl FilesSorter {
 		*items = new;
 	}
 
+	// The name compared, transliterated if enabled, and lowercased when
+	// insensitive, as neither the plain comparison nor `natsort` folds it fully
+	fn name_key<'a>(&self, file: &'a File) -> Cow<'a, str> {
+		let mut s = file.url.to_string_lossy();
+		if self.translit {
+			s = match s {
+				Cow::Borrowed(b) => translit(b),
+				Cow::Owned(o) => Cow::Owned(translit(&o).into_owned()),
+			};
+		}
+		if !self.sensitive {
+			s = Cow::Owned(s.to_lowercase());
+		}
+		s
+	}
+
 	#[inline(always)]
 	#[allow(clippy::collapsible_else_if)]
 	fn cmp<T: Ord>(&self, a: T, b: T, promote: Ordering) -> Ordering {
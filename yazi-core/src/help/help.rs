@@ -67,7 +67,7 @@ impl Help {
 		}
 
 		let b = match &key {
-			Key { code: KeyCode::Backspace, shift: false, ctrl: false, alt: false } => {
+			Key { code: KeyCode::Backspace, shift: false, ctrl: false, alt: false, mouse: None } => {
 				input.backspace(false)
 			}
 			_ => input.type_(key),
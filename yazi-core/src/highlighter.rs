@@ -1,11 +1,13 @@
-use std::{mem, path::{Path, PathBuf}, sync::{atomic::{AtomicUsize, Ordering}, OnceLock}};
+use std::{io, mem, path::{Path, PathBuf}, sync::{atomic::{AtomicUsize, Ordering}, OnceLock}};
 
 use anyhow::{anyhow, Result};
 use syntect::{dumps::from_uncompressed_data, easy::HighlightLines, highlighting::{Theme, ThemeSet}, parsing::{SyntaxReference, SyntaxSet}, util::as_24_bit_terminal_escaped};
 use tokio::{fs::File, io::{AsyncBufReadExt, BufReader}};
-use yazi_config::THEME;
+use yazi_config::{PREVIEW, THEME};
 use yazi_shared::PeekError;
 
+use crate::preview::{DecodedLines, TextEncoding};
+
 static INCR: AtomicUsize = AtomicUsize::new(0);
 static SYNTECT_SYNTAX: OnceLock<SyntaxSet> = OnceLock::new();
 static SYNTECT_THEME: OnceLock<Theme> = OnceLock::new();
@@ -52,11 +54,22 @@ impl Highlighter {
 		syntaxes.find_syntax_by_first_line(&line).ok_or_else(|| anyhow!("No syntax found"))
 	}
 
-	pub async fn highlight(&self, skip: usize, limit: usize) -> Result<String, PeekError> {
-		let mut reader = BufReader::new(File::open(&self.path).await?).lines();
+	pub async fn highlight(
+		&self,
+		skip: usize,
+		limit: usize,
+		encoding: TextEncoding,
+	) -> Result<String, PeekError> {
+		let file = File::open(&self.path).await?;
+		let mut reader = if encoding.is_utf8() {
+			Lines::Stream(BufReader::new(file).lines())
+		} else {
+			Lines::Decoded(encoding.lines(file))
+		};
 
+		// Highlighting deep into a large file means highlighting everything above it
 		let syntax = Self::find_syntax(&self.path).await;
-		let mut plain = syntax.is_err();
+		let mut plain = syntax.is_err() || PREVIEW.highlight_lines.is_some_and(|n| skip + limit > n);
 
 		let mut before = Vec::with_capacity(if plain { 0 } else { skip });
 		let mut after = Vec::with_capacity(limit);
@@ -129,3 +142,17 @@ impl Highlighter {
 	#[inline]
 	pub fn abort() { INCR.fetch_add(1, Ordering::Relaxed); }
 }
+
+enum Lines {
+	Stream(tokio::io::Lines<BufReader<File>>),
+	Decoded(DecodedLines),
+}
+
+impl Lines {
+	async fn next_line(&mut self) -> io::Result<Option<String>> {
+		match self {
+			Self::Stream(lines) => lines.next_line().await,
+			Self::Decoded(lines) => lines.next_line().await,
+		}
+	}
+}
@@ -0,0 +1,103 @@
+use std::ffi::OsString;
+
+use parking_lot::Mutex;
+use tracing::error;
+use yazi_config::{hooks::Hook, keymap::{Exec, KeymapLayer}, HOOKS};
+use yazi_shared::Url;
+
+use crate::{emit, external::{self, ShellOpt}};
+
+static LAST_CD: Mutex<Option<Url>> = Mutex::new(None);
+
+pub struct Hooks;
+
+impl Hooks {
+	pub fn cd(url: &Url) {
+		if HOOKS.cd.is_empty() {
+			return;
+		}
+
+		let mut last = LAST_CD.lock();
+		if last.as_ref() != Some(url) {
+			*last = Some(url.clone());
+			let cwd = url.as_os_str().to_owned();
+			Self::emit(Self::run(&HOOKS.cd, "cd", vec![("YAZI_CWD".to_owned(), cwd.clone())], vec![cwd]));
+		}
+	}
+
+	pub fn open(urls: &[&Url]) {
+		if HOOKS.open.is_empty() {
+			return;
+		}
+
+		let args: Vec<_> = urls.iter().map(|u| u.as_os_str().to_owned()).collect();
+		let files = args.iter().enumerate().fold(OsString::new(), |mut s, (i, a)| {
+			if i > 0 {
+				s.push("\n");
+			}
+			s.push(a);
+			s
+		});
+		Self::emit(Self::run(&HOOKS.open, "open", vec![("YAZI_FILES".to_owned(), files)], args));
+	}
+
+	pub fn task(name: &str, fail: u32, canceled: bool) {
+		if HOOKS.task.is_empty() {
+			return;
+		}
+
+		let env = vec![
+			("YAZI_TASK".to_owned(), name.into()),
+			("YAZI_TASK_FAIL".to_owned(), fail.to_string().into()),
+			("YAZI_TASK_STATE".to_owned(), if canceled { "canceled" } else { "done" }.into()),
+		];
+		Self::emit(Self::run(&HOOKS.task, "task", env, vec![]));
+	}
+
+	// The plugin hooks are returned for the caller to run right away,
+	// since no more events are handled once quitting
+	pub fn quit(cwd: &Url) -> Vec<Exec> {
+		let cwd = cwd.as_os_str().to_owned();
+		Self::run(&HOOKS.quit, "quit", vec![("YAZI_CWD".to_owned(), cwd.clone())], vec![cwd])
+	}
+
+	fn run(
+		hooks: &[Hook],
+		kind: &str,
+		mut env: Vec<(String, OsString)>,
+		args: Vec<OsString>,
+	) -> Vec<Exec> {
+		env.push(("YAZI_HOOK".to_owned(), kind.into()));
+
+		let mut plugins = vec![];
+		for hook in hooks {
+			match hook {
+				Hook::Exec(exec) => {
+					let result = external::shell(ShellOpt {
+						cmd:    exec.into(),
+						arg0:   Default::default(),
+						args:   args.clone(),
+						env:    env.clone(),
+						piped:  false,
+						orphan: true,
+					});
+					if let Err(e) = result {
+						error!("failed to run the {kind} hook `{exec}`: {e}");
+					}
+				}
+				Hook::Plugin(name) => {
+					let exec = Exec::call("plugin", vec![name.clone()]);
+					plugins.push(env.iter().fold(exec, |e, (k, v)| e.with(k, v.to_string_lossy())));
+				}
+			}
+		}
+		plugins
+	}
+
+	#[inline]
+	fn emit(plugins: Vec<Exec>) {
+		if !plugins.is_empty() {
+			emit!(Call(plugins, KeymapLayer::Manager));
+		}
+	}
+}
@@ -24,6 +24,9 @@ impl Input {
 
 		if let Some(cb) = self.callback.take() {
 			let value = self.snap_mut().value.clone();
+			if opt.submit {
+				self.history.push(&value);
+			}
 			_ = cb.send(if opt.submit { Ok(value) } else { Err(InputError::Canceled(value)) });
 		}
 
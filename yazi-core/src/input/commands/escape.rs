@@ -20,6 +20,7 @@ impl Input {
 			}
 			InputMode::Normal => {
 				snap.op = InputOp::None;
+				self.object = None;
 			}
 			InputMode::Insert => {
 				snap.mode = InputMode::Normal;
@@ -14,6 +14,10 @@ impl From<&Exec> for Opt {
 impl Input {
 	pub fn forward(&mut self, opt: impl Into<Opt>) -> bool {
 		let opt = opt.into() as Opt;
+		if let Some(around) = self.object.take() {
+			return self.handle_object(around);
+		}
+
 		let snap = self.snap();
 
 		let mut it = snap.value.chars().skip(snap.cursor).enumerate();
@@ -0,0 +1,48 @@
+use yazi_config::keymap::Exec;
+
+use crate::input::{op::InputOp, Input};
+
+pub struct Opt {
+	step: isize,
+}
+
+impl From<&Exec> for Opt {
+	fn from(e: &Exec) -> Self {
+		Self { step: e.args.first().and_then(|s| s.parse().ok()).unwrap_or(0) }
+	}
+}
+
+impl Input {
+	pub fn history(&mut self, opt: impl Into<Opt>) -> bool {
+		let opt = opt.into() as Opt;
+		let len = self.history.entries().len();
+
+		// Past the newest entry is the value being typed before cycling
+		let old = self.history.cursor.unwrap_or(len);
+		let new = (old as isize + opt.step).clamp(0, len as isize) as usize;
+		if new == old {
+			return false;
+		}
+
+		let value = if new == len {
+			self.history.cursor = None;
+			std::mem::take(&mut self.history.draft)
+		} else {
+			if self.history.cursor.is_none() {
+				self.history.draft = self.snap().value.clone();
+			}
+			self.history.cursor = Some(new);
+			self.history.entries()[new].clone()
+		};
+
+		let snap = self.snap_mut();
+		let count = value.chars().count();
+		(snap.value, snap.op) = (value, InputOp::None);
+		(snap.offset, snap.cursor) = (0, 0);
+
+		self.move_(count as isize);
+		self.snaps.tag(self.limit());
+		self.flush_value();
+		true
+	}
+}
@@ -15,7 +15,15 @@ impl From<bool> for Opt {
 
 impl Input {
 	pub fn insert(&mut self, opt: impl Into<Opt>) -> bool {
+		let opt = opt.into() as Opt;
 		let snap = self.snap_mut();
+
+		// `i` and `a` after an operator or in visual mode start a text object
+		if snap.mode == InputMode::Normal && snap.op != InputOp::None {
+			self.object = Some(opt.append);
+			return false;
+		}
+
 		if snap.mode == InputMode::Normal {
 			snap.op = InputOp::None;
 			snap.mode = InputMode::Insert;
@@ -23,7 +31,6 @@ impl Input {
 			return false;
 		}
 
-		let opt = opt.into() as Opt;
 		if opt.append {
 			self.move_(1);
 		}
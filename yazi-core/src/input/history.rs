@@ -0,0 +1,88 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use tokio::{fs, sync::mpsc::{self, UnboundedSender}};
+use tracing::error;
+use yazi_config::BOOT;
+
+// Entries kept for each kind of prompt, the oldest ones are dropped
+const LIMIT: usize = 100;
+
+#[derive(Default)]
+pub(super) struct InputHistory {
+	loaded:  bool,
+	entries: BTreeMap<String, Vec<String>>,
+	writer:  Option<UnboundedSender<String>>,
+
+	kind:              Option<&'static str>,
+	pub(super) cursor: Option<usize>,
+	pub(super) draft:  String,
+}
+
+impl InputHistory {
+	pub(super) fn start(&mut self, kind: Option<&'static str>) {
+		if kind.is_some() && !self.loaded {
+			self.loaded = true;
+			self.entries = std::fs::read_to_string(Self::path())
+				.ok()
+				.and_then(|s| serde_json::from_str(&s).ok())
+				.unwrap_or_default();
+		}
+
+		self.kind = kind;
+		self.cursor = None;
+		self.draft.clear();
+	}
+
+	pub(super) fn push(&mut self, value: &str) {
+		let Some(kind) = self.kind else {
+			return;
+		};
+		if value.trim().is_empty() {
+			return;
+		}
+
+		let entries = self.entries.entry(kind.to_owned()).or_default();
+		entries.retain(|v| v != value);
+		entries.push(value.to_owned());
+		if entries.len() > LIMIT {
+			entries.drain(..entries.len() - LIMIT);
+		}
+
+		if let Ok(json) = serde_json::to_string(&self.entries) {
+			_ = self.writer.get_or_insert_with(Self::writer).send(json);
+		}
+	}
+
+	// The saves go through a single task one by one, and only the latest of
+	// those queued up is written. It's written to a file of this process first
+	// and then renamed over, so that other instances never see a partial one
+	fn writer() -> UnboundedSender<String> {
+		let (tx, mut rx) = mpsc::unbounded_channel::<String>();
+		tokio::spawn(async move {
+			let path = Self::path();
+			let tmp = path.with_extension(format!("{}.tmp", std::process::id()));
+			while let Some(mut json) = rx.recv().await {
+				while let Ok(newer) = rx.try_recv() {
+					json = newer;
+				}
+
+				let result = match fs::write(&tmp, json).await {
+					Ok(()) => fs::rename(&tmp, &path).await,
+					Err(e) => Err(e),
+				};
+				if let Err(e) = result {
+					error!("failed to save the input history to {path:?}: {e}");
+				}
+			}
+		});
+		tx
+	}
+
+	#[inline]
+	pub(super) fn entries(&self) -> &[String] {
+		self.kind.and_then(|k| self.entries.get(k)).map_or(&[], |v| v.as_slice())
+	}
+
+	#[inline]
+	fn path() -> PathBuf { BOOT.state_dir.join("input_history.json") }
+}
@@ -1,18 +1,19 @@
 use std::ops::Range;
 
-use tokio::sync::mpsc::UnboundedSender;
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
 use unicode_width::UnicodeWidthStr;
 use yazi_config::{popup::{InputOpt, Position}, INPUT};
-use yazi_shared::InputError;
+use yazi_shared::{CharKind, InputError};
 
-use super::{mode::InputMode, op::InputOp, InputSnap, InputSnaps};
-use crate::external;
+use super::{mode::InputMode, op::InputOp, InputHistory, InputSnap, InputSnaps};
+use crate::{completion::Completion, external};
 
 #[derive(Default)]
 pub struct Input {
-	pub(super) snaps: InputSnaps,
-	pub ticket:       usize,
-	pub visible:      bool,
+	pub(super) snaps:   InputSnaps,
+	pub(super) history: InputHistory,
+	pub ticket:         usize,
+	pub visible:        bool,
 
 	pub title:    String,
 	pub position: Position,
@@ -24,6 +25,9 @@ pub struct Input {
 
 	// Shell
 	pub(super) highlight: bool,
+
+	// A text object pending after an operator, true for "around" or false for "inner"
+	pub(super) object: Option<bool>,
 }
 
 impl Input {
@@ -40,9 +44,46 @@ impl Input {
 
 		// Shell
 		self.highlight = opt.highlight;
+		self.object = None;
 
 		// Reset snaps
 		self.snaps.reset(opt.value, self.limit());
+		self.history.start(opt.history);
+		if let Some(cursor) = opt.cursor {
+			self.place(cursor);
+		}
+	}
+
+	// Put the cursor at the start of the range, or select it in visual mode
+	fn place(&mut self, Range { start, end }: Range<usize>) {
+		let limit = self.limit();
+		let snap = self.snaps.current_mut();
+		let count = snap.count();
+		let (start, end) = (start.min(count), end.min(count));
+
+		if start < end {
+			snap.mode = InputMode::Normal;
+			snap.op = InputOp::Select(start);
+			snap.cursor = end - 1;
+		} else {
+			snap.cursor = start;
+		}
+
+		snap.offset = usize::MAX;
+		snap.reset(limit);
+		self.snaps.tag(limit);
+	}
+
+	// Wait for the submitted value, completing the path being typed in the meantime
+	pub async fn _recv(rx: &mut UnboundedReceiver<Result<String, InputError>>) -> Option<String> {
+		while let Some(result) = rx.recv().await {
+			match result {
+				Ok(s) => return Some(s),
+				Err(InputError::Completed(before, ticket)) => Completion::_trigger(&before, ticket),
+				Err(_) => break,
+			}
+		}
+		None
 	}
 
 	#[inline]
@@ -64,6 +105,7 @@ impl Input {
 	}
 
 	pub(super) fn handle_op(&mut self, cursor: usize, include: bool) -> bool {
+		self.object = None;
 		let old = self.snap().clone();
 		let snap = self.snaps.current_mut();
 
@@ -104,6 +146,32 @@ impl Input {
 		true
 	}
 
+	// Apply the pending operator to the word under the cursor, along with the
+	// spaces after it, or before it if none, for "around"
+	pub(super) fn handle_object(&mut self, around: bool) -> bool {
+		let snap = self.snap();
+		let kinds: Vec<_> = snap.value.chars().map(CharKind::new).collect();
+		let Some(&kind) = kinds.get(snap.cursor) else {
+			return false;
+		};
+
+		let mut start = kinds[..snap.cursor].iter().rposition(|&k| k != kind).map_or(0, |i| i + 1);
+		let mut end =
+			kinds[snap.cursor..].iter().position(|&k| k != kind).map_or(kinds.len(), |i| snap.cursor + i);
+		if around && kind != CharKind::Space {
+			let after = kinds[end..].iter().take_while(|&&k| k == CharKind::Space).count();
+			if after > 0 {
+				end += after;
+			} else {
+				start -= kinds[..start].iter().rev().take_while(|&&k| k == CharKind::Space).count();
+			}
+		}
+
+		let op = snap.op.with_start(start);
+		self.snap_mut().op = op;
+		self.handle_op(end - 1, true)
+	}
+
 	#[inline]
 	pub(super) fn flush_value(&mut self) {
 		self.ticket = self.ticket.wrapping_add(1);
@@ -127,6 +195,9 @@ impl Input {
 	#[inline]
 	pub fn mode(&self) -> InputMode { self.snap().mode }
 
+	#[inline]
+	pub fn is_visual(&self) -> bool { matches!(self.snap().op, InputOp::Select(_)) }
+
 	#[inline]
 	pub fn cursor(&self) -> u16 {
 		let snap = self.snap();
@@ -1,4 +1,5 @@
 mod commands;
+mod history;
 mod input;
 mod mode;
 mod op;
@@ -6,6 +7,7 @@ mod shell;
 mod snap;
 mod snaps;
 
+use history::*;
 pub use input::*;
 pub use mode::*;
 use op::*;
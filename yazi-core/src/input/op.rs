@@ -21,6 +21,16 @@ impl InputOp {
 		}
 	}
 
+	#[inline]
+	pub(super) fn with_start(self, start: usize) -> Self {
+		match self {
+			InputOp::None => InputOp::None,
+			InputOp::Select(_) => InputOp::Select(start),
+			InputOp::Delete(cut, insert, _) => InputOp::Delete(cut, insert, start),
+			InputOp::Yank(_) => InputOp::Yank(start),
+		}
+	}
+
 	#[inline]
 	pub(super) fn range(&self, cursor: usize, include: bool) -> Option<Range<usize>> {
 		self
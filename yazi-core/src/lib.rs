@@ -8,15 +8,19 @@
 
 mod blocker;
 pub mod completion;
+pub mod confirm;
 mod context;
 mod event;
+mod event_log;
 pub mod external;
 pub mod files;
 pub mod help;
 mod highlighter;
+mod hooks;
 pub mod input;
 pub mod manager;
 pub mod preview;
+mod remote;
 pub mod select;
 mod step;
 pub mod tab;
@@ -26,7 +30,14 @@ pub mod which;
 pub use blocker::*;
 pub use context::*;
 pub use event::*;
+pub use event_log::*;
 pub use highlighter::*;
+pub use hooks::*;
+pub use remote::*;
 pub use step::*;
 
-pub fn init() { init_blocker(); }
+pub fn init() {
+	init_blocker();
+	EventLog::init();
+	preview::Cache::init();
+}
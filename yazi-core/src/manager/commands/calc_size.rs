@@ -0,0 +1,15 @@
+use yazi_config::keymap::Exec;
+
+use crate::{manager::Manager, tasks::Tasks};
+
+pub struct Opt;
+impl From<&Exec> for Opt {
+	fn from(_: &Exec) -> Self { Self }
+}
+
+impl Manager {
+	pub fn calc_size(&mut self, _: impl Into<Opt>, tasks: &Tasks) -> bool {
+		let targets = self.selected().into_iter().filter(|f| f.is_dir()).map(|f| &f.url).collect();
+		tasks.file_size(targets)
+	}
+}
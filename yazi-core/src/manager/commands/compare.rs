@@ -0,0 +1,45 @@
+use yazi_config::keymap::Exec;
+use yazi_shared::{MimeKind, Url, MIME_DIR};
+
+use crate::manager::Manager;
+
+pub struct Opt {
+	visual: bool,
+}
+
+impl From<&Exec> for Opt {
+	fn from(e: &Exec) -> Self { Self { visual: e.named.contains_key("visual") } }
+}
+
+impl Manager {
+	pub fn compare(&mut self, opt: impl Into<Opt>) -> bool {
+		let opt = opt.into() as Opt;
+		let Some(hovered) = self.hovered().cloned() else {
+			return false;
+		};
+
+		let [a, b] = &self.selected()[..] else {
+			return false;
+		};
+		if a.is_dir() || b.is_dir() {
+			return false;
+		}
+
+		let image = |f: &Url| self.mimetype.get(f).is_some_and(|m| MimeKind::new(m) == MimeKind::Image);
+		if opt.visual && !(image(&a.url) && image(&b.url)) {
+			return false;
+		}
+
+		let mime = if hovered.is_dir() {
+			MIME_DIR.to_owned()
+		} else if let Some(mime) = self.mimetype.get(&hovered.url) {
+			mime.clone()
+		} else {
+			return false;
+		};
+
+		let pair = [a.url.clone(), b.url.clone()];
+		self.active_mut().preview.compare(&hovered.url, &mime, pair, opt.visual);
+		false
+	}
+}
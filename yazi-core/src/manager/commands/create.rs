@@ -1,10 +1,12 @@
-use std::path::{PathBuf, MAIN_SEPARATOR};
+use std::path::{Component, Path, PathBuf, MAIN_SEPARATOR};
 
+use anyhow::Result;
 use tokio::fs;
-use yazi_config::{keymap::Exec, popup::InputOpt};
-use yazi_shared::Url;
+use tracing::error;
+use yazi_config::{keymap::Exec, popup::{ConfirmOpt, InputOpt}, MANAGER};
+use yazi_shared::{expand_names, expand_vars};
 
-use crate::{emit, files::{File, FilesOp}, manager::Manager};
+use crate::{emit, files::{File, FilesOp}, input::Input, manager::Manager};
 
 pub struct Opt {
 	force: bool,
@@ -19,35 +21,86 @@ impl Manager {
 		let opt = opt.into() as Opt;
 		let cwd = self.cwd().to_owned();
 		tokio::spawn(async move {
-			let mut result = emit!(Input(InputOpt::create()));
-			let Some(Ok(name)) = result.recv().await else {
-				return Ok(());
+			let (file, dir) = Self::create_modes();
+			let mut result = emit!(Input(InputOpt::create(&file, &dir)));
+			let Some(names) = Input::_recv(&mut result).await else {
+				return;
 			};
 
-			let path = cwd.join(&name);
-			if !opt.force && fs::symlink_metadata(&path).await.is_ok() {
-				match emit!(Input(InputOpt::overwrite())).recv().await {
-					Some(Ok(c)) if c == "y" || c == "Y" => (),
-					_ => return Ok(()),
+			// Several names can be given at once, the last one made is hovered
+			let mut last = None;
+			for name in expand_names(&names) {
+				let name = Self::portable_name(&cwd, name).await;
+				let path = cwd.join(expand_vars(&name));
+				if !opt.force
+					&& fs::symlink_metadata(&path).await.is_ok()
+					&& !emit!(Confirm(ConfirmOpt::overwrite(&path))).await
+				{
+					continue;
 				}
-			}
 
-			if name.ends_with(MAIN_SEPARATOR) {
-				fs::create_dir_all(&path).await?;
-			} else {
-				fs::create_dir_all(&path.parent().unwrap()).await.ok();
-				fs::File::create(&path).await?;
+				// A failed one doesn't stop the rest
+				if let Err(e) = Self::create_do(&path, name.ends_with(MAIN_SEPARATOR)).await {
+					error!("failed to create {path:?}: {e}");
+					continue;
+				}
+
+				// Only what's made right in the cwd shows up there, unlike "~/a" or "../a"
+				let first = path.strip_prefix(&cwd).and_then(|p| p.components().next());
+				let Some(Component::Normal(first)) = first else {
+					continue;
+				};
+
+				let child = cwd.join(first);
+				if let Ok(f) = File::from(child.clone()).await {
+					emit!(Files(FilesOp::Creating(cwd.clone(), f.into_map())));
+					last = Some(child);
+				}
 			}
 
-			let child =
-				Url::from(path.components().take(cwd.components().count() + 1).collect::<PathBuf>());
-			if let Ok(f) = File::from(child.clone()).await {
-				emit!(Files(FilesOp::Creating(cwd, f.into_map())));
-				Manager::_hover(Some(child));
+			if last.is_some() {
+				Manager::_hover(last);
 				Manager::_refresh();
 			}
-			Ok::<(), anyhow::Error>(())
 		});
 		false
 	}
+
+	async fn create_do(path: &Path, is_dir: bool) -> Result<()> {
+		if is_dir {
+			fs::create_dir_all(path).await?;
+		} else {
+			fs::create_dir_all(path.parent().unwrap()).await.ok();
+			if let Some(template) = Self::template(path).await {
+				fs::copy(template, path).await?;
+			} else {
+				fs::File::create(path).await?;
+			}
+		}
+
+		#[cfg(unix)]
+		if let Some(mode) = if is_dir { MANAGER.create_dir_mode } else { MANAGER.create_file_mode } {
+			use std::os::unix::fs::PermissionsExt;
+			fs::set_permissions(path, std::fs::Permissions::from_mode(mode)).await?;
+		}
+		Ok(())
+	}
+
+	// The template with the same name as the new file, if it's a regular file
+	async fn template(path: &Path) -> Option<PathBuf> {
+		let template = MANAGER.templates_dir()?.join(path.file_name()?);
+		fs::metadata(&template).await.ok().filter(|m| m.is_file()).map(|_| template)
+	}
+
+	// The permissions of the new files and directories, as shown in the prompt
+	#[cfg(unix)]
+	fn create_modes() -> (String, String) {
+		let umask = yazi_shared::umask();
+		let file = MANAGER.create_file_mode.unwrap_or(0o666 & !umask);
+		let dir = MANAGER.create_dir_mode.unwrap_or(0o777 & !umask);
+		(yazi_shared::permissions(file)[1..].to_owned(), yazi_shared::permissions(dir)[1..].to_owned())
+	}
+
+	#[cfg(windows)]
+	fn create_modes() -> (String, String) { ("default".to_owned(), "default".to_owned()) }
 }
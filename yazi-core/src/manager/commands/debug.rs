@@ -0,0 +1,88 @@
+use std::io::{stdout, Write};
+
+use crossterm::{event::{self, Event as CrosstermEvent, KeyCode}, terminal::{disable_raw_mode, enable_raw_mode}};
+use ratatui::prelude::Rect;
+use yazi_adaptor::{Diagnosis, ADAPTOR};
+use yazi_config::keymap::Exec;
+use yazi_shared::{Defer, Term};
+
+use crate::{emit, manager::Manager, Event, BLOCKER};
+
+pub struct Opt {
+	graphics: bool,
+}
+
+impl From<&Exec> for Opt {
+	fn from(e: &Exec) -> Self { Self { graphics: e.named.contains_key("graphics") } }
+}
+
+impl Manager {
+	pub fn debug(&mut self, opt: impl Into<Opt>) -> bool {
+		let opt = opt.into() as Opt;
+		tokio::spawn(async move {
+			let _guard = BLOCKER.acquire().await.unwrap();
+
+			emit!(Stop(true)).await;
+			let _defer = Defer::new(|| {
+				disable_raw_mode().ok();
+				Event::Stop(false, None).emit();
+			});
+
+			Term::clear(&mut stdout()).ok();
+			enable_raw_mode().ok();
+
+			let mut report = Diagnosis::report();
+			if opt.graphics {
+				report.extend(Diagnosis::probe().await);
+			}
+
+			let width = report.iter().map(|(k, _)| k.chars().count()).max().unwrap_or(0);
+			let mut lines = report.len() as u16;
+			{
+				let mut stdout = stdout().lock();
+				Term::move_to(&mut stdout, 0, 0).ok();
+				for (k, v) in report {
+					write!(stdout, "{k:width$}  {v}\r\n").ok();
+				}
+			}
+
+			let mut rect = None;
+			if opt.graphics {
+				let size = Term::size();
+				let r = Rect {
+					x:      0,
+					y:      lines + 1,
+					width:  (size.columns / 2).min(40),
+					height: size.rows.saturating_sub(lines + 4).min(10),
+				};
+				if let Err(e) = Diagnosis::test_image(r).await {
+					write!(stdout(), "\r\nTest image failed: {e}\r\n").ok();
+					lines += 2;
+				} else {
+					lines += r.height + 1;
+					rect = Some(r);
+				}
+			}
+
+			Term::move_to(&mut stdout(), 0, lines + 1).ok();
+			stdout().write_all(b"Press `q` to quit\r\n").ok();
+			stdout().flush().ok();
+
+			// Through the same reader the app gets its keys from, so nothing is left
+			// pending on `stdin` once this screen is closed
+			_ = tokio::task::spawn_blocking(|| loop {
+				match event::read() {
+					Ok(CrosstermEvent::Key(k)) if k.code == KeyCode::Char('q') => break,
+					Ok(_) => continue,
+					Err(_) => break,
+				}
+			})
+			.await;
+
+			if let Some(rect) = rect {
+				ADAPTOR.image_hide(rect).ok();
+			}
+		});
+		false
+	}
+}
@@ -0,0 +1,18 @@
+use yazi_config::keymap::Exec;
+
+use crate::{manager::Manager, tasks::Tasks};
+
+pub struct Opt {
+	url: Option<String>,
+}
+
+impl From<&Exec> for Opt {
+	fn from(e: &Exec) -> Self { Self { url: e.args.first().cloned() } }
+}
+
+impl Manager {
+	pub fn download(&mut self, opt: impl Into<Opt>, tasks: &Tasks) -> bool {
+		let opt = opt.into() as Opt;
+		tasks.file_download(opt.url, self.cwd())
+	}
+}
@@ -0,0 +1,104 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use tokio::fs;
+use yazi_config::{keymap::Exec, popup::InputOpt};
+use yazi_shared::{path_relative_to, unique_path, Url};
+
+use crate::{emit, files::{File, FilesOp}, manager::Manager};
+
+pub struct Opt {
+	relative: bool,
+	absolute: bool,
+	force:    bool,
+}
+
+impl From<&Exec> for Opt {
+	fn from(e: &Exec) -> Self {
+		Self {
+			relative: e.named.contains_key("relative"),
+			absolute: e.named.contains_key("absolute"),
+			force:    e.named.contains_key("force"),
+		}
+	}
+}
+
+impl Manager {
+	// Made under a temporary name first, then renamed over the old one, so the
+	// link is never gone, nor lost if the new one can't be made
+	async fn edit_link_do(link: Url, target: PathBuf) -> Result<()> {
+		let tmp = unique_path(link.clone()).await;
+
+		#[cfg(unix)]
+		{
+			fs::symlink(&target, &tmp).await?;
+		}
+		#[cfg(windows)]
+		{
+			let resolved = link.parent().unwrap().join(&target);
+			if fs::metadata(resolved).await.is_ok_and(|m| m.is_dir()) {
+				fs::symlink_dir(&target, &tmp).await?;
+			} else {
+				fs::symlink_file(&target, &tmp).await?;
+			}
+		}
+
+		if let Err(e) = fs::rename(&tmp, &link).await {
+			fs::remove_file(&tmp).await.ok();
+			return Err(e.into());
+		}
+
+		let file = File::from(link).await?;
+		emit!(Files(FilesOp::Creating(file.parent().unwrap(), file.into_map())));
+		Ok(())
+	}
+
+	async fn edit_link_value(parent: &Path, current: PathBuf, opt: &Opt) -> PathBuf {
+		if !opt.relative && !opt.absolute {
+			return current;
+		}
+
+		let joined = parent.join(&current);
+		let absolute = fs::canonicalize(&joined).await.unwrap_or(joined);
+		if !opt.relative {
+			return absolute;
+		}
+
+		match fs::canonicalize(parent).await {
+			Ok(root) if absolute.is_absolute() => path_relative_to(&absolute, &root).into_owned(),
+			_ => current,
+		}
+	}
+
+	pub fn edit_link(&self, opt: impl Into<Opt>) -> bool {
+		let Some(hovered) = self.hovered().filter(|h| h.is_link()).map(|h| h.url()) else {
+			return false;
+		};
+
+		let opt = opt.into() as Opt;
+		tokio::spawn(async move {
+			let parent = hovered.parent().unwrap().to_owned();
+			let current = fs::read_link(&hovered).await?;
+			let value = Self::edit_link_value(&parent, current, &opt).await;
+
+			let mut result = emit!(Input(InputOpt::edit_link().with_value(value.to_string_lossy())));
+			let Some(Ok(target)) = result.recv().await else {
+				return Ok(());
+			};
+
+			let target = PathBuf::from(target);
+			if opt.force || fs::metadata(parent.join(&target)).await.is_ok() {
+				return Self::edit_link_do(hovered, target).await;
+			}
+
+			let mut result = emit!(Input(InputOpt::dangling()));
+			if let Some(Ok(choice)) = result.recv().await {
+				if choice == "y" || choice == "Y" {
+					return Self::edit_link_do(hovered, target).await;
+				}
+			}
+			Ok::<(), anyhow::Error>(())
+		});
+		false
+	}
+}
@@ -1,9 +1,7 @@
-use std::collections::BTreeSet;
-
 use yazi_config::keymap::{Exec, KeymapLayer};
 use yazi_shared::Url;
 
-use crate::{emit, manager::Manager};
+use crate::{emit, manager::Manager, EventLog};
 
 pub struct Opt {
 	url: Option<Url>,
@@ -23,17 +21,19 @@ impl Manager {
 	}
 
 	pub fn hover(&mut self, opt: impl Into<Opt>) -> bool {
-		// Refresh watcher
-		let mut to_watch = BTreeSet::new();
-		for tab in self.tabs.iter() {
-			to_watch.insert(&tab.current.cwd);
+		// Refresh watcher, the active tab comes first
+		let mut to_watch = Vec::new();
+		let mut tabs: Vec<_> = self.tabs.iter().enumerate().collect();
+		tabs.sort_by_key(|&(i, _)| i != self.tabs.idx);
+		for (_, tab) in tabs {
+			to_watch.push(&tab.current.cwd);
+			if let Some(ref p) = tab.parent {
+				to_watch.push(&p.cwd);
+			}
 			match tab.current.hovered() {
-				Some(h) if h.is_dir() => _ = to_watch.insert(&h.url),
+				Some(h) if h.is_dir() => to_watch.push(&h.url),
 				_ => {}
 			}
-			if let Some(ref p) = tab.parent {
-				to_watch.insert(&p.cwd);
-			}
 		}
 		self.watcher.watch(to_watch);
 
@@ -42,6 +42,9 @@ impl Manager {
 
 		// Hover
 		let opt = opt.into() as Opt;
-		self.current_mut().repos(opt.url)
+		let b = self.current_mut().repos(opt.url);
+
+		EventLog::hover(self.tabs.idx, self.hovered().map(|h| &h.url));
+		b
 	}
 }
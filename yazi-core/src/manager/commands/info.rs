@@ -0,0 +1,102 @@
+use std::{fs::Metadata, io, time::SystemTime};
+
+use tokio::fs;
+use yazi_config::{keymap::Exec, popup::SelectOpt};
+use yazi_shared::{local_time, Url};
+
+use crate::{emit, external, manager::Manager};
+
+pub struct Opt;
+impl From<&Exec> for Opt {
+	fn from(_: &Exec) -> Self { Self }
+}
+
+impl Manager {
+	pub fn info(&mut self, _: impl Into<Opt>) -> bool {
+		let Some(hovered) = self.hovered().map(|h| h.url()) else {
+			return false;
+		};
+
+		let mime = self.mimetype.get(&hovered).cloned();
+		tokio::spawn(async move {
+			let Ok(meta) = fs::symlink_metadata(&hovered).await else {
+				return;
+			};
+
+			let mime = match mime {
+				Some(m) => Some(m),
+				None => external::file(&[&hovered]).await.ok().and_then(|mut m| m.remove(&hovered)),
+			};
+
+			let rows = Self::info_rows(&hovered, &meta, mime).await;
+			let width = rows.iter().map(|(k, _)| k.len()).max().unwrap_or(0);
+			let items = rows.iter().map(|(k, v)| format!("{k:width$}  {v}")).collect();
+
+			let name = hovered.file_name().map(|n| n.to_string_lossy()).unwrap_or_default();
+			if let Ok(choice) = emit!(Select(SelectOpt::info(&name, items))).await {
+				// Picking a row copies its value
+				external::clipboard_set(&rows[choice].1).await.ok();
+			}
+		});
+		false
+	}
+
+	async fn info_rows(
+		url: &Url,
+		meta: &Metadata,
+		mime: Option<String>,
+	) -> Vec<(&'static str, String)> {
+		let or_dash = |s: Option<String>| s.unwrap_or_else(|| "-".to_owned());
+		let time = |t: io::Result<SystemTime>| or_dash(t.ok().map(local_time));
+
+		let mut rows = vec![
+			("Path", url.to_string_lossy().into_owned()),
+			("Size", format!("{} bytes ({})", meta.len(), Self::readable_size(meta.len()))),
+			("Mimetype", or_dash(mime)),
+		];
+
+		if meta.is_symlink() {
+			let target = fs::read_link(url).await.ok();
+			rows.push(("Target", or_dash(target.map(|p| p.to_string_lossy().into_owned()))));
+		}
+
+		rows.push(("Accessed", time(meta.accessed())));
+		rows.push(("Modified", time(meta.modified())));
+
+		#[cfg(unix)]
+		{
+			use std::{os::unix::fs::MetadataExt, time::{Duration, UNIX_EPOCH}};
+
+			use yazi_shared::{group_name, permissions, user_name};
+
+			let ctime = UNIX_EPOCH + Duration::new(meta.ctime().max(0) as u64, meta.ctime_nsec() as u32);
+			rows.push(("Changed", local_time(ctime)));
+			rows.push(("Created", time(meta.created())));
+
+			let (uid, gid) = (meta.uid(), meta.gid());
+			rows.push(("Owner", format!("{} ({uid})", or_dash(user_name(uid)))));
+			rows.push(("Group", format!("{} ({gid})", or_dash(group_name(gid)))));
+
+			let mode = meta.mode();
+			rows.push(("Permissions", format!("{} ({:04o})", permissions(mode), mode & 0o7777)));
+			rows.push(("Inode", meta.ino().to_string()));
+			rows.push(("Links", meta.nlink().to_string()));
+		}
+
+		#[cfg(not(unix))]
+		rows.push(("Created", time(meta.created())));
+
+		rows
+	}
+
+	fn readable_size(size: u64) -> String {
+		const UNITS: [&str; 7] = ["B", "KB", "MB", "GB", "TB", "PB", "EB"];
+
+		let (mut size, mut i) = (size as f64, 0);
+		while size > 1024.0 && i < UNITS.len() - 1 {
+			size /= 1024.0;
+			i += 1;
+		}
+		format!("{size:.1} {}", UNITS[i])
+	}
+}
@@ -1,22 +1,44 @@
-use yazi_config::keymap::Exec;
+use yazi_config::{keymap::Exec, manager::YankScope, MANAGER};
 
-use crate::{manager::Manager, tasks::Tasks};
+use crate::{manager::Manager, tasks::Tasks, Remote};
 
 pub struct Opt {
 	relative: bool,
 	force:    bool,
+	hard:     bool,
 }
 
 impl From<&Exec> for Opt {
 	fn from(e: &Exec) -> Self {
-		Self { relative: e.named.contains_key("relative"), force: e.named.contains_key("force") }
+		Self {
+			relative: e.named.contains_key("relative"),
+			force:    e.named.contains_key("force"),
+			hard:     e.named.contains_key("hard"),
+		}
 	}
 }
 
 impl Manager {
 	pub fn link(&mut self, opt: impl Into<Opt>, tasks: &Tasks) -> bool {
 		let opt = opt.into() as Opt;
-		let (cut, ref src) = self.yanked;
-		!cut && tasks.file_link(src, self.cwd(), opt.relative, opt.force)
+		let (cut, ref src) = *self.yanked();
+		if opt.hard {
+			tasks.file_hardlink(src, self.cwd(), opt.force);
+		} else {
+			tasks.file_link(src, self.cwd(), opt.relative, opt.force);
+		}
+
+		// Linking leaves the sources where they are, so a cut is turned into a copy,
+		// to keep a later paste from moving them away from under the links
+		if !cut {
+			return false;
+		}
+		let src = src.clone();
+		self.tabs.iter_mut().filter(|t| t.yanked.1 == src).for_each(|t| t.yanked.0 = false);
+		if MANAGER.yank_scope == YankScope::Shared {
+			let paths = src.iter().map(|u| u.to_string_lossy().into_owned()).collect();
+			Remote::broadcast(Exec::call("yank", paths));
+		}
+		true
 	}
 }
@@ -1,17 +1,34 @@
+mod calc_size;
 mod close;
+mod compare;
 mod create;
+mod debug;
+mod download;
+mod edit_link;
 mod hover;
+mod info;
 mod link;
+mod normalize;
 mod open;
+mod own;
 mod paste;
 mod peek;
 mod quit;
 mod refresh;
 mod remove;
 mod rename;
+mod resize;
+mod selection;
+mod subshell;
 mod suspend;
 mod tab_close;
 mod tab_create;
+mod tab_dual;
 mod tab_swap;
 mod tab_switch;
+mod thumbs;
+mod unyank;
+mod usage;
+mod verify_archive;
+mod xattr;
 mod yank;
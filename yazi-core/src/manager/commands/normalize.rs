@@ -0,0 +1,31 @@
+use yazi_config::{keymap::Exec, TASKS};
+
+use crate::{manager::Manager, tasks::Tasks};
+
+pub struct Opt<'a> {
+	preset:  Option<&'a str>,
+	dry_run: bool,
+}
+
+impl<'a> From<&'a Exec> for Opt<'a> {
+	fn from(e: &'a Exec) -> Self {
+		Self { preset: e.args.first().map(|s| s.as_str()), dry_run: e.named.contains_key("dry-run") }
+	}
+}
+
+impl Manager {
+	pub fn normalize<'a>(&mut self, opt: impl Into<Opt<'a>>, tasks: &Tasks) -> bool {
+		let opt = opt.into() as Opt;
+		let preset = match opt.preset {
+			Some(name) => TASKS.normalize.iter().find(|p| p.name == name),
+			None => TASKS.normalize.first(),
+		};
+
+		let Some(preset) = preset else {
+			return false;
+		};
+
+		let targets = self.selected().into_iter().map(|f| f.url()).collect();
+		tasks.file_normalize(targets, preset, opt.dry_run)
+	}
+}
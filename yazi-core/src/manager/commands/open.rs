@@ -1,9 +1,21 @@
-use std::ffi::OsString;
+use std::{collections::HashMap, ffi::OsString, path::{Path, PathBuf}, time::SystemTime};
 
-use yazi_config::{keymap::Exec, popup::SelectOpt, OPEN};
+use parking_lot::Mutex;
+use tokio::fs;
+use yazi_config::{keymap::Exec, manager::OpenDir, open::Opener, popup::{InputOpt, SelectOpt}, BOOT, MANAGER, OPEN, SELECT};
 use yazi_shared::MIME_DIR;
 
-use crate::{emit, external, manager::Manager};
+use crate::{emit, external::{self, ShellOpt}, manager::Manager, EventLog, Hooks};
+
+// Results of the open hooks, keyed by path and invalidated by mtime
+static VERDICTS: Mutex<Option<HashMap<PathBuf, (SystemTime, Verdict)>>> = Mutex::new(None);
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Verdict {
+	Pass,
+	Ask,
+	Block,
+}
 
 pub struct Opt {
 	interactive: bool,
@@ -16,14 +28,102 @@ impl From<&Exec> for Opt {
 impl Manager {
 	async fn open_interactive(files: Vec<(OsString, String)>) {
 		let openers = OPEN.common_openers(&files);
-		if openers.is_empty() {
+		let mut items: Vec<_> = openers.iter().map(|o| o.desc.clone()).collect();
+		items.push(SELECT.open_custom.clone());
+
+		let Ok(choice) = emit!(Select(SelectOpt::open(items))).await else {
+			return;
+		};
+		if let Some(&opener) = openers.get(choice) {
+			emit!(Open(files, Some(opener.clone())));
+			return;
+		}
+
+		// The last item, for an arbitrary command run with the files as its arguments,
+		// where there's nothing to run for a blank one
+		let mut result = emit!(Input(InputOpt::shell(true)));
+		let Some(Ok(cmd)) = result.recv().await else {
+			return;
+		};
+		if cmd.trim().is_empty() {
 			return;
 		}
+		emit!(Open(
+			files,
+			Some(Opener {
+				exec:    cmd,
+				block:   true,
+				orphan:  false,
+				desc:    Default::default(),
+				for_:    None,
+				spread:  true,
+				extract: None,
+				arg0:    None,
+			})
+		));
+	}
+
+	async fn check(files: Vec<(OsString, String)>) -> Vec<(OsString, String)> {
+		let mut allowed = Vec::with_capacity(files.len());
+		for (path, mime) in files {
+			let pass = match Self::verdict(Path::new(&path), &mime).await {
+				Verdict::Pass => true,
+				Verdict::Block => false,
+				Verdict::Ask => {
+					let name = Path::new(&path).file_name().unwrap_or_default().to_string_lossy();
+					let mut result = emit!(Input(InputOpt::suspicious(&name)));
+					matches!(result.recv().await, Some(Ok(s)) if s == "y" || s == "Y")
+				}
+			};
+			if pass {
+				allowed.push((path, mime));
+			}
+		}
+		allowed
+	}
+
+	async fn verdict(path: &Path, mime: &str) -> Verdict {
+		let hooks: Vec<_> = OPEN.hooks(path, mime).collect();
+		if hooks.is_empty() {
+			return Verdict::Pass;
+		}
+
+		let mtime = fs::metadata(path).await.and_then(|m| m.modified()).ok();
+		if let Some(mtime) = mtime {
+			let cached = VERDICTS.lock().as_ref().and_then(|m| m.get(path).copied());
+			if let Some((_, v)) = cached.filter(|&(t, _)| t == mtime) {
+				return v;
+			}
+		}
+
+		let mut verdict = Verdict::Pass;
+		for hook in hooks {
+			let child = external::shell(ShellOpt {
+				cmd:    hook.exec.clone().into(),
+				arg0:   Default::default(),
+				args:   vec![path.as_os_str().to_owned()],
+				env:    Default::default(),
+				piped:  true,
+				orphan: false,
+			});
 
-		let result = emit!(Select(SelectOpt::open(openers.iter().map(|o| o.desc.clone()).collect())));
-		if let Ok(choice) = result.await {
-			emit!(Open(files, Some(openers[choice].clone())));
+			let ok = match child {
+				Ok(child) => child.wait_with_output().await.is_ok_and(|o| o.status.success()),
+				Err(_) => false,
+			};
+			if !ok {
+				verdict = if hook.ask { Verdict::Ask } else { Verdict::Block };
+				break;
+			}
 		}
+
+		if let Some(mtime) = mtime {
+			VERDICTS
+				.lock()
+				.get_or_insert_with(Default::default)
+				.insert(path.to_owned(), (mtime, verdict));
+		}
+		verdict
 	}
 
 	pub fn open(&mut self, opt: impl Into<Opt>) -> bool {
@@ -43,6 +143,45 @@ impl Manager {
 		}
 
 		let opt = opt.into() as Opt;
+		let urls: Vec<_> = files.iter().map(|(u, _)| u).collect();
+		EventLog::open(&urls, opt.interactive);
+		Hooks::open(&urls);
+
+		// Picked in the chooser mode, without going through any openers
+		if BOOT.chooser_file.is_some() {
+			let files = files.into_iter().map(|(u, m)| (u.into_os_string(), m.unwrap_or_default()));
+			emit!(Open(files.collect(), None));
+			return false;
+		}
+
+		// The directories that can't be entered or handed over, e.g. several of them
+		// or along with files, go through the rules like any other file
+		if !opt.interactive {
+			match (MANAGER.open_dir, OPEN.opener("open")) {
+				(OpenDir::Enter, _)
+					if files.len() == 1
+						&& files[0].1.as_deref() == Some(MIME_DIR)
+						&& self.hovered().is_some_and(|h| h.url == files[0].0) =>
+				{
+					return self.active_mut().enter(());
+				}
+				(OpenDir::System, Some(opener)) => {
+					let (dirs, rest): (Vec<_>, Vec<_>) =
+						files.into_iter().partition(|(_, m)| m.as_deref() == Some(MIME_DIR));
+					if !dirs.is_empty() {
+						let dirs = dirs.into_iter().map(|(u, _)| (u.into_os_string(), MIME_DIR.to_owned()));
+						emit!(Open(dirs.collect(), Some(opener.clone())));
+					}
+					files = rest;
+				}
+				_ => {}
+			}
+
+			if files.is_empty() {
+				return false;
+			}
+		}
+
 		tokio::spawn(async move {
 			let todo: Vec<_> = files.iter().filter(|(_, m)| m.is_none()).map(|(u, _)| u).collect();
 			if let Ok(mut mimes) = external::file(&todo).await {
@@ -58,6 +197,11 @@ impl Manager {
 			let files: Vec<_> =
 				files.into_iter().filter_map(|(u, m)| m.map(|m| (u.into_os_string(), m))).collect();
 
+			let files = Self::check(files).await;
+			if files.is_empty() {
+				return;
+			}
+
 			if opt.interactive {
 				Self::open_interactive(files).await;
 				return;
@@ -0,0 +1,71 @@
+use tokio::task::spawn_blocking;
+use yazi_config::{keymap::{Exec, KeymapLayer}, popup::InputOpt};
+
+use crate::{emit, manager::Manager, tasks::Tasks};
+
+pub struct Opt {
+	owner:     Option<String>,
+	recursive: bool,
+}
+
+impl From<&Exec> for Opt {
+	fn from(e: &Exec) -> Self {
+		Self { owner: e.args.first().cloned(), recursive: e.named.contains_key("recursive") }
+	}
+}
+
+impl Manager {
+	// Complete the user names, or the group names once a `:` is typed,
+	// as the whole `user:group` so the input can take it as it is. Listing them
+	// can go through NSS, e.g. LDAP, so it's done on a blocking thread
+	#[cfg(unix)]
+	async fn own_complete(before: String, ticket: usize) {
+		let user = before.split_once(':').map(|(u, _)| u.to_owned());
+		let (name, cache) = match user {
+			Some(user) => {
+				let groups = spawn_blocking(yazi_shared::group_names).await.unwrap_or_default();
+				let cache = groups.into_iter().map(|g| format!("{user}:{g}")).collect();
+				(format!("own-groups:{user}"), cache)
+			}
+			None => {
+				let users = spawn_blocking(yazi_shared::user_names).await.unwrap_or_default();
+				("own-users".to_owned(), users)
+			}
+		};
+
+		emit!(Call(
+			Exec::call("show", cache)
+				.with("cache-name", name)
+				.with("word", before)
+				.with("ticket", ticket)
+				.vec(),
+			KeymapLayer::Completion
+		));
+	}
+
+	pub fn own(&mut self, opt: impl Into<Opt>, tasks: &Tasks) -> bool {
+		let opt = opt.into() as Opt;
+		if let Some(owner) = opt.owner {
+			let targets = self.selected().into_iter().map(|f| f.url()).collect();
+			return tasks.file_own(targets, &owner, opt.recursive);
+		}
+
+		tokio::spawn(async move {
+			let mut result = emit!(Input(InputOpt::own()));
+			while let Some(r) = result.recv().await {
+				match r {
+					Ok(owner) => {
+						let exec = Exec::call("own", vec![owner]).with_bool("recursive", opt.recursive);
+						emit!(Call(exec.vec(), KeymapLayer::Manager));
+					}
+					#[cfg(unix)]
+					Err(yazi_shared::InputError::Completed(before, ticket)) => {
+						tokio::spawn(Self::own_complete(before, ticket));
+					}
+					_ => break,
+				}
+			}
+		});
+		false
+	}
+}
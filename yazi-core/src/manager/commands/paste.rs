@@ -1,21 +1,38 @@
-use yazi_config::keymap::Exec;
+use yazi_config::{keymap::Exec, tasks::PasteOrder, TASKS};
 
 use crate::{manager::Manager, tasks::Tasks};
 
 pub struct Opt {
 	force: bool,
+	here:  bool,
+	order: PasteOrder,
 }
 
 impl From<&Exec> for Opt {
-	fn from(e: &Exec) -> Self { Self { force: e.named.contains_key("force") } }
+	fn from(e: &Exec) -> Self {
+		Self {
+			force: e.named.contains_key("force"),
+			here:  e.named.contains_key("here"),
+			order: e.named.get("order").and_then(|s| s.parse().ok()).unwrap_or(TASKS.paste_order),
+		}
+	}
 }
 
 impl Manager {
 	pub fn paste(&mut self, opt: impl Into<Opt>, tasks: &Tasks) -> bool {
-		let dest = self.cwd();
-		let (cut, ref src) = self.yanked;
-
 		let opt = opt.into() as Opt;
-		if cut { tasks.file_cut(src, dest, opt.force) } else { tasks.file_copy(src, dest, opt.force) }
+
+		// Into the other pane in the dual-pane layout, unless `--here`
+		let dest = match self.tabs.other() {
+			Some(t) if !opt.here => &t.current.cwd,
+			_ => self.cwd(),
+		};
+		let (cut, ref src) = *self.yanked();
+
+		if cut {
+			tasks.file_cut(src, dest, opt.force, opt.order)
+		} else {
+			tasks.file_copy(src, dest, opt.force, opt.order)
+		}
 	}
 }
@@ -1,27 +1,47 @@
+use yazi_config::PREVIEW;
+use yazi_shared::MIME_DIR;
+
 use crate::manager::Manager;
 
 impl Manager {
-	pub fn peek(&mut self, sequent: bool, show_image: bool) -> bool {
+	pub fn peek(&mut self, sequent: bool, show_image: bool, force: bool) -> bool {
+		// There's no preview pane in the dual-pane layout
+		if self.tabs.other.is_some() {
+			return self.active_mut().preview.reset(|_| true);
+		}
+
 		let Some(hovered) = self.hovered().cloned() else {
 			return self.active_mut().preview.reset(|_| true);
 		};
 
 		let url = &hovered.url;
-		if !show_image {
+		if force {
+			self.active_mut().preview.reset(|_| true);
+		} else if !show_image {
 			self.active_mut().preview.reset(|l| l.is_image());
 		}
 
-		if hovered.is_dir() {
-			let position = self.active().history(url).map(|f| (f.offset, f.files.len()));
-			self.active_mut().preview.folder(url, position, sequent);
-			return false;
-		}
-
-		let Some(mime) = self.mimetype.get(url).cloned() else {
+		let mime = if hovered.is_dir() {
+			MIME_DIR.to_owned()
+		} else if let Some(mime) = self.mimetype.touch(url) {
+			mime.clone()
+		} else {
 			return self.active_mut().preview.reset(|_| true);
 		};
 
-		if sequent {
+		// Known-slow mounts only get a placeholder, until the preview is forced
+		if !force && PREVIEW.is_slow(url) {
+			let preview = &mut self.active_mut().preview;
+			if !preview.lock.as_ref().is_some_and(|l| &l.url == url && !l.is_slow()) {
+				preview.slow(url, &mime);
+				return false;
+			}
+		}
+
+		if hovered.is_dir() {
+			let position = self.active().history(url).map(|f| (f.offset, f.files.len()));
+			self.active_mut().preview.folder(url, position, sequent);
+		} else if sequent {
 			self.active_mut().preview.sequent(url, &mime, show_image);
 		} else {
 			self.active_mut().preview.go(url, &mime, show_image);
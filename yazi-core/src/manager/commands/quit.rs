@@ -1,4 +1,4 @@
-use yazi_config::{keymap::Exec, popup::InputOpt};
+use yazi_config::{keymap::Exec, popup::ConfirmOpt};
 
 use crate::{emit, manager::Manager, tasks::Tasks};
 
@@ -17,18 +17,15 @@ impl Manager {
 	pub fn quit(&self, opt: impl Into<Opt>, tasks: &Tasks) -> bool {
 		let opt = opt.into() as Opt;
 
-		let tasks = tasks.len();
-		if tasks == 0 {
+		let tasks = tasks.names();
+		if tasks.is_empty() {
 			emit!(Quit(opt.no_cwd_file));
 			return false;
 		}
 
 		tokio::spawn(async move {
-			let mut result = emit!(Input(InputOpt::quit(tasks)));
-			if let Some(Ok(choice)) = result.recv().await {
-				if choice == "y" || choice == "Y" {
-					emit!(Quit(opt.no_cwd_file));
-				}
+			if emit!(Confirm(ConfirmOpt::quit(tasks))).await {
+				emit!(Quit(opt.no_cwd_file));
 			}
 		});
 		false
@@ -2,12 +2,14 @@ use std::env;
 
 use yazi_config::keymap::{Exec, KeymapLayer};
 
-use crate::{emit, manager::Manager};
+use crate::{emit, manager::Manager, EventLog, Hooks};
 
-pub struct Opt;
+pub struct Opt {
+	hard: bool,
+}
 
 impl From<&Exec> for Opt {
-	fn from(_: &Exec) -> Self { Self }
+	fn from(e: &Exec) -> Self { Self { hard: e.named.contains_key("hard") } }
 }
 
 impl Manager {
@@ -16,7 +18,13 @@ impl Manager {
 		emit!(Call(Exec::call("refresh", vec![]).vec(), KeymapLayer::Manager));
 	}
 
-	pub fn refresh(&mut self, _: impl Into<Opt>) -> bool {
+	pub fn refresh(&mut self, opt: impl Into<Opt>) -> bool {
+		if (opt.into() as Opt).hard {
+			self.refresh_hard();
+		}
+
+		EventLog::cd(self.tabs.idx, self.cwd());
+		Hooks::cd(self.cwd());
 		env::set_current_dir(self.cwd()).ok();
 		env::set_var("PWD", self.cwd());
 
@@ -27,8 +35,28 @@ impl Manager {
 		} else {
 			self.watcher.trigger_dirs(&[self.cwd()]);
 		}
+		if let Some(t) = self.tabs.other() {
+			self.watcher.trigger_dirs(&[&t.current.cwd]);
+		}
 
 		Self::_hover(None);
 		false
 	}
+
+	// Drop everything cached for the active tab, so it's all read again
+	fn refresh_hard(&mut self) {
+		let dirs: Vec<_> =
+			[Some(self.cwd()), self.parent().map(|p| &p.cwd)].into_iter().flatten().cloned().collect();
+		self.mimetype.retain(|u| !u.parent_url().is_some_and(|p| dirs.contains(&p)));
+
+		let tab = self.active_mut();
+		tab.history.clear();
+		tab.current.files.sizes.clear();
+		tab.preview.reset(|_| true);
+		tab.current.set_page(true);
+
+		if let Some(h) = self.hovered().filter(|h| h.is_dir()) {
+			self.watcher.trigger_dirs(&[&h.url]);
+		}
+	}
 }
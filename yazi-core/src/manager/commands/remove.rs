@@ -19,7 +19,12 @@ impl From<&Exec> for Opt {
 impl Manager {
 	pub fn remove(&mut self, opt: impl Into<Opt>, tasks: &Tasks) -> bool {
 		let opt = opt.into() as Opt;
-		let targets = self.selected().into_iter().map(|f| f.url()).collect();
-		tasks.file_remove(targets, opt.force, opt.permanently)
+		let targets: Vec<_> = self.selected_all().into_iter().map(|f| f.url()).collect();
+
+		// The files selected outside of the current directory come last, they're
+		// out of sight, so they always get confirmed and are marked in it
+		let elsewhere = self.active().selected_elsewhere().count();
+		let marked = (targets.len() - elsewhere..targets.len()).collect();
+		tasks.file_remove(targets, marked, opt.force && elsewhere == 0, opt.permanently)
 	}
 }
@@ -1,18 +1,64 @@
-use std::{collections::BTreeSet, ffi::OsStr, io::{stdout, BufWriter, Write}, path::PathBuf};
+use std::{collections::{BTreeSet, HashSet}, ffi::OsStr, io::{stdout, BufWriter, Write}, ops::Range, path::{Path, PathBuf, MAIN_SEPARATOR}};
 
 use anyhow::{anyhow, bail, Result};
+use regex::Regex;
 use tokio::{fs::{self, OpenOptions}, io::{stdin, AsyncReadExt, AsyncWriteExt}};
-use yazi_config::{keymap::Exec, popup::InputOpt, OPEN, PREVIEW};
-use yazi_shared::{max_common_root, Defer, Term, Url};
+use tracing::error;
+use yazi_config::{keymap::Exec, manager::Sanitize, popup::{ConfirmOpt, InputOpt}, MANAGER, OPEN, PREVIEW};
+use yazi_shared::{expand_vars, fs_type, max_common_root, sanitize_name, Defer, Term, Url};
 
-use crate::{emit, external::{self, ShellOpt}, files::{File, FilesOp}, manager::Manager, Event, BLOCKER};
+use crate::{emit, external::{self, ShellOpt}, files::{File, FilesOp}, input::Input, manager::Manager, Event, BLOCKER};
 
 pub struct Opt {
-	force: bool,
+	force:  bool,
+	regex:  bool,
+	cursor: OptCursor,
+}
+
+// Where the cursor starts in the prefilled name
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum OptCursor {
+	Start,
+	BeforeExt,
+	End,
+	Stem,
+	All,
 }
 
 impl From<&Exec> for Opt {
-	fn from(e: &Exec) -> Self { Self { force: e.named.contains_key("force") } }
+	fn from(e: &Exec) -> Self {
+		Self {
+			force:  e.named.contains_key("force"),
+			regex:  e.named.contains_key("regex"),
+			cursor: match e.named.get("cursor").map(|s| s.as_str()) {
+				Some("start") => OptCursor::Start,
+				Some("before_ext") => OptCursor::BeforeExt,
+				Some("stem") => OptCursor::Stem,
+				Some("all") => OptCursor::All,
+				_ => OptCursor::End,
+			},
+		}
+	}
+}
+
+impl OptCursor {
+	// The range in chars of the name, the extension is left out of directories
+	// and dotfiles like `.bashrc`
+	fn range(self, name: &str, is_dir: bool) -> Range<usize> {
+		let len = name.chars().count();
+		let stem = match Path::new(name).extension() {
+			Some(ext) if !is_dir => len - ext.to_string_lossy().chars().count() - 1,
+			_ => len,
+		};
+
+		match self {
+			Self::Start => 0..0,
+			Self::BeforeExt => stem..stem,
+			Self::End => len..len,
+			Self::Stem => 0..stem,
+			Self::All => 0..len,
+		}
+	}
 }
 
 impl Manager {
@@ -31,36 +77,80 @@ impl Manager {
 		Ok(())
 	}
 
+	// Check the name against the filesystem of `dir`, which it's going to be in
+	pub(super) async fn portable_name(dir: &Path, name: String) -> String {
+		let dir = dir.to_owned();
+		let fs = tokio::task::spawn_blocking(move || fs_type(&dir)).await.ok().flatten();
+
+		let mode = MANAGER.sanitize_for(fs.as_deref());
+		let new = if mode == Sanitize::Off { return name } else { sanitize_name(&name) };
+		if new == name {
+			return name;
+		}
+
+		match mode {
+			Sanitize::Off => name,
+			Sanitize::Warn => {
+				let fs = fs.as_deref().unwrap_or("other platforms");
+				match emit!(Input(InputOpt::sanitize(fs, &new))).recv().await {
+					Some(Ok(c)) if c == "y" || c == "Y" => new,
+					_ => name,
+				}
+			}
+			Sanitize::Replace => new,
+		}
+	}
+
+	// Like `portable_name`, but for a path of the bulk rename under `root`, where
+	// the list to confirm says which names were sanitized, rather than a prompt
+	async fn portable_path(root: &Path, path: PathBuf) -> (PathBuf, bool) {
+		let Some(name) = path.file_name().map(|n| n.to_string_lossy().into_owned()) else {
+			return (path, false);
+		};
+
+		let dir = root.join(path.parent().unwrap_or(Path::new("")));
+		let fs = tokio::task::spawn_blocking(move || fs_type(&dir)).await.ok().flatten();
+		if MANAGER.sanitize_for(fs.as_deref()) == Sanitize::Off {
+			return (path, false);
+		}
+
+		let new = sanitize_name(&name);
+		if new == name { (path, false) } else { (path.with_file_name(new), true) }
+	}
+
 	pub fn rename(&self, opt: impl Into<Opt>) -> bool {
+		let opt = opt.into() as Opt;
+		if opt.regex {
+			return self.regex_rename();
+		}
 		if self.active().in_selecting() {
 			return self.bulk_rename();
 		}
 
-		let Some(hovered) = self.hovered().map(|h| h.url()) else {
+		let Some(hovered) = self.hovered() else {
 			return false;
 		};
 
-		let opt = opt.into() as Opt;
+		let name = hovered.url.file_name().unwrap().to_string_lossy().into_owned();
+		let cursor = opt.cursor.range(&name, hovered.is_dir());
+		let hovered = hovered.url();
 		tokio::spawn(async move {
-			let mut result =
-				emit!(Input(InputOpt::rename().with_value(hovered.file_name().unwrap().to_string_lossy())));
+			let mut result = emit!(Input(InputOpt::rename().with_value(name).with_cursor(cursor)));
 
-			let Some(Ok(name)) = result.recv().await else {
+			let Some(name) = Input::_recv(&mut result).await else {
 				return;
 			};
 
-			let new = hovered.parent().unwrap().join(name);
+			let parent = hovered.parent().unwrap();
+			let new = parent.join(expand_vars(Self::portable_name(parent, name).await));
 			if opt.force || fs::symlink_metadata(&new).await.is_err() {
 				Self::rename_and_hover(hovered, Url::from(new)).await.ok();
 				return;
 			}
 
-			let mut result = emit!(Input(InputOpt::overwrite()));
-			if let Some(Ok(choice)) = result.recv().await {
-				if choice == "y" || choice == "Y" {
-					Self::rename_and_hover(hovered, Url::from(new)).await.ok();
-				}
-			};
+			if emit!(Confirm(ConfirmOpt::overwrite(&new))).await {
+				Self::rename_and_hover(hovered, Url::from(new)).await.ok();
+			}
 		});
 		false
 	}
@@ -100,7 +190,9 @@ impl Manager {
 
 			let mut child = external::shell(ShellOpt {
 				cmd:    (*opener.exec).into(),
+				arg0:   Default::default(),
 				args:   vec![tmp.to_owned().into()],
+				env:    Default::default(),
 				piped:  false,
 				orphan: false,
 			})?;
@@ -113,6 +205,111 @@ impl Manager {
 		false
 	}
 
+	fn regex_rename(&self) -> bool {
+		let old: Vec<_> = self.selected().into_iter().map(|f| f.url()).collect();
+		if old.is_empty() {
+			return false;
+		}
+
+		let cwd = self.cwd().clone();
+		tokio::spawn(async move {
+			// The pattern is asked again while it's invalid or matches nothing, with
+			// the reason as the title
+			let names: Vec<_> =
+				old.iter().map(|o| o.file_name().unwrap().to_string_lossy().into_owned()).collect();
+			let mut opt = InputOpt::replace(true);
+			let re = loop {
+				let Some(Ok(s)) = emit!(Input(opt)).recv().await else {
+					return;
+				};
+				let title = match Regex::new(&s) {
+					Ok(re) if names.iter().any(|n| re.is_match(n)) => break re,
+					Ok(_) => "No name matches, try another pattern:".to_owned(),
+					Err(e) => format!("Invalid regex, {}:", Self::regex_error(&e)),
+				};
+				opt = InputOpt::replace(true).with_title(title).with_value(s);
+			};
+
+			let Some(Ok(replacement)) = emit!(Input(InputOpt::replace(false))).recv().await else {
+				return;
+			};
+
+			// A new name conflicts if it's invalid, the same as one of those before it,
+			// or taken by a file that isn't renamed away as well
+			let (mut todo, mut conflicts) = (vec![], vec![]);
+			let mut seen = HashSet::new();
+			for (o, name) in old.into_iter().zip(names) {
+				let new_name = re.replace_all(&name, replacement.as_str());
+				if new_name == name {
+					continue;
+				}
+
+				// Those outside of the current directory are shown with their path
+				let line = if o.parent() == Some(&cwd) {
+					format!("{name} → {new_name}")
+				} else {
+					format!("{} → {new_name}", o.display())
+				};
+				let new = o.parent().unwrap().join(new_name.as_ref());
+				if new_name.is_empty() || new_name.contains(MAIN_SEPARATOR) || !seen.insert(new.clone()) {
+					conflicts.push(line);
+				} else {
+					let taken = fs::symlink_metadata(&new).await.is_ok();
+					todo.push((o, new, line, taken));
+				}
+			}
+
+			// A file that can't be renamed stays, and takes its name from whatever
+			// counted on it being freed, until nothing else changes
+			loop {
+				let moving: HashSet<_> = todo.iter().map(|(o, ..)| o.to_path_buf()).collect();
+				let (free, stuck): (Vec<_>, Vec<_>) =
+					todo.into_iter().partition(|(_, n, _, taken)| !taken || moving.contains(n));
+				todo = free;
+				if stuck.is_empty() {
+					break;
+				}
+				conflicts.extend(stuck.into_iter().map(|(.., l, _)| l));
+			}
+
+			// Nothing to rename is still shown, with every line marked
+			let marked = (0..conflicts.len()).collect();
+			let content = conflicts.into_iter().chain(todo.iter().map(|(.., l, _)| l.clone())).collect();
+			if !emit!(Confirm(ConfirmOpt::replace(content, marked))).await {
+				return;
+			}
+
+			// Moved aside to temporary names first, so swaps and chains such as
+			// a → b, b → a don't run into each other
+			let mut moved = vec![];
+			for (i, (o, n, ..)) in todo.into_iter().enumerate() {
+				let tmp = o.with_file_name(format!(".yazi-rename-{}-{i}", std::process::id()));
+				match fs::rename(&o, &tmp).await {
+					Ok(()) => moved.push((o, tmp, n)),
+					Err(e) => error!("failed to rename {o:?} to {n:?}: {e}"),
+				}
+			}
+			for (o, tmp, n) in moved {
+				let result = match fs::symlink_metadata(&n).await {
+					Ok(_) => Err(anyhow!("the name is taken")),
+					Err(_) => fs::rename(&tmp, &n).await.map_err(Into::into),
+				};
+				if let Err(e) = result {
+					error!("failed to rename {o:?} to {n:?}: {e}");
+					fs::rename(&tmp, &o).await.ok();
+				}
+			}
+		});
+		false
+	}
+
+	// The last line of a regex error, which is the reason, without its pointer
+	// to the pattern
+	fn regex_error(e: &regex::Error) -> String {
+		let s = e.to_string();
+		s.lines().last().unwrap_or_default().trim_start_matches("error: ").to_owned()
+	}
+
 	async fn bulk_rename_do(root: PathBuf, old: Vec<PathBuf>, new: Vec<PathBuf>) -> Result<()> {
 		Term::clear(&mut stdout())?;
 		if old.len() != new.len() {
@@ -121,7 +318,18 @@ impl Manager {
 			return Ok(());
 		}
 
-		let todo: Vec<_> = old.into_iter().zip(new).filter(|(o, n)| o != n).collect();
+		let mut todo = Vec::with_capacity(old.len());
+		let mut sanitized = HashSet::new();
+		for (o, n) in old.into_iter().zip(new) {
+			let (n, changed) = Self::portable_path(&root, n).await;
+			if o == n {
+				continue;
+			}
+			if changed {
+				sanitized.insert(n.clone());
+			}
+			todo.push((o, n));
+		}
 		if todo.is_empty() {
 			return Ok(());
 		}
@@ -129,7 +337,8 @@ impl Manager {
 		{
 			let mut stdout = BufWriter::new(stdout().lock());
 			for (o, n) in &todo {
-				writeln!(stdout, "{} -> {}", o.display(), n.display())?;
+				let note = if sanitized.contains(n) { " (sanitized)" } else { "" };
+				writeln!(stdout, "{} -> {}{note}", o.display(), n.display())?;
 			}
 			write!(stdout, "Continue to rename? (y/N): ")?;
 			stdout.flush()?;
@@ -0,0 +1,41 @@
+use yazi_config::{keymap::Exec, MANAGER};
+use yazi_shared::Term;
+
+use crate::{emit, manager::Manager};
+
+pub struct Opt {
+	column: String,
+	step:   isize,
+	reset:  bool,
+}
+
+impl From<&Exec> for Opt {
+	fn from(e: &Exec) -> Self {
+		Self {
+			column: e.args.first().cloned().unwrap_or_default(),
+			step:   e.args.get(1).and_then(|s| s.parse().ok()).unwrap_or(0),
+			reset:  e.named.contains_key("reset"),
+		}
+	}
+}
+
+impl Manager {
+	pub fn resize(&mut self, opt: impl Into<Opt>) -> bool {
+		let opt = opt.into() as Opt;
+		let b = if opt.reset {
+			MANAGER.layout.reset()
+		} else {
+			match opt.column.as_str() {
+				"parent" => MANAGER.layout.resize_parent(opt.step),
+				"preview" => MANAGER.layout.resize_preview(opt.step),
+				_ => false,
+			}
+		};
+
+		if b {
+			let size = Term::size();
+			emit!(Resize(size.columns, size.rows));
+		}
+		false
+	}
+}
@@ -0,0 +1,76 @@
+use std::path::Path;
+
+use tokio::fs;
+use yazi_config::{keymap::Exec, popup::{ConfirmOpt, InputOpt}};
+use yazi_shared::expand_path;
+
+use crate::{emit, manager::Manager, tasks::Tasks};
+
+pub struct Opt {
+	action:   String,
+	nul:      bool,
+	relative: bool,
+	pipe:     bool,
+}
+
+impl From<&Exec> for Opt {
+	fn from(e: &Exec) -> Self {
+		Self {
+			action:   e.args.first().cloned().unwrap_or_default(),
+			nul:      e.named.contains_key("nul"),
+			relative: e.named.contains_key("relative"),
+			pipe:     e.named.contains_key("pipe"),
+		}
+	}
+}
+
+impl Manager {
+	pub fn selection(&self, opt: impl Into<Opt>, tasks: &Tasks) -> bool {
+		let opt = opt.into() as Opt;
+		if opt.action != "export" {
+			return false;
+		}
+
+		let cwd = self.cwd().clone();
+		let mut buf = Vec::new();
+		for f in self.selected() {
+			let path = if opt.relative { f.url.strip_prefix(&cwd).unwrap_or(&f.url) } else { &f.url };
+			buf.extend_from_slice(&Self::path_bytes(path));
+			buf.push(if opt.nul { b'\0' } else { b'\n' });
+		}
+		if buf.is_empty() {
+			return false;
+		}
+
+		if opt.pipe {
+			return tasks.process_pipe(buf);
+		}
+
+		tokio::spawn(async move {
+			let mut result = emit!(Input(InputOpt::export(false)));
+			let Some(Ok(name)) = result.recv().await else {
+				return Ok(());
+			};
+
+			let path = cwd.join(expand_path(name.trim()));
+			if fs::symlink_metadata(&path).await.is_ok()
+				&& !emit!(Confirm(ConfirmOpt::overwrite(&path))).await
+			{
+				return Ok(());
+			}
+			fs::write(path, buf).await
+		});
+		false
+	}
+
+	#[cfg(unix)]
+	#[inline]
+	fn path_bytes(path: &Path) -> Vec<u8> {
+		use std::os::unix::ffi::OsStrExt;
+		path.as_os_str().as_bytes().to_vec()
+	}
+
+	#[cfg(windows)]
+	#[inline]
+	fn path_bytes(path: &Path) -> Vec<u8> { path.to_string_lossy().as_bytes().to_vec() }
+}
@@ -0,0 +1,35 @@
+use std::env;
+
+use tokio::process::Command;
+use tracing::error;
+use yazi_config::keymap::Exec;
+
+use crate::{emit, manager::Manager, BLOCKER};
+
+pub struct Opt;
+impl From<&Exec> for Opt {
+	fn from(_: &Exec) -> Self { Self }
+}
+
+impl Manager {
+	pub fn subshell(&mut self, _: impl Into<Opt>) -> bool {
+		let cwd = self.cwd().clone();
+		tokio::spawn(async move {
+			let _guard = BLOCKER.acquire().await.unwrap();
+			emit!(Stop(true)).await;
+
+			let shell = if cfg!(windows) {
+				env::var_os("COMSPEC").unwrap_or_else(|| "cmd".into())
+			} else {
+				env::var_os("SHELL").filter(|s| !s.is_empty()).unwrap_or_else(|| "sh".into())
+			};
+
+			match Command::new(&shell).current_dir(&cwd).kill_on_drop(true).spawn() {
+				Ok(mut child) => _ = child.wait().await,
+				Err(e) => error!("Failed to spawn the shell {shell:?}: {e}"),
+			}
+			emit!(Stop(false)).await;
+		});
+		false
+	}
+}
@@ -1,4 +1,4 @@
-use yazi_config::keymap::Exec;
+use yazi_config::{keymap::Exec, manager::YankScope, MANAGER};
 
 use crate::manager::Tabs;
 
@@ -25,10 +25,23 @@ impl Tabs {
 			return false;
 		}
 
-		self.items.remove(opt.idx);
+		// Closing the tab in the other pane leaves the dual-pane layout
+		let other = match self.other.take() {
+			Some(o) if o == opt.idx => None,
+			Some(o) if o > opt.idx => Some(o - 1),
+			o => o,
+		};
+
+		let tab = self.items.remove(opt.idx);
 		if opt.idx <= self.idx {
 			self.set_idx(self.absolute(1));
 		}
+		self.other = other.filter(|&o| o != self.idx);
+
+		// Keep the global yanked files around after the tab holding them is closed
+		if MANAGER.yank_scope != YankScope::Tab && !tab.yanked.1.is_empty() {
+			self.active_mut().yanked = tab.yanked;
+		}
 
 		true
 	}
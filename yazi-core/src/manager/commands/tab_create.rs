@@ -1,20 +1,23 @@
 use yazi_config::keymap::Exec;
 use yazi_shared::Url;
 
-use crate::{manager::Tabs, tab::Tab};
-
-const MAX_TABS: usize = 9;
+use crate::{manager::{Tabs, MAX_TABS}, tab::Tab};
 
 pub struct Opt {
 	url:     Option<Url>,
 	current: bool,
+	hovered: bool,
 }
 
 impl From<&Exec> for Opt {
 	fn from(e: &Exec) -> Self {
-		let mut opt = Self { url: None, current: e.named.contains_key("current") };
+		let mut opt = Self {
+			url:     None,
+			current: e.named.contains_key("current"),
+			hovered: e.named.contains_key("hovered"),
+		};
 
-		if !opt.current {
+		if !opt.current && !opt.hovered {
 			opt.url = Some(e.args.first().map_or_else(|| Url::from("."), Url::from));
 		}
 		opt
@@ -28,12 +31,25 @@ impl Tabs {
 		}
 
 		let opt = opt.into() as Opt;
-		let url = if opt.current { self.active().current.cwd.to_owned() } else { opt.url.unwrap() };
+		let url = if opt.hovered {
+			// The hovered directory, or where the hovered file is otherwise
+			let current = &self.active().current;
+			current.hovered().filter(|h| h.is_dir()).map_or(&current.cwd, |h| &h.url).to_owned()
+		} else if opt.current {
+			self.active().current.cwd.to_owned()
+		} else {
+			opt.url.unwrap()
+		};
 
 		let mut tab = Tab::from(url);
 		tab.conf = self.active().conf.clone();
+		tab.usage = self.active().usage.clone();
 		tab.apply_files_attrs(false);
 
+		if let Some(o) = self.other.as_mut().filter(|o| **o > self.idx) {
+			*o += 1;
+		}
+
 		self.items.insert(self.idx + 1, tab);
 		self.set_idx(self.idx + 1);
 		true
@@ -0,0 +1,31 @@
+use yazi_config::keymap::Exec;
+
+use crate::{emit, manager::{Manager, Tabs}, tab::Tab};
+
+pub struct Opt;
+impl From<&Exec> for Opt {
+	fn from(_: &Exec) -> Self { Self }
+}
+
+impl Tabs {
+	pub fn dual(&mut self, _: impl Into<Opt>) -> bool {
+		if self.other.take().is_some() {
+			emit!(Peek);
+			return true;
+		}
+
+		// Another tab in the same directory, when there's only the one
+		if self.items.len() < 2 {
+			let mut tab = Tab::from(self.active().current.cwd.clone());
+			tab.conf = self.active().conf.clone();
+			tab.usage = self.active().usage.clone();
+			tab.apply_files_attrs(false);
+			self.items.push(tab);
+		}
+
+		self.other = Some(if self.idx + 1 < self.items.len() { self.idx + 1 } else { self.idx - 1 });
+		self.active_mut().preview.reset(|_| true);
+		Manager::_refresh();
+		true
+	}
+}
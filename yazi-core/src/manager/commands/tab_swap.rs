@@ -20,6 +20,9 @@ impl Tabs {
 		}
 
 		self.items.swap(self.idx, idx);
+		if self.other == Some(idx) {
+			self.other = Some(self.idx);
+		}
 		self.set_idx(idx);
 		true
 	}
@@ -1,17 +1,21 @@
-use yazi_config::keymap::Exec;
+use yazi_config::{keymap::{Exec, KeymapLayer}, popup::SelectOpt};
 
-use crate::manager::Tabs;
+use crate::{emit, manager::Tabs};
 
 pub struct Opt {
-	step:     isize,
-	relative: bool,
+	step:        isize,
+	relative:    bool,
+	interactive: bool,
+	other:       bool,
 }
 
 impl From<&Exec> for Opt {
 	fn from(e: &Exec) -> Self {
 		Self {
-			step:     e.args.first().and_then(|s| s.parse().ok()).unwrap_or(0),
-			relative: e.named.contains_key("relative"),
+			step:        e.args.first().and_then(|s| s.parse().ok()).unwrap_or(0),
+			relative:    e.named.contains_key("relative"),
+			interactive: e.named.contains_key("interactive"),
+			other:       e.named.contains_key("other"),
 		}
 	}
 }
@@ -19,7 +23,16 @@ impl From<&Exec> for Opt {
 impl Tabs {
 	pub fn switch(&mut self, opt: impl Into<Opt>) -> bool {
 		let opt = opt.into() as Opt;
-		let idx = if opt.relative {
+		if opt.interactive {
+			return self.switch_interactive();
+		}
+
+		let idx = if opt.other {
+			let Some(other) = self.other else {
+				return false;
+			};
+			other
+		} else if opt.relative {
 			(self.idx as isize + opt.step).rem_euclid(self.items.len() as isize) as usize
 		} else {
 			opt.step as usize
@@ -32,4 +45,20 @@ impl Tabs {
 		self.set_idx(idx);
 		true
 	}
+
+	fn switch_interactive(&self) -> bool {
+		let items: Vec<_> = self
+			.items
+			.iter()
+			.enumerate()
+			.map(|(i, t)| format!("{}  {}", i + 1, t.current.cwd.to_string_lossy()))
+			.collect();
+
+		tokio::spawn(async move {
+			if let Ok(choice) = emit!(Select(SelectOpt::tab(items))).await {
+				emit!(Call(Exec::call("tab_switch", vec![choice.to_string()]).vec(), KeymapLayer::Manager));
+			}
+		});
+		false
+	}
 }
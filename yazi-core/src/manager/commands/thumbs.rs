@@ -0,0 +1,19 @@
+use yazi_config::keymap::Exec;
+
+use crate::{manager::Manager, tasks::Tasks};
+
+pub struct Opt {
+	import: bool,
+}
+
+impl From<&Exec> for Opt {
+	fn from(e: &Exec) -> Self { Self { import: e.named.contains_key("import") } }
+}
+
+impl Manager {
+	// Export the preview cache of the current directory tree, or `--import` it
+	pub fn thumbs(&mut self, opt: impl Into<Opt>, tasks: &Tasks) -> bool {
+		let opt = opt.into() as Opt;
+		tasks.precache_thumbs(self.cwd(), opt.import)
+	}
+}
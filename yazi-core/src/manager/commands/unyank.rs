@@ -0,0 +1,32 @@
+use yazi_config::{keymap::Exec, manager::YankScope, MANAGER};
+
+use crate::{manager::Manager, Remote};
+
+pub struct Opt {
+	local: bool,
+}
+
+impl From<&Exec> for Opt {
+	fn from(e: &Exec) -> Self { Self { local: e.named.contains_key("local") } }
+}
+
+impl Manager {
+	pub fn unyank(&mut self, opt: impl Into<Opt>) -> bool {
+		let opt = opt.into() as Opt;
+		if self.yanked().1.is_empty() {
+			return false;
+		}
+
+		if MANAGER.yank_scope == YankScope::Tab {
+			self.active_mut().yanked = Default::default();
+		} else {
+			self.tabs.iter_mut().for_each(|t| t.yanked = Default::default());
+		}
+
+		// `--local` is how the other instances pass it on, so it isn't shared again
+		if !opt.local && MANAGER.yank_scope == YankScope::Shared {
+			Remote::broadcast(Exec::call("unyank", vec![]).with_bool("local", true));
+		}
+		true
+	}
+}
@@ -0,0 +1,32 @@
+use yazi_config::{keymap::Exec, manager::SortBy};
+
+use crate::{manager::Manager, tasks::Tasks};
+
+pub struct Opt;
+impl From<&Exec> for Opt {
+	fn from(_: &Exec) -> Self { Self }
+}
+
+impl Manager {
+	pub fn usage(&mut self, _: impl Into<Opt>, tasks: &Tasks) -> bool {
+		let tab = self.active_mut();
+		if let Some(old) = tab.usage.take() {
+			tab.conf.sort_by = old.sort_by;
+			tab.conf.sort_reverse = old.sort_reverse;
+			tab.conf.sort_dir_first = old.sort_dir_first;
+			tab.conf.linemode = old.linemode;
+		} else {
+			tab.usage = Some(tab.conf.clone());
+			tab.conf.sort_by = SortBy::Size;
+			tab.conf.sort_reverse = true;
+			tab.conf.sort_dir_first = false;
+			tab.conf.linemode = "usage".to_owned();
+		}
+
+		// Directory sizes are calculated in the background as long as sorting by size,
+		// including the ones entered later, so the mode follows the navigation
+		tab.apply_files_attrs(false);
+		tasks.precache_size(&self.current().files);
+		true
+	}
+}
@@ -0,0 +1,106 @@
+use std::{ffi::OsString, path::PathBuf};
+
+use anyhow::{bail, Result};
+use tokio::task::spawn_blocking;
+use tracing::error;
+use yazi_config::{keymap::{Exec, KeymapLayer}, popup::{InputOpt, SelectOpt}, SELECT};
+use yazi_shared::{xattr_get, xattr_list, xattr_remove, xattr_set};
+
+use crate::{emit, manager::Manager};
+
+pub struct Opt;
+impl From<&Exec> for Opt {
+	fn from(_: &Exec) -> Self { Self }
+}
+
+impl Manager {
+	fn xattr_all(path: PathBuf) -> Result<Vec<(OsString, Vec<u8>)>> {
+		let mut attrs = Vec::new();
+		for name in xattr_list(&path)? {
+			let value = xattr_get(&path, &name).unwrap_or_default();
+			attrs.push((name, value));
+		}
+		Ok(attrs)
+	}
+
+	// Printable values are shown as they are, and the others in hex
+	fn xattr_display(value: &[u8]) -> (String, bool) {
+		match std::str::from_utf8(value) {
+			Ok(s) if !s.chars().any(char::is_control) => (s.to_owned(), false),
+			_ => (value.iter().fold("0x".to_owned(), |s, b| s + &format!("{b:02x}")), true),
+		}
+	}
+
+	// The hex of a binary value is turned back into bytes, if still valid
+	fn xattr_parse(s: String, hex: bool) -> Vec<u8> {
+		let decoded = s.strip_prefix("0x").filter(|h| hex && h.len() % 2 == 0).and_then(|h| {
+			(0..h.len()).step_by(2).map(|i| u8::from_str_radix(&h[i..i + 2], 16).ok()).collect()
+		});
+		decoded.unwrap_or_else(|| s.into_bytes())
+	}
+
+	pub fn xattr(&self, _: impl Into<Opt>) -> bool {
+		let Some(hovered) = self.hovered().map(|h| h.url()) else {
+			return false;
+		};
+
+		tokio::spawn(async move {
+			if let Err(e) = Self::xattr_do(hovered.to_path_buf()).await {
+				error!("failed to change the attributes of {hovered:?}: {e}");
+			}
+		});
+		false
+	}
+
+	async fn xattr_do(path: PathBuf) -> Result<()> {
+		let p = path.clone();
+		let attrs = spawn_blocking(move || Self::xattr_all(p)).await??;
+
+		let mut items: Vec<_> = attrs
+			.iter()
+			.map(|(k, v)| format!("{} = {}", k.to_string_lossy(), Self::xattr_display(v).0))
+			.collect();
+		items.push(SELECT.xattr_custom.clone());
+
+		let name = path.file_name().map(|n| n.to_string_lossy()).unwrap_or_default();
+		let Ok(choice) = emit!(Select(SelectOpt::xattr(&name, items))).await else {
+			return Ok(());
+		};
+
+		// An existing one is either edited or removed, and an empty value is kept
+		let (attr, value) = if let Some((attr, value)) = attrs.get(choice) {
+			let attr_s = attr.to_string_lossy();
+			match emit!(Select(SelectOpt::xattr_action(&attr_s))).await {
+				Ok(0) => {
+					let (display, hex) = Self::xattr_display(value);
+					let opt = InputOpt::xattr(Some(&attr_s)).with_value(display);
+					let Some(Ok(value)) = emit!(Input(opt)).recv().await else {
+						return Ok(());
+					};
+					(attr.clone(), Some(Self::xattr_parse(value, hex)))
+				}
+				Ok(_) => (attr.clone(), None),
+				Err(_) => return Ok(()),
+			}
+		} else {
+			let Some(Ok(s)) = emit!(Input(InputOpt::xattr(None))).recv().await else {
+				return Ok(());
+			};
+			let Some((attr, value)) = s.split_once('=').filter(|(a, _)| !a.trim().is_empty()) else {
+				bail!("expected an attribute as name=value, got {s:?}");
+			};
+			(OsString::from(attr.trim()), Some(value.as_bytes().to_vec()))
+		};
+
+		let p = path.clone();
+		spawn_blocking(move || match value {
+			Some(value) => xattr_set(&p, &attr, &value),
+			None => xattr_remove(&p, &attr),
+		})
+		.await??;
+
+		// Back to the list, to see the change or make another one
+		emit!(Call(Exec::call("xattr", vec![]).vec(), KeymapLayer::Manager));
+		Ok(())
+	}
+}
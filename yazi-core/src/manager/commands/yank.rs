@@ -1,21 +1,55 @@
-use yazi_config::keymap::Exec;
+use std::collections::HashSet;
 
-use crate::manager::Manager;
+use yazi_config::{keymap::Exec, manager::YankScope, MANAGER};
+use yazi_shared::Url;
+
+use crate::{manager::Manager, EventLog, Remote};
 
 pub struct Opt {
-	cut: bool,
+	cut:    bool,
+	append: bool,
+	urls:   Vec<Url>,
 }
 
 impl From<&Exec> for Opt {
-	fn from(e: &Exec) -> Self { Self { cut: e.named.contains_key("cut") } }
+	fn from(e: &Exec) -> Self {
+		Self {
+			cut:    e.named.contains_key("cut"),
+			append: e.named.contains_key("append"),
+			urls:   e.args.iter().map(Url::from).collect(),
+		}
+	}
 }
 
 impl Manager {
 	pub fn yank(&mut self, opt: impl Into<Opt>) -> bool {
 		let opt = opt.into() as Opt;
 
-		self.yanked.0 = opt.cut;
-		self.yanked.1 = self.selected().into_iter().map(|f| f.url()).collect();
+		// The given files rather than the selected, which is how the other instances
+		// share theirs, so these aren't shared again
+		let shared = opt.urls.is_empty() && MANAGER.yank_scope == YankScope::Shared;
+		let mut yanked: (_, HashSet<_>) = if opt.urls.is_empty() {
+			(opt.cut, self.selected_all().into_iter().map(|f| f.url()).collect())
+		} else {
+			(opt.cut, opt.urls.into_iter().collect())
+		};
+
+		// Appending keeps what's already yanked, and the whole set takes the mode
+		// of this yank
+		if opt.append {
+			yanked.1.extend(self.yanked().1.iter().cloned());
+		}
+		if MANAGER.yank_scope != YankScope::Tab {
+			self.tabs.iter_mut().for_each(|t| t.yanked = Default::default());
+		}
+
+		if shared && !yanked.1.is_empty() {
+			let paths = yanked.1.iter().map(|u| u.to_string_lossy().into_owned()).collect();
+			Remote::broadcast(Exec::call("yank", paths).with_bool("cut", yanked.0));
+		}
+
+		EventLog::yank(yanked.1.iter(), yanked.0);
+		self.active_mut().yanked = yanked;
 		true
 	}
 }
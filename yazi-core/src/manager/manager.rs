@@ -1,23 +1,22 @@
-use std::collections::{BTreeMap, HashMap, HashSet};
+use std::collections::{BTreeMap, HashSet};
 
+use yazi_config::{manager::YankScope, MANAGER};
 use yazi_shared::Url;
 
-use super::{Tabs, Watcher};
+use super::{Mimetype, Tabs, Watcher};
 use crate::{files::{File, FilesOp}, tab::{Folder, Tab}, tasks::Tasks};
 
 pub struct Manager {
-	pub tabs:   Tabs,
-	pub yanked: (bool, HashSet<Url>),
+	pub tabs: Tabs,
 
 	pub(super) watcher: Watcher,
-	pub mimetype:       HashMap<Url, String>,
+	pub mimetype:       Mimetype,
 }
 
 impl Manager {
 	pub fn make() -> Self {
 		Self {
-			tabs:   Tabs::make(),
-			yanked: Default::default(),
+			tabs: Tabs::make(),
 
 			watcher:  Watcher::start(),
 			mimetype: Default::default(),
@@ -29,16 +28,23 @@ impl Manager {
 		let cwd = self.cwd().to_owned();
 		let hovered = self.hovered().map(|h| h.url());
 
-		let mut b = if cwd == url {
+		// The other pane is on screen too, so it can't wait for being switched to
+		let other = self.tabs.other.map(|i| &mut self.tabs.items[i]);
+		let mut b = match other {
+			Some(t) if t.current.cwd == url => t.current.update(op.clone()),
+			_ => false,
+		};
+
+		b |= if cwd == url {
 			self.current_mut().update(op)
 		} else if matches!(self.parent(), Some(p) if p.cwd == url) {
 			self.active_mut().parent.as_mut().unwrap().update(op)
 		} else if matches!(self.hovered(), Some(h) if h.url == url) {
-			self.active_mut().history.entry(url.clone()).or_insert_with(|| Folder::from(&url));
+			self.active_mut().history_touch(&url);
 			self.active_mut().apply_files_attrs(true);
-			self.active_mut().history.get_mut(&url).unwrap().update(op)
+			self.active_mut().history_touch(&url).update(op)
 		} else {
-			self.active_mut().history.entry(url.clone()).or_insert_with(|| Folder::from(&url)).update(op);
+			self.active_mut().history_touch(&url).update(op);
 			false
 		};
 
@@ -55,6 +61,12 @@ impl Manager {
 		let url = op.url();
 		let op = FilesOp::Full(url.clone(), Vec::new());
 
+		// Nothing cached for the paths that are gone is of use anymore
+		for tab in self.tabs.iter_mut() {
+			tab.history_forget(url);
+		}
+		self.mimetype.remove(url);
+
 		if url == self.cwd() {
 			self.current_mut().update(op);
 			self.active_mut().leave(());
@@ -66,6 +78,15 @@ impl Manager {
 		}
 	}
 
+	// Whether the current directory may not reflect the changes made to it,
+	// as it's not being watched
+	#[inline]
+	pub fn stale(&self) -> bool { self.watcher.is_stale(self.cwd()) }
+
+	// Whether the system has run out of file watches at some point
+	#[inline]
+	pub fn watch_exhausted(&self) -> bool { self.watcher.is_exhausted() }
+
 	pub fn update_mimetype(&mut self, mut mimes: BTreeMap<Url, String>, tasks: &Tasks) -> bool {
 		mimes.retain(|f, m| self.mimetype.get(f) != Some(m));
 		if mimes.is_empty() {
@@ -105,4 +126,21 @@ impl Manager {
 
 	#[inline]
 	pub fn selected(&self) -> Vec<&File> { self.tabs.active().selected() }
+
+	#[inline]
+	pub fn selected_all(&self) -> Vec<&File> { self.tabs.active().selected_all() }
+
+	// In the global scope, they're held by the tab that yanked them last
+	#[inline]
+	pub fn yanked(&self) -> &(bool, HashSet<Url>) {
+		match MANAGER.yank_scope {
+			YankScope::Global | YankScope::Shared => self
+				.tabs
+				.iter()
+				.map(|t| &t.yanked)
+				.find(|y| !y.1.is_empty())
+				.unwrap_or(&self.active().yanked),
+			YankScope::Tab => &self.active().yanked,
+		}
+	}
 }
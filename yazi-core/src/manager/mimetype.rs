@@ -0,0 +1,45 @@
+use std::num::NonZeroUsize;
+
+use lru::LruCache;
+use yazi_config::MANAGER;
+use yazi_shared::Url;
+
+// The mimetypes of the files, with the least recently used ones dropped once
+// there're more than `mimetype_max`. Looking one up with `get` doesn't count
+// as a use, as it's done for every visible file on each render, only `touch`
+// does, for the hovered one.
+pub struct Mimetype(LruCache<Url, String>);
+
+impl Default for Mimetype {
+	fn default() -> Self {
+		let cap = NonZeroUsize::new(MANAGER.mimetype_max).unwrap_or(NonZeroUsize::MIN);
+		Self(LruCache::new(cap))
+	}
+}
+
+impl Mimetype {
+	#[inline]
+	pub fn get(&self, url: &Url) -> Option<&String> { self.0.peek(url) }
+
+	#[inline]
+	pub fn touch(&mut self, url: &Url) -> Option<&String> { self.0.get(url) }
+
+	#[inline]
+	pub fn contains(&self, url: &Url) -> bool { self.0.contains(url) }
+
+	pub fn extend(&mut self, mimes: impl IntoIterator<Item = (Url, String)>) {
+		for (url, mime) in mimes {
+			self.0.put(url, mime);
+		}
+	}
+
+	// Including everything under it, for a directory
+	pub fn remove(&mut self, url: &Url) { self.retain(|u| !u.starts_with(url)); }
+
+	pub fn retain(&mut self, f: impl Fn(&Url) -> bool) {
+		let gone: Vec<_> = self.0.iter().filter(|&(u, _)| !f(u)).map(|(u, _)| u.clone()).collect();
+		for url in gone {
+			self.0.pop(&url);
+		}
+	}
+}
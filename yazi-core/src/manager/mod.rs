@@ -1,8 +1,10 @@
 mod commands;
 mod manager;
+mod mimetype;
 mod tabs;
 mod watcher;
 
 pub use manager::*;
+pub use mimetype::*;
 pub use tabs::*;
 pub use watcher::*;
@@ -1,18 +1,39 @@
-use yazi_config::BOOT;
+use yazi_config::{BOOT, MANAGER};
 use yazi_shared::Url;
 
-use crate::{manager::Manager, tab::Tab};
+use crate::{files::{File, FilesOp}, manager::Manager, tab::Tab};
+
+pub(super) const MAX_TABS: usize = 9;
 
 pub struct Tabs {
 	pub idx:          usize,
 	pub(super) items: Vec<Tab>,
+
+	// The tab shown alongside the active one, in the dual-pane layout
+	pub other: Option<usize>,
 }
 
 impl Tabs {
 	pub fn make() -> Self {
-		let mut tabs = Self { idx: usize::MAX, items: vec![Tab::from(Url::from(&BOOT.cwd))] };
-		if let Some(file) = &BOOT.file {
-			tabs.items[0].reveal(Url::from(BOOT.cwd.join(file)));
+		let entries =
+			[(&BOOT.cwd, &BOOT.file)].into_iter().chain(BOOT.tabs.iter().map(|(c, f)| (c, f)));
+
+		let mut tabs = Self { idx: usize::MAX, items: Vec::with_capacity(MAX_TABS), other: None };
+		for (cwd, file) in entries.take(MAX_TABS) {
+			let mut tab = Tab::from(Url::from(cwd));
+			if let Some(file) = file {
+				// Hovered right away, since only the active tab receives the events
+				let url = Url::from(cwd.join(file));
+				tab.current.update(FilesOp::Creating(
+					tab.current.cwd.clone(),
+					File::from_dummy(url.clone()).into_map(),
+				));
+				tab.current.hover(&url);
+				if tabs.items.is_empty() {
+					tab.reveal(url);
+				}
+			}
+			tabs.items.push(tab);
 		}
 
 		tabs.set_idx(0);
@@ -30,12 +51,41 @@ impl Tabs {
 
 	#[inline]
 	pub(super) fn set_idx(&mut self, idx: usize) {
+		// Focusing the other pane swaps the two around
+		if self.other == Some(idx) {
+			self.other = Some(self.idx);
+		}
+
 		self.idx = idx;
 		self.active_mut().preview.reset(|l| l.is_image());
 		Manager::_refresh();
 	}
 }
 
+impl Tabs {
+	// Make the tabs showing the same directory as the active one
+	// follow its hovered and selected files.
+	pub fn sync(&mut self) -> bool {
+		if !MANAGER.sync_tabs || self.items.len() < 2 {
+			return false;
+		}
+
+		let (before, rest) = self.items.split_at_mut(self.idx);
+		let Some((active, after)) = rest.split_first_mut() else {
+			return false;
+		};
+
+		let mut b = false;
+		for tab in before.iter_mut().chain(after).filter(|t| t.current.cwd == active.current.cwd) {
+			b |= tab.current.files.select_as(&active.current.files);
+			if let Some(h) = active.current.hovered() {
+				b |= tab.current.hover(&h.url);
+			}
+		}
+		b
+	}
+}
+
 impl Tabs {
 	#[inline]
 	pub fn len(&self) -> usize { self.items.len() }
@@ -46,6 +96,12 @@ impl Tabs {
 	#[inline]
 	pub fn active(&self) -> &Tab { &self.items[self.idx] }
 
+	#[inline]
+	pub fn other(&self) -> Option<&Tab> { self.other.map(|i| &self.items[i]) }
+
+	#[inline]
+	pub(super) fn iter_mut(&mut self) -> impl Iterator<Item = &mut Tab> { self.items.iter_mut() }
+
 	#[inline]
 	pub(super) fn active_mut(&mut self) -> &mut Tab { &mut self.items[self.idx] }
 }
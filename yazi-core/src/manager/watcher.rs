@@ -1,118 +1,154 @@
-use std::{collections::BTreeSet, sync::Arc, time::Duration};
+use std::{collections::{btree_map::Entry, BTreeMap, BTreeSet}, mem, sync::{atomic::{AtomicBool, Ordering}, Arc}, time::Duration};
 
 use indexmap::IndexMap;
-use notify::{event::{MetadataKind, ModifyKind}, EventKind, RecommendedWatcher, RecursiveMode, Watcher as _Watcher};
-use parking_lot::RwLock;
-use tokio::{fs, pin, sync::mpsc::{self, UnboundedReceiver}};
+use notify::{event::{MetadataKind, ModifyKind}, Config, ErrorKind, EventKind, PollWatcher, RecommendedWatcher, RecursiveMode, Watcher as _Watcher};
+use parking_lot::{Mutex, RwLock};
+use tokio::{fs, pin, sync::mpsc::{self, UnboundedReceiver, UnboundedSender}, time::{timeout, Instant}};
 use tokio_stream::{wrappers::UnboundedReceiverStream, StreamExt};
-use yazi_shared::Url;
+use tracing::warn;
+use yazi_config::MANAGER;
+use yazi_shared::{fs_type, Url};
 
-use crate::{emit, external, files::{File, Files, FilesOp}};
+use crate::{emit, external::{self, GitRepo, GitStatus}, files::{File, Files, FilesOp, FilesStream}};
+
+// The repositories with a `git status` running, and the ones queued up after it
+static GIT_RUNNING: Mutex<BTreeMap<String, BTreeSet<Url>>> = Mutex::new(BTreeMap::new());
+
+// The last statuses of the directories, and the stamps they were got under
+type GitStatuses = IndexMap<Url, (u64, BTreeMap<Url, GitStatus>)>;
+static GIT_STATUSES: Mutex<Option<GitStatuses>> = Mutex::new(None);
 
 pub struct Watcher {
+	tx:      UnboundedSender<Vec<Url>>,
+	watched: Arc<RwLock<IndexMap<Url, Option<Url>>>>,
+
+	// Directories that couldn't be watched, e.g. out of inotify watches,
+	// or on a filesystem that doesn't support it
+	failed:    Arc<RwLock<BTreeSet<Url>>>,
+	exhausted: Arc<AtomicBool>,
+}
+
+// The watches are set up on a blocking thread, away from the UI, since telling
+// the filesystem and the first scan of a polled directory can both hang, e.g.
+// on a dead NFS mount.
+struct Watches {
 	watcher: RecommendedWatcher,
 	watched: Arc<RwLock<IndexMap<Url, Option<Url>>>>,
+
+	// Those on the filesystems in `poll_fs`, which are polled instead
+	poller: PollWatcher,
+	polled: BTreeSet<Url>,
+
+	failed:    Arc<RwLock<BTreeSet<Url>>>,
+	exhausted: Arc<AtomicBool>,
+
+	// How many directories can be watched at most, learned the first time
+	// the system runs out of watches, e.g. a low `max_user_watches`
+	limit: Option<usize>,
 }
 
 impl Watcher {
 	pub(super) fn start() -> Self {
 		let (tx, rx) = mpsc::unbounded_channel();
-		let watcher = RecommendedWatcher::new(
-			{
-				let tx = tx.clone();
-				move |res: Result<notify::Event, notify::Error>| {
-					let Ok(event) = res else {
-						return;
-					};
+		let watcher = RecommendedWatcher::new(Self::handler(tx.clone()), Default::default());
+		let poller = PollWatcher::new(
+			Self::handler(tx),
+			Config::default().with_poll_interval(Duration::from_millis(MANAGER.poll_interval)),
+		);
 
-					let Some(path) = event.paths.first().map(Url::from) else {
-						return;
-					};
+		let mut watches = Watches {
+			watcher:   watcher.unwrap(),
+			watched:   Default::default(),
+			poller:    poller.unwrap(),
+			polled:    Default::default(),
+			failed:    Default::default(),
+			exhausted: Default::default(),
+			limit:     None,
+		};
 
-					let parent = path.parent_url().unwrap_or_else(|| path.clone());
-					match event.kind {
-						EventKind::Create(_) => {
-							tx.send(parent).ok();
-						}
-						EventKind::Modify(kind) => {
-							match kind {
-								ModifyKind::Data(_) => {}
-								ModifyKind::Metadata(kind) => match kind {
-									MetadataKind::Permissions => {}
-									MetadataKind::Ownership => {}
-									MetadataKind::Extended => {}
-									_ => return,
-								},
-								ModifyKind::Name(_) => {}
-								_ => return,
-							};
-
-							tx.send(path).ok();
-							tx.send(parent).ok();
-						}
-						EventKind::Remove(_) => {
-							tx.send(path).ok();
-							tx.send(parent).ok();
-						}
-						_ => (),
-					}
-				}
-			},
-			Default::default(),
-		);
+		let (wanted_tx, mut wanted_rx) = mpsc::unbounded_channel::<Vec<Url>>();
+		let instance = Self {
+			tx:        wanted_tx,
+			watched:   watches.watched.clone(),
+			failed:    watches.failed.clone(),
+			exhausted: watches.exhausted.clone(),
+		};
 
-		let instance = Self { watcher: watcher.unwrap(), watched: Default::default() };
 		tokio::spawn(Self::on_changed(rx, instance.watched.clone()));
+		tokio::task::spawn_blocking(move || {
+			while let Some(mut wanted) = wanted_rx.blocking_recv() {
+				// Only the latest is of interest, if it's been changed again meanwhile
+				while let Ok(newer) = wanted_rx.try_recv() {
+					wanted = newer;
+				}
+				if watches.watch(wanted) {
+					emit!(Render);
+				}
+			}
+		});
 		instance
 	}
 
-	pub(super) fn watch(&mut self, mut watched: BTreeSet<&Url>) {
-		watched.retain(|&u| u.is_regular());
-		let (to_unwatch, to_watch): (BTreeSet<_>, BTreeSet<_>) = {
-			let guard = self.watched.read();
-			let keys = guard.keys().collect::<BTreeSet<_>>();
-			(
-				keys.difference(&watched).map(|&x| x.clone()).collect(),
-				watched.difference(&keys).map(|&x| x.clone()).collect(),
-			)
-		};
+	fn handler(tx: UnboundedSender<Url>) -> impl Fn(Result<notify::Event, notify::Error>) {
+		move |res| {
+			let Ok(event) = res else {
+				return;
+			};
 
-		for u in to_unwatch {
-			self.watcher.unwatch(&u).ok();
-		}
-		for u in to_watch {
-			if self.watcher.watch(&u, RecursiveMode::NonRecursive).is_err() {
-				watched.remove(&u);
+			let Some(path) = event.paths.first().map(Url::from) else {
+				return;
+			};
+
+			// Neither it nor its parent is refreshed for an ignored one
+			if MANAGER.is_watch_ignored(&path) {
+				return;
 			}
-		}
 
-		let mut to_resolve = Vec::new();
-		let mut guard = self.watched.write();
-		*guard = watched
-			.into_iter()
-			.map(|k| {
-				if let Some(old) = guard.remove_entry(k) {
-					old
-				} else {
-					to_resolve.push(k.clone());
-					(k.clone(), None)
+			let parent = path.parent_url().unwrap_or_else(|| path.clone());
+			match event.kind {
+				EventKind::Create(_) => {
+					tx.send(parent).ok();
 				}
-			})
-			.collect();
+				EventKind::Modify(kind) => {
+					match kind {
+						ModifyKind::Data(_) => {}
+						ModifyKind::Metadata(kind) => match kind {
+							MetadataKind::Permissions => {}
+							MetadataKind::Ownership => {}
+							MetadataKind::Extended => {}
+							// What the polling reports in place of `Data`
+							MetadataKind::WriteTime => {}
+							_ => return,
+						},
+						ModifyKind::Name(_) => {}
+						_ => return,
+					};
 
-		let lock = self.watched.clone();
-		tokio::spawn(async move {
-			for k in to_resolve {
-				match fs::canonicalize(&k).await {
-					Ok(v) if v != *k => {
-						lock.write().insert(k, Some(Url::from(v)));
-					}
-					_ => {}
+					tx.send(path).ok();
+					tx.send(parent).ok();
 				}
+				EventKind::Remove(_) => {
+					tx.send(path).ok();
+					tx.send(parent).ok();
+				}
+				_ => (),
 			}
-		});
+		}
+	}
+
+	// `wanted` is in the order of priority, so when the watches run out, the
+	// ones at the end are given up first to make room for those in front.
+	#[inline]
+	pub(super) fn watch(&self, wanted: Vec<&Url>) {
+		self.tx.send(wanted.into_iter().cloned().collect()).ok();
 	}
 
+	#[inline]
+	pub(super) fn is_stale(&self, url: &Url) -> bool { self.failed.read().contains(url) }
+
+	#[inline]
+	pub(super) fn is_exhausted(&self) -> bool { self.exhausted.load(Ordering::Relaxed) }
+
 	pub(super) fn trigger_dirs(&self, dirs: &[&Url]) {
 		let dirs: Vec<_> = dirs.iter().filter(|&u| u.is_regular()).map(|&u| u.clone()).collect();
 		if dirs.is_empty() {
@@ -129,18 +165,28 @@ impl Watcher {
 	}
 
 	async fn on_changed(
-		rx: UnboundedReceiver<Url>,
+		mut rx: UnboundedReceiver<Url>,
 		watched: Arc<RwLock<IndexMap<Url, Option<Url>>>>,
 	) {
-		// TODO: revert this once a new notification is implemented
-		// let rx = UnboundedReceiverStream::new(rx).chunks_timeout(100,
-		// Duration::from_millis(200));
-		let rx = UnboundedReceiverStream::new(rx).chunks_timeout(1, Duration::ZERO);
-		pin!(rx);
+		let debounce = Duration::from_millis(MANAGER.watch_debounce);
+		while let Some(first) = rx.recv().await {
+			let mut urls = BTreeSet::from([first]);
+
+			// Until nothing's changed for `debounce`, but no longer than 10 times that,
+			// so the ones changing all the time still get refreshed
+			if !debounce.is_zero() {
+				let deadline = Instant::now() + debounce * 10;
+				loop {
+					let wait = debounce.min(deadline.saturating_duration_since(Instant::now()));
+					match timeout(wait, rx.recv()).await {
+						Ok(Some(url)) => _ = urls.insert(url),
+						_ => break,
+					}
+				}
+			}
 
-		while let Some(urls) = rx.next().await {
 			let (mut files, mut dirs): (Vec<_>, Vec<_>) = Default::default();
-			for url in urls.into_iter().collect::<BTreeSet<_>>() {
+			for url in urls {
 				if fs::metadata(&url).await.map(|m| !m.is_dir()).unwrap_or(false) {
 					files.push(url);
 				} else {
@@ -204,12 +250,83 @@ impl Watcher {
 			new
 		};
 
-		let files: Vec<_> = UnboundedReceiverStream::new(rx).collect().await;
-		for u in linked {
-			let files = linked_files(&files, u);
-			emit!(Files(FilesOp::Full(u.clone(), files)));
+		let rx = UnboundedReceiverStream::new(rx).chunks_timeout(10000, Duration::from_millis(500));
+		pin!(rx);
+
+		// All at once if it's been read by the first chunk, otherwise part by part,
+		// so the first screenful of a huge directory shows up without the rest
+		let first = rx.next().await.unwrap_or_default();
+		let Some(second) = rx.next().await else {
+			for u in linked {
+				emit!(Files(FilesOp::Full(u.clone(), linked_files(&first, u))));
+			}
+			emit!(Files(FilesOp::Full(url.clone(), first)));
+			return Self::git_changed(url).await;
+		};
+
+		let streams: Vec<_> = linked.iter().map(|&u| FilesStream::new(u)).collect();
+		let stream = FilesStream::new(url);
+		let part = |chunk: Vec<File>| {
+			for s in &streams {
+				s.part(linked_files(&chunk, &s.url));
+			}
+			stream.part(chunk);
+		};
+
+		part(first);
+		part(second);
+		while let Some(chunk) = rx.next().await {
+			part(chunk);
+		}
+
+		streams.into_iter().for_each(FilesStream::done);
+		stream.done();
+		Self::git_changed(url).await;
+	}
+
+	async fn git_changed(url: &Url) {
+		let Ok(repo) = GitRepo::new(url).await else {
+			emit!(Files(FilesOp::Git(url.clone(), Default::default())));
+			return;
+		};
+
+		// A single `git status` at a time per repository, where the directories
+		// changed in the meantime are queued up, to run once each after it
+		match GIT_RUNNING.lock().entry(repo.root.clone()) {
+			Entry::Occupied(mut e) => {
+				e.get_mut().insert(url.clone());
+				return;
+			}
+			Entry::Vacant(e) => _ = e.insert(Default::default()),
+		}
+
+		let mut next = Some(url.clone());
+		while let Some(url) = next {
+			let stamp = repo.stamp(&url).await;
+			let cached = GIT_STATUSES.lock().as_ref().and_then(|m| m.get(&url)).cloned();
+
+			let statuses = match cached {
+				Some((s, statuses)) if s == stamp => statuses,
+				_ => {
+					let statuses = external::git_status(&url, &repo).await.unwrap_or_default();
+					let mut cache = GIT_STATUSES.lock();
+					let cache = cache.get_or_insert_with(Default::default);
+					cache.shift_remove(&url);
+					cache.insert(url.clone(), (stamp, statuses.clone()));
+					if cache.len() > MANAGER.history_max {
+						cache.shift_remove_index(0);
+					}
+					statuses
+				}
+			};
+			emit!(Files(FilesOp::Git(url, statuses)));
+
+			let mut running = GIT_RUNNING.lock();
+			next = running.get_mut(&repo.root).and_then(|q| q.pop_first());
+			if next.is_none() {
+				running.remove(&repo.root);
+			}
 		}
-		emit!(Files(FilesOp::Full(url.clone(), files)));
 	}
 
 	fn linked_urls<'a>(url: &'a Url, watched: &'a IndexMap<Url, Option<Url>>) -> Vec<&'a Url> {
@@ -221,3 +338,121 @@ impl Watcher {
 			.collect()
 	}
 }
+
+impl Watches {
+	// Whether the directories that couldn't be watched have changed
+	fn watch(&mut self, wanted: Vec<Url>) -> bool {
+		let mut seen = BTreeSet::new();
+		let wanted: Vec<_> = wanted.iter().filter(|&u| u.is_regular() && seen.insert(u)).collect();
+
+		let mut active: BTreeSet<_> = {
+			let guard = self.watched.read();
+			for u in guard.keys().filter(|u| !seen.contains(u)) {
+				if self.polled.remove(u) {
+					self.poller.unwatch(u).ok();
+				} else {
+					self.watcher.unwatch(u).ok();
+				}
+			}
+			guard.keys().filter(|u| seen.contains(u)).cloned().collect()
+		};
+
+		let old = mem::take(&mut *self.failed.write());
+		let mut i = 0;
+		while i < wanted.len() {
+			let u = wanted[i];
+			if active.contains(u) {
+				i += 1;
+				continue;
+			}
+
+			// The polled ones don't take up any of the watches
+			if Self::should_poll(u) {
+				match self.poller.watch(u, RecursiveMode::NonRecursive) {
+					Ok(_) => {
+						self.polled.insert(u.clone());
+						active.insert(u.clone());
+					}
+					Err(_) => _ = self.failed.write().insert(u.clone()),
+				}
+				i += 1;
+				continue;
+			}
+
+			let full = self.limit.is_some_and(|n| active.len() - self.polled.len() >= n);
+			if full && !self.evict(&wanted[i + 1..], &mut active) {
+				self.failed.write().insert(u.clone());
+				i += 1;
+				continue;
+			}
+
+			match self.watcher.watch(u, RecursiveMode::NonRecursive) {
+				Ok(_) => _ = active.insert(u.clone()),
+				Err(e) if matches!(e.kind, ErrorKind::MaxFilesWatch) => {
+					let n = active.len() - self.polled.len();
+					if self.limit.is_none() {
+						warn!("Out of file watches after {n} directories");
+					}
+					self.limit = Some(n);
+					self.exhausted.store(true, Ordering::Relaxed);
+					if self.evict(&wanted[i + 1..], &mut active) {
+						continue;
+					}
+					self.failed.write().insert(u.clone());
+				}
+				Err(_) => _ = self.failed.write().insert(u.clone()),
+			}
+			i += 1;
+		}
+
+		let watched: Vec<_> = wanted.into_iter().filter(|&u| active.contains(u)).collect();
+		let mut to_resolve = Vec::new();
+		let mut guard = self.watched.write();
+		*guard = watched
+			.into_iter()
+			.map(|k| {
+				if let Some(old) = guard.remove_entry(k) {
+					old
+				} else {
+					to_resolve.push(k.clone());
+					(k.clone(), None)
+				}
+			})
+			.collect();
+
+		let lock = self.watched.clone();
+		tokio::spawn(async move {
+			for k in &to_resolve {
+				Watcher::git_changed(k).await;
+			}
+			for k in to_resolve {
+				match fs::canonicalize(&k).await {
+					Ok(v) if v != *k => {
+						lock.write().insert(k, Some(Url::from(v)));
+					}
+					_ => {}
+				}
+			}
+		});
+
+		*self.failed.read() != old
+	}
+
+	// Give up the watch of the least important one in `rest`, if there is any
+	fn evict(&mut self, rest: &[&Url], active: &mut BTreeSet<Url>) -> bool {
+		let Some(&u) = rest.iter().rev().find(|&&u| active.contains(u) && !self.polled.contains(u))
+		else {
+			return false;
+		};
+
+		self.watcher.unwatch(u).ok();
+		active.remove(u);
+		self.failed.write().insert(u.clone());
+		true
+	}
+
+	#[inline]
+	fn should_poll(url: &Url) -> bool {
+		!MANAGER.poll_fs.is_empty() && MANAGER.is_polled(fs_type(url).as_deref())
+	}
+}
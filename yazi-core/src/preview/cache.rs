@@ -0,0 +1,156 @@
+use std::{collections::HashMap, fs::{self, File}, io::{self, Read, Seek, SeekFrom}, path::{Path, PathBuf}, sync::atomic::{AtomicBool, AtomicU64, Ordering}, time::SystemTime};
+
+use md5::{Digest, Md5};
+use parking_lot::Mutex;
+use tokio::fs as tfs;
+use yazi_config::PREVIEW;
+
+const SAMPLE_SIZE: u64 = 65536;
+const HASHES_MAX: usize = 10000;
+
+static TOTAL: AtomicU64 = AtomicU64::new(0);
+static EVICTING: AtomicBool = AtomicBool::new(false);
+
+#[allow(clippy::type_complexity)]
+static HASHES: Mutex<Option<HashMap<PathBuf, ((u64, Option<SystemTime>), String)>>> =
+	Mutex::new(None);
+
+// Rendered thumbnails and pages, kept under `cache_size` by dropping the least
+// recently used ones. A hit bumps the modification time, which is what the
+// eviction goes by.
+pub struct Cache;
+
+impl Cache {
+	pub(crate) fn init() {
+		if PREVIEW.cache_size.is_some() {
+			Self::evict();
+		}
+	}
+
+	// Keyed by a hash of the contents instead of the path, so a renamed or copied
+	// file still hits the cache, while a changed one doesn't keep showing what was
+	// cached for its old contents.
+	pub(crate) async fn path(path: &Path, skip: usize) -> PathBuf {
+		PREVIEW.cache(&Self::hash(path).await, skip)
+	}
+
+	// Remembered along with the size and modification time, so the contents are
+	// only read again once either of them changes.
+	pub(crate) async fn hash(path: &Path) -> String {
+		let Ok(meta) = tfs::metadata(path).await else {
+			return format!("{path:?}");
+		};
+
+		let stamp = (meta.len(), meta.modified().ok());
+		if let Some(hashes) = &*HASHES.lock() {
+			if let Some((s, hash)) = hashes.get(path) {
+				if *s == stamp {
+					return hash.clone();
+				}
+			}
+		}
+
+		let owned = path.to_owned();
+		let Ok(Ok(hash)) = tokio::task::spawn_blocking(move || Self::contents_hash(&owned)).await
+		else {
+			return format!("{path:?}");
+		};
+
+		let mut hashes = HASHES.lock();
+		let hashes = hashes.get_or_insert_with(HashMap::new);
+		if hashes.len() >= HASHES_MAX {
+			hashes.clear();
+		}
+		hashes.insert(path.to_owned(), (stamp, hash.clone()));
+		hash
+	}
+
+	// A hash of the size, and the head and tail of the contents, which is enough
+	// to tell the files apart without reading huge videos all the way through.
+	pub(super) fn contents_hash(path: &Path) -> io::Result<String> {
+		let mut file = File::open(path)?;
+		let len = file.metadata()?.len();
+
+		let mut hasher = Md5::new_with_prefix(len.to_le_bytes());
+		let mut buf = Vec::with_capacity(SAMPLE_SIZE as usize);
+		(&mut file).take(SAMPLE_SIZE).read_to_end(&mut buf)?;
+		hasher.update(&buf);
+
+		if len > SAMPLE_SIZE * 2 {
+			buf.clear();
+			file.seek(SeekFrom::End(-(SAMPLE_SIZE as i64)))?;
+			file.read_to_end(&mut buf)?;
+			hasher.update(&buf);
+		}
+		Ok(format!("{:x}", hasher.finalize()))
+	}
+
+	pub(crate) async fn hit(cache: &Path) -> bool {
+		if tfs::symlink_metadata(cache).await.is_err() {
+			return false;
+		}
+
+		let cache = cache.to_owned();
+		tokio::task::spawn_blocking(move || {
+			File::options().write(true).open(cache).and_then(|f| f.set_modified(SystemTime::now())).ok();
+		});
+		true
+	}
+
+	pub(crate) async fn put(cache: &Path) {
+		let Some(max) = PREVIEW.cache_size else {
+			return;
+		};
+
+		let len = tfs::metadata(cache).await.map_or(0, |m| m.len());
+		if TOTAL.fetch_add(len, Ordering::Relaxed) + len > max {
+			Self::evict();
+		}
+	}
+
+	fn evict() {
+		if EVICTING.swap(true, Ordering::Relaxed) {
+			return;
+		}
+
+		tokio::task::spawn_blocking(|| {
+			let mut entries = Self::entries();
+			let mut total: u64 = entries.iter().map(|(_, len, _)| len).sum();
+
+			// Leave some room, so it's not run again by the very next file
+			let target = PREVIEW.cache_size.map_or(u64::MAX, |n| n / 10 * 9);
+			if total > target {
+				entries.sort_unstable_by_key(|(mtime, ..)| *mtime);
+				for (_, len, path) in entries {
+					if total <= target {
+						break;
+					} else if fs::remove_file(path).is_ok() {
+						total -= len;
+					}
+				}
+			}
+
+			TOTAL.store(total, Ordering::Relaxed);
+			EVICTING.store(false, Ordering::Relaxed);
+		});
+	}
+
+	// Only the files named by `PREVIEW.cache()`, the temporary ones live there too
+	fn entries() -> Vec<(SystemTime, u64, PathBuf)> {
+		let Ok(it) = fs::read_dir(&PREVIEW.cache_dir) else {
+			return vec![];
+		};
+
+		it.flatten()
+			.filter(|e| {
+				let name = e.file_name();
+				let name = name.to_string_lossy();
+				name.len() == 32 && name.bytes().all(|b| b.is_ascii_hexdigit())
+			})
+			.filter_map(|e| {
+				let meta = e.metadata().ok().filter(|m| m.is_file())?;
+				Some((meta.modified().unwrap_or(SystemTime::UNIX_EPOCH), meta.len(), e.path()))
+			})
+			.collect()
+	}
+}
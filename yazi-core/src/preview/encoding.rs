@@ -0,0 +1,132 @@
+use std::{collections::VecDeque, fmt::{self, Display}, io, mem, path::Path};
+
+use chardetng::EncodingDetector;
+use encoding_rs::{Decoder, Encoding, UTF_16BE, UTF_16LE, UTF_8};
+use tokio::{fs::File, io::AsyncReadExt};
+
+const SNIFF_SIZE: usize = 8192;
+const CHUNK_SIZE: usize = 65536;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TextEncoding {
+	inner:    &'static Encoding,
+	pub crlf: bool,
+}
+
+impl TextEncoding {
+	pub async fn detect(path: &Path) -> io::Result<Self> {
+		let mut buf = Vec::with_capacity(SNIFF_SIZE);
+		File::open(path).await?.take(SNIFF_SIZE as u64).read_to_end(&mut buf).await?;
+
+		let inner = if let Some((enc, _)) = Encoding::for_bom(&buf) {
+			enc
+		} else if Self::valid_utf8(&buf) {
+			UTF_8
+		} else {
+			let mut detector = EncodingDetector::new();
+			detector.feed(&buf, buf.len() < SNIFF_SIZE);
+			detector.guess(None, true)
+		};
+
+		let (sample, _) = inner.decode_with_bom_removal(&buf);
+		let crlf = sample.find('\n').is_some_and(|i| sample[..i].ends_with('\r'));
+		Ok(Self { inner, crlf })
+	}
+
+	#[inline]
+	pub fn is_utf8(&self) -> bool { self.inner == UTF_8 }
+
+	// The size of a code unit, which the offsets to start decoding at must be a
+	// multiple of
+	#[inline]
+	pub fn unit(&self) -> u64 {
+		if self.inner == UTF_16LE || self.inner == UTF_16BE {
+			2
+		} else {
+			1
+		}
+	}
+
+	// Decoded before split, as the newline isn't a single `\n` byte for all of
+	// them, e.g. UTF-16. Each line comes with its length in bytes, newline
+	// included, which is why it's fed to the decoder byte by byte.
+	pub fn decode_lines(&self, bytes: &[u8]) -> Vec<(String, usize)> {
+		let mut decoder = self.inner.new_decoder_with_bom_removal();
+		let (mut lines, mut line, mut len) = (vec![], String::new(), 0);
+		for (i, b) in bytes.iter().enumerate() {
+			line.reserve(decoder.max_utf8_buffer_length(1).unwrap_or(4));
+			_ = decoder.decode_to_string(std::slice::from_ref(b), &mut line, i + 1 == bytes.len());
+
+			len += 1;
+			if line.ends_with('\n') {
+				line.pop();
+				if line.ends_with('\r') {
+					line.pop();
+				}
+				lines.push((mem::take(&mut line), mem::take(&mut len)));
+			}
+		}
+
+		if len > 0 {
+			lines.push((line, len));
+		}
+		lines
+	}
+
+	// Lines of the file decoded as they're read, for the ones not in UTF-8
+	#[inline]
+	pub fn lines(&self, file: File) -> DecodedLines {
+		DecodedLines {
+			file,
+			decoder: self.inner.new_decoder_with_bom_removal(),
+			lines: Default::default(),
+			pending: String::new(),
+			eof: false,
+		}
+	}
+
+	// The sniffed buffer may end in the middle of a multi-byte sequence.
+	fn valid_utf8(buf: &[u8]) -> bool {
+		let valid = Encoding::utf8_valid_up_to(buf);
+		valid == buf.len() || (buf.len() == SNIFF_SIZE && buf.len() - valid < 4)
+	}
+}
+
+impl Display for TextEncoding {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{} {}", self.inner.name(), if self.crlf { "CRLF" } else { "LF" })
+	}
+}
+
+pub struct DecodedLines {
+	file:    File,
+	decoder: Decoder,
+	lines:   VecDeque<String>,
+	// What's after the last newline decoded so far
+	pending: String,
+	eof:     bool,
+}
+
+impl DecodedLines {
+	pub async fn next_line(&mut self) -> io::Result<Option<String>> {
+		while self.lines.is_empty() && !self.eof {
+			let mut buf = vec![0; CHUNK_SIZE];
+			let n = self.file.read(&mut buf).await?;
+
+			self.eof = n == 0;
+			self.pending.reserve(self.decoder.max_utf8_buffer_length(n).unwrap_or(n * 3 + 4));
+			_ = self.decoder.decode_to_string(&buf[..n], &mut self.pending, self.eof);
+
+			if let Some(i) = self.pending.rfind('\n') {
+				let rest = self.pending.split_off(i + 1);
+				self.lines.extend(self.pending.lines().map(ToOwned::to_owned));
+				self.pending = rest;
+			}
+		}
+
+		if let Some(line) = self.lines.pop_front() {
+			return Ok(Some(line));
+		}
+		Ok(Some(mem::take(&mut self.pending)).filter(|s| !s.is_empty()))
+	}
+}
@@ -1,5 +1,15 @@
+mod cache;
+mod encoding;
 mod preview;
 mod provider;
+mod sqlite;
+mod table;
+mod thumbs;
 
+pub use cache::*;
+pub use encoding::*;
 pub use preview::*;
 use provider::*;
+use sqlite::*;
+use table::*;
+pub(crate) use thumbs::*;
@@ -1,18 +1,20 @@
-use std::time::Duration;
+use std::{collections::HashMap, future::Future, time::Duration};
 
-use tokio::{pin, task::JoinHandle};
+use tokio::{pin, task::JoinHandle, time::{self, error::Elapsed}};
 use tokio_stream::{wrappers::UnboundedReceiverStream, StreamExt};
 use yazi_adaptor::ADAPTOR;
-use yazi_config::MANAGER;
+use yazi_config::{previewers::PreviewerUse, MANAGER, PREVIEW, PREVIEWERS};
 use yazi_shared::{MimeKind, PeekError, Url, MIME_DIR};
 
-use super::Provider;
-use crate::{emit, files::{Files, FilesOp}, Highlighter};
+use super::{Provider, TextEncoding};
+use crate::{emit, external::{self, VideoMeta}, files::{Files, FilesOp, FilesStream}, Highlighter};
 
 #[derive(Default)]
 pub struct Preview {
 	pub lock: Option<PreviewLock>,
 	skip:     usize,
+	skips:    HashMap<Url, usize>,
+	exif:     ExifView,
 
 	handle: Option<JoinHandle<()>>,
 }
@@ -22,6 +24,17 @@ pub struct PreviewLock {
 	pub mime: String,
 	pub skip: usize,
 	pub data: PreviewData,
+
+	pub encoding: Option<TextEncoding>,
+	pub video:    Option<VideoMeta>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum ExifView {
+	#[default]
+	Hidden,
+	Above,
+	Only,
 }
 
 #[derive(Debug)]
@@ -29,11 +42,13 @@ pub enum PreviewData {
 	Folder,
 	Text(String),
 	Image,
+	Media(String),
+	Slow,
 }
 
 impl Preview {
 	pub fn go(&mut self, url: &Url, mime: &str, show_image: bool) {
-		let kind = MimeKind::new(mime);
+		let kind = Self::kind(url, mime);
 		if !show_image && kind.show_as_image() {
 			return;
 		} else if self.same(url, mime) {
@@ -42,20 +57,13 @@ impl Preview {
 
 		self.reset(|_| true);
 		if !self.same_mime(url, mime) {
-			self.skip = 0;
+			self.skip = self.skips.get(url).copied().unwrap_or(0);
 		}
 
-		let (url, mime, skip) = (url.clone(), mime.to_owned(), self.skip);
+		let (url, mime, skip, exif) = (url.clone(), mime.to_owned(), self.skip, self.exif);
 		self.handle = Some(tokio::spawn(async move {
-			match Provider::auto(kind, &url, skip).await {
-				Ok(data) => {
-					emit!(Preview(PreviewLock { url, mime, skip, data }));
-				}
-				Err(PeekError::Exceed(max)) => {
-					emit!(Peek(max, url));
-				}
-				_ => {}
-			}
+			Self::debounce().await;
+			Self::auto(kind, url, mime, skip, exif).await
 		}));
 	}
 
@@ -76,36 +84,55 @@ impl Preview {
 			mime: MIME_DIR.to_owned(),
 			skip: self.skip,
 			data: PreviewData::Folder,
+
+			encoding: None,
+			video:    None,
 		}));
 
 		if sequent {
 			return;
 		}
 
-		let url = url.clone();
+		let (url, skip) = (url.clone(), self.skip);
 		self.handle = Some(tokio::spawn(async move {
-			let Ok(rx) = Files::from_dir(&url).await else {
-				emit!(Files(FilesOp::IOErr(url)));
-				return;
+			Self::debounce().await;
+			let rx = match Self::timeout(PREVIEW.timeout, Files::from_dir(&url)).await {
+				Ok(Ok(rx)) => rx,
+				Ok(Err(_)) => {
+					emit!(Files(FilesOp::IOErr(url)));
+					return;
+				}
+				Err(_) => {
+					emit!(Preview(PreviewLock::slow(url, MIME_DIR.to_owned(), skip)));
+					return;
+				}
 			};
 
 			if position.is_some() {
-				emit!(Files(FilesOp::Full(url, UnboundedReceiverStream::new(rx).collect().await)));
+				match Self::timeout(PREVIEW.timeout, UnboundedReceiverStream::new(rx).collect()).await {
+					Ok(files) => {
+						emit!(Files(FilesOp::Full(url, files)));
+					}
+					Err(_) => {
+						emit!(Preview(PreviewLock::slow(url, MIME_DIR.to_owned(), skip)));
+					}
+				}
 				return;
 			}
 
 			let rx = UnboundedReceiverStream::new(rx).chunks_timeout(10000, Duration::from_millis(500));
 			pin!(rx);
 
-			let ticket = FilesOp::prepare(&url);
+			let stream = FilesStream::new(&url);
 			while let Some(chunk) = rx.next().await {
-				emit!(Files(FilesOp::Part(url.clone(), ticket, chunk)));
+				stream.part(chunk);
 			}
+			stream.done();
 		}));
 	}
 
 	pub fn sequent(&mut self, url: &Url, mime: &str, show_image: bool) {
-		let kind = MimeKind::new(mime);
+		let kind = Self::kind(url, mime);
 		if !show_image && kind.show_as_image() {
 			return;
 		} else if self.same(url, mime) {
@@ -116,40 +143,82 @@ impl Preview {
 		Highlighter::abort();
 
 		let (url, mime, skip) = (url.clone(), mime.to_owned(), self.skip);
+		self.handle = Some(tokio::spawn(Self::auto(kind, url, mime, skip, self.exif)));
+	}
+
+	pub fn compare(&mut self, url: &Url, mime: &str, pair: [Url; 2], visual: bool) {
+		self.reset(|_| true);
+		self.skip = 0;
+
+		let (url, mime) = (url.clone(), mime.to_owned());
 		self.handle = Some(tokio::spawn(async move {
-			match Provider::auto(kind, &url, skip).await {
-				Ok(data) => {
-					emit!(Preview(PreviewLock { url, mime, skip, data }));
-				}
-				Err(PeekError::Exceed(max)) => {
-					emit!(Peek(max, url));
-				}
-				_ => {}
+			let result = if visual {
+				Self::timeout(PREVIEW.timeout, Provider::compare_visual(&pair[0], &pair[1])).await
+			} else {
+				Self::timeout(PREVIEW.timeout, Provider::compare(&pair[0], &pair[1])).await
+			};
+
+			if let Ok(Ok(data)) = result {
+				emit!(Preview(PreviewLock { url, mime, skip: 0, data, encoding: None, video: None }));
 			}
 		}));
 	}
 
-	pub fn arrow(&mut self, step: isize) -> bool {
-		let Some(kind) = self.lock.as_ref().map(|l| MimeKind::new(&l.mime)) else {
+	// Toggle the EXIF of images, above the image or in place of it
+	pub fn exif(&mut self, only: bool) {
+		let view = if only { ExifView::Only } else { ExifView::Above };
+		self.exif = if self.exif == view { ExifView::Hidden } else { view };
+	}
+
+	pub fn slow(&mut self, url: &Url, mime: &str) {
+		self.reset(|_| true);
+		if !self.same_mime(url, mime) {
+			self.skip = 0;
+		}
+		emit!(Preview(PreviewLock::slow(url.clone(), mime.to_owned(), self.skip)));
+	}
+
+	pub fn arrow(&mut self, step: isize, by_line: bool) -> bool {
+		let Some(lock) = &self.lock else {
 			return false;
 		};
 
-		let old = self.skip;
-		let size = Provider::step_size(kind, step.unsigned_abs());
+		let kind = Self::kind(&lock.url, &lock.mime);
+		let size = if by_line && !kind.show_as_image() {
+			step.unsigned_abs()
+		} else {
+			Provider::step_size(kind, step.unsigned_abs())
+		};
 
+		let old = self.skip;
 		self.skip = if step < 0 { old.saturating_sub(size) } else { old + size };
+		self.remember(lock.url.clone());
 		self.skip != old
 	}
 
 	pub fn arrow_max(&mut self, max: usize) -> bool {
 		if self.skip > max {
 			self.skip = max;
+			if let Some(url) = self.lock.as_ref().map(|l| l.url.clone()) {
+				self.remember(url);
+			}
 			return true;
 		}
 
 		false
 	}
 
+	fn remember(&mut self, url: Url) {
+		if self.skip == 0 {
+			self.skips.remove(&url);
+			return;
+		}
+		if self.skips.len() >= 1000 {
+			self.skips.clear();
+		}
+		self.skips.insert(url, self.skip);
+	}
+
 	pub fn reset<F: FnOnce(&PreviewLock) -> bool>(&mut self, f: F) -> bool {
 		self.handle.take().map(|h| h.abort());
 		Highlighter::abort();
@@ -163,12 +232,89 @@ impl Preview {
 			return false;
 		}
 
-		let b = !lock.is_image();
+		let b = !matches!(lock.data, PreviewData::Image);
 		self.lock = None;
 		b
 	}
 }
 
+impl Preview {
+	// The configured previewer for the file, or the one by its mimetype
+	#[inline]
+	fn kind(url: &Url, mime: &str) -> MimeKind {
+		match PREVIEWERS.matches(url, mime) {
+			Some(PreviewerUse::Builtin(kind)) => *kind,
+			Some(PreviewerUse::Exec(_) | PreviewerUse::Plugin(_)) => MimeKind::Text,
+			None => MimeKind::new(mime),
+		}
+	}
+
+	async fn auto(kind: MimeKind, url: Url, mime: String, skip: usize, exif: ExifView) {
+		let custom = match PREVIEWERS.matches(&url, &mime) {
+			Some(PreviewerUse::Builtin(_)) | None => None,
+			u => u,
+		};
+
+		let encoding = match kind {
+			MimeKind::Text if custom.is_none() => TextEncoding::detect(&url).await.ok(),
+			_ => None,
+		};
+
+		// Probed once, for both the frame and the details under it
+		let video = match kind {
+			MimeKind::Video => external::ffprobe(&url).await.ok(),
+			_ => None,
+		};
+
+		let result = Self::timeout(PREVIEWERS.timeout(&url, &mime), async {
+			match custom {
+				Some(PreviewerUse::Exec(exec)) => {
+					return Provider::command(exec, &url, skip).await.map(PreviewData::Text);
+				}
+				Some(PreviewerUse::Plugin(name)) => {
+					return Provider::plugin(name, &url, &mime, skip).await.map(PreviewData::Text);
+				}
+				_ => {}
+			}
+			match kind {
+				MimeKind::Image if exif != ExifView::Hidden => {
+					Provider::exif(&url, exif == ExifView::Only).await
+				}
+				_ => Provider::auto(kind, &url, skip, encoding, video.as_ref()).await,
+			}
+		});
+
+		match result.await {
+			Ok(Ok(data)) => {
+				emit!(Preview(PreviewLock { url, mime, skip, data, encoding, video }));
+			}
+			Ok(Err(PeekError::Exceed(max))) => {
+				emit!(Peek(max, url));
+			}
+			Ok(Err(_)) => {}
+			Err(_) => {
+				emit!(Preview(PreviewLock::slow(url, mime, skip)));
+			}
+		}
+	}
+
+	// Wait a moment before the actual work, which is aborted along with this
+	// task if the hovered file changes again in the meantime.
+	#[inline]
+	async fn debounce() {
+		if let Some(t) = PREVIEW.debounce {
+			time::sleep(t).await;
+		}
+	}
+
+	async fn timeout<F: Future>(t: Option<Duration>, f: F) -> Result<F::Output, Elapsed> {
+		match t {
+			Some(t) => time::timeout(t, f).await,
+			None => Ok(f.await),
+		}
+	}
+}
+
 impl Preview {
 	#[inline]
 	pub fn same(&self, url: &Url, mime: &str) -> bool {
@@ -197,8 +343,16 @@ impl Preview {
 
 impl PreviewLock {
 	#[inline]
-	pub fn is_image(&self) -> bool { matches!(self.data, PreviewData::Image) }
+	fn slow(url: Url, mime: String, skip: usize) -> Self {
+		Self { url, mime, skip, data: PreviewData::Slow, encoding: None, video: None }
+	}
+
+	#[inline]
+	pub fn is_image(&self) -> bool { matches!(self.data, PreviewData::Image | PreviewData::Media(_)) }
 
 	#[inline]
 	pub fn is_folder(&self) -> bool { matches!(self.data, PreviewData::Folder) }
+
+	#[inline]
+	pub fn is_slow(&self) -> bool { matches!(self.data, PreviewData::Slow) }
 }
@@ -1,12 +1,12 @@
-use std::path::Path;
+use std::{io::{self, SeekFrom}, path::{Path, PathBuf}};
 
-use tokio::fs;
-use yazi_adaptor::ADAPTOR;
+use tokio::{fs::{self, File}, io::{AsyncReadExt, AsyncSeekExt}};
+use yazi_adaptor::{Image, ADAPTOR};
 use yazi_config::{MANAGER, PREVIEW};
-use yazi_shared::{MimeKind, PeekError};
+use yazi_shared::{MimeKind, PeekError, Url};
 
-use super::PreviewData;
-use crate::{external, Highlighter};
+use super::{Cache, PreviewData, Sqlite, Table, TextEncoding};
+use crate::{emit, external::{self, ShellOpt, VideoMeta}, files, Highlighter};
 
 pub(super) struct Provider;
 
@@ -15,16 +15,22 @@ impl Provider {
 		kind: MimeKind,
 		path: &Path,
 		skip: usize,
+		encoding: Option<TextEncoding>,
+		video: Option<&VideoMeta>,
 	) -> Result<PreviewData, PeekError> {
 		match kind {
 			MimeKind::Empty => Err("Empty file".into()),
 			MimeKind::Archive => Provider::archive(path, skip).await.map(PreviewData::Text),
-			MimeKind::Image => Provider::image(path).await,
-			MimeKind::Video => Provider::video(path, skip).await,
+			MimeKind::Image => Provider::image(&Self::precached(path).await).await,
+			MimeKind::Video => Provider::video(path, skip, video).await,
+			MimeKind::Audio => Provider::audio(path).await,
+			MimeKind::Font => Provider::font(path).await,
 			MimeKind::JSON => Provider::json(path, skip).await.map(PreviewData::Text),
 			MimeKind::PDF => Provider::pdf(path, skip).await,
-			MimeKind::Text => Provider::highlight(path, skip).await.map(PreviewData::Text),
-			MimeKind::Others => Err("Unsupported mimetype".into()),
+			MimeKind::SQLite => Sqlite::new(path).render(skip).await.map(PreviewData::Text),
+			MimeKind::Table => Table::new(path).render(skip).await.map(PreviewData::Text),
+			MimeKind::Text => Provider::highlight(path, skip, encoding).await.map(PreviewData::Text),
+			MimeKind::Others => Provider::hexdump(path, skip).await.map(PreviewData::Text),
 		}
 	}
 
@@ -34,31 +40,144 @@ impl Provider {
 			MimeKind::Archive => step * MANAGER.layout.preview_height() / 10,
 			MimeKind::Image => 0,
 			MimeKind::Video => step,
+			MimeKind::Audio => 0,
+			MimeKind::Font => 0,
 			MimeKind::JSON => step * MANAGER.layout.preview_height() / 10,
-			MimeKind::PDF => 1,
+			MimeKind::PDF => (step * PREVIEW.pdf_jump / 10).max(1),
+			MimeKind::SQLite => 1,
+			MimeKind::Table => step * MANAGER.layout.preview_height() / 10,
 			MimeKind::Text => step * MANAGER.layout.preview_height() / 10,
 			MimeKind::Others => step * MANAGER.layout.preview_height() / 10,
 		}
 	}
 
 	pub(super) async fn image(path: &Path) -> Result<PreviewData, PeekError> {
+		if !ADAPTOR.supported() {
+			let (w, h) = Image::dimensions(path).await?;
+			return Ok(PreviewData::Text(format!(
+				"{w}x{h} image\n\nInline images are not supported here"
+			)));
+		}
+
 		ADAPTOR.image_show(path, MANAGER.layout.image_rect()).await?;
 		Ok(PreviewData::Image)
 	}
 
-	pub(super) async fn video(path: &Path, skip: usize) -> Result<PreviewData, PeekError> {
-		let cache = PREVIEW.cache(path, skip);
-		if fs::symlink_metadata(&cache).await.is_err() {
-			external::ffmpegthumbnailer(path, &cache, skip).await?;
+	pub(super) async fn video(
+		path: &Path,
+		skip: usize,
+		meta: Option<&VideoMeta>,
+	) -> Result<PreviewData, PeekError> {
+		let cache = Cache::path(path, skip).await;
+		if !Cache::hit(&cache).await {
+			external::ffmpeg(path, &cache, skip, meta.map(|m| m.duration)).await?;
+			Cache::put(&cache).await;
+		}
+
+		Self::image(&cache).await
+	}
+
+	pub(super) async fn compare_visual(a: &Path, b: &Path) -> Result<PreviewData, PeekError> {
+		let key = format!("{}{}", Cache::hash(a).await, Cache::hash(b).await);
+		let cache = PREVIEW.cache(&key, 0);
+		if !Cache::hit(&cache).await {
+			Image::precache_pair(a, b, cache.clone()).await?;
+			Cache::put(&cache).await;
+		}
+
+		Self::image(&cache).await
+	}
+
+	pub(super) async fn compare(a: &Path, b: &Path) -> Result<PreviewData, PeekError> {
+		let (la, lb) = (fs::metadata(a).await?.len(), fs::metadata(b).await?.len());
+		let same = la == lb && Self::same_contents(a, b).await?;
+
+		let name =
+			|p: &Path| p.file_name().map(|s| s.to_string_lossy().into_owned()).unwrap_or_default();
+		Ok(PreviewData::Text(format!(
+			"{}  {la} bytes\n{}  {lb} bytes\n\n{}",
+			name(a),
+			name(b),
+			if same { "Identical contents" } else { "Contents differ" }
+		)))
+	}
+
+	async fn same_contents(a: &Path, b: &Path) -> io::Result<bool> {
+		let (mut a, mut b) = (File::open(a).await?, File::open(b).await?);
+		let (mut buf_a, mut buf_b) = (vec![0; 65536], vec![0; 65536]);
+		loop {
+			let n = a.read(&mut buf_a).await?;
+			if n == 0 {
+				return Ok(b.read(&mut buf_b[..1]).await? == 0);
+			}
+			b.read_exact(&mut buf_b[..n]).await?;
+			if buf_a[..n] != buf_b[..n] {
+				return Ok(false);
+			}
+		}
+	}
+
+	pub(super) async fn audio(path: &Path) -> Result<PreviewData, PeekError> {
+		let meta = external::ffprobe_audio(path).await?;
+		let text = meta.to_string();
+		if !meta.cover || !ADAPTOR.supported() {
+			return Ok(PreviewData::Text(text));
+		}
+
+		let cache = Cache::path(path, 0).await;
+		if !Cache::hit(&cache).await {
+			external::ffmpeg_cover(path, &cache).await?;
+			Cache::put(&cache).await;
+		}
+
+		Self::media(&cache, text).await
+	}
+
+	pub(super) async fn exif(path: &Path, only: bool) -> Result<PreviewData, PeekError> {
+		let text = external::exiftool(path).await?.to_string();
+		if only || !ADAPTOR.supported() {
+			return Ok(PreviewData::Text(text));
+		}
+
+		Self::media(&Self::precached(path).await, text).await
+	}
+
+	// The downscaled one if it's been precached, or the image itself
+	async fn precached(path: &Path) -> PathBuf {
+		let cache = Cache::path(path, 0).await;
+		if Cache::hit(&cache).await {
+			cache
+		} else {
+			path.to_owned()
+		}
+	}
+
+	// Show the image below the text
+	async fn media(image: &Path, text: String) -> Result<PreviewData, PeekError> {
+		let lines = text.lines().count() as u16 + 1;
+		let mut rect = MANAGER.layout.image_rect();
+		rect.y += lines;
+		rect.height = rect.height.saturating_sub(lines);
+
+		ADAPTOR.image_show(image, rect).await?;
+		Ok(PreviewData::Media(text))
+	}
+
+	pub(super) async fn font(path: &Path) -> Result<PreviewData, PeekError> {
+		let cache = Cache::path(path, 0).await;
+		if !Cache::hit(&cache).await {
+			external::magick_font(path, &cache).await?;
+			Cache::put(&cache).await;
 		}
 
 		Self::image(&cache).await
 	}
 
 	pub(super) async fn pdf(path: &Path, skip: usize) -> Result<PreviewData, PeekError> {
-		let cache = PREVIEW.cache(path, skip);
-		if fs::symlink_metadata(&cache).await.is_err() {
+		let cache = Cache::path(path, skip).await;
+		if !Cache::hit(&cache).await {
 			external::pdftoppm(path, &cache, skip).await?;
+			Cache::put(&cache).await;
 		}
 
 		Self::image(&cache).await
@@ -67,7 +186,7 @@ impl Provider {
 	pub(super) async fn json(path: &Path, skip: usize) -> Result<String, PeekError> {
 		let result = external::jq(path, skip, MANAGER.layout.preview_height()).await;
 		if let Err(PeekError::Unexpected(_)) = result {
-			return Self::highlight(path, skip).await;
+			return Self::highlight(path, skip, None).await;
 		}
 		result
 	}
@@ -83,9 +202,188 @@ impl Provider {
 		)
 	}
 
-	pub(super) async fn highlight(path: &Path, skip: usize) -> Result<String, PeekError> {
+	// Detected here, unless it's been done for the preview already
+	pub(super) async fn highlight(
+		path: &Path,
+		skip: usize,
+		encoding: Option<TextEncoding>,
+	) -> Result<String, PeekError> {
+		let encoding = match encoding {
+			Some(e) => e,
+			None => TextEncoding::detect(path).await?,
+		};
+
+		let len = fs::metadata(path).await?.len();
+		if PREVIEW.huge_size.is_some_and(|n| len > n) {
+			return Self::huge(path, len, skip, encoding).await;
+		}
+
 		let limit = MANAGER.layout.preview_height();
-		let result = Highlighter::new(path.to_owned()).highlight(skip, limit).await?;
+		let result = Highlighter::new(path.to_owned()).highlight(skip, limit, encoding).await?;
 		Ok(result.replace('\t', &" ".repeat(PREVIEW.tab_size as usize)))
 	}
+
+	// Huge files are paged by seeking, each unit of `skip` is `HUGE_STEP` bytes,
+	// and the last page always shows the tail of the file.
+	async fn huge(
+		path: &Path,
+		len: u64,
+		skip: usize,
+		encoding: TextEncoding,
+	) -> Result<String, PeekError> {
+		const HUGE_STEP: u64 = 128;
+		const LINE_MAX: usize = 1024;
+
+		let limit = MANAGER.layout.preview_height().saturating_sub(1).max(1);
+		let window = (limit * LINE_MAX) as u64;
+
+		let mut file = File::open(path).await?;
+		let tail = Self::huge_tail(&mut file, len, window, limit, encoding).await?;
+
+		let max = (tail / HUGE_STEP) as usize;
+		if skip > max {
+			return Err(PeekError::Exceed(max));
+		}
+
+		let offset = if skip == max { tail } else { skip as u64 * HUGE_STEP };
+		let mut buf = Vec::with_capacity(window as usize);
+		file.seek(SeekFrom::Start(offset)).await?;
+		(&mut file).take(window).read_to_end(&mut buf).await?;
+
+		// Drop the partial line we landed in the middle of
+		let mut decoded = encoding.decode_lines(&buf).into_iter();
+		let mut start = 0;
+		if offset != 0 && offset != tail {
+			start = decoded.next().map_or(0, |(_, n)| n);
+		}
+
+		let mut lines = Vec::with_capacity(limit);
+		let mut end = start;
+		for (mut line, n) in decoded.take(limit) {
+			end += n;
+			if let Some((i, _)) = line.char_indices().nth(LINE_MAX) {
+				line.truncate(i);
+			}
+			lines.push(line);
+		}
+
+		let (from, to) = (offset + start as u64, offset + end as u64);
+		Ok(format!(
+			"\x1b[2mbytes {from}-{to} of {len}\x1b[0m\n{}",
+			lines.join("\n").replace('\t', &" ".repeat(PREVIEW.tab_size as usize))
+		))
+	}
+
+	// Byte offset of the first of the last `limit` lines
+	async fn huge_tail(
+		file: &mut File,
+		len: u64,
+		window: u64,
+		limit: usize,
+		encoding: TextEncoding,
+	) -> io::Result<u64> {
+		let unit = encoding.unit();
+		let start = len.saturating_sub(window) / unit * unit;
+		let mut buf = Vec::with_capacity(window as usize);
+		file.seek(SeekFrom::Start(start)).await?;
+		file.read_to_end(&mut buf).await?;
+
+		// The first one is partial when it's not read from the beginning
+		let lines = encoding.decode_lines(&buf);
+		let n = if lines.len() > limit {
+			lines.len() - limit
+		} else if start > 0 && lines.len() > 1 {
+			1
+		} else {
+			0
+		};
+		Ok(start + lines[..n].iter().map(|&(_, n)| n as u64).sum::<u64>())
+	}
+
+	pub(super) async fn command(exec: &str, path: &Path, skip: usize) -> Result<String, PeekError> {
+		let output = external::shell(ShellOpt {
+			cmd:    exec.into(),
+			arg0:   Default::default(),
+			args:   vec![path.as_os_str().to_owned()],
+			env:    Default::default(),
+			piped:  true,
+			orphan: false,
+		})?
+		.wait_with_output()
+		.await?;
+
+		if !output.status.success() {
+			return Err(String::from_utf8_lossy(&output.stderr).trim().to_owned().into());
+		}
+
+		Self::paginate(&String::from_utf8_lossy(&output.stdout), skip)
+	}
+
+	pub(super) async fn plugin(
+		name: &str,
+		url: &Url,
+		mime: &str,
+		skip: usize,
+	) -> Result<String, PeekError> {
+		let file = files::File::from(url.clone()).await?;
+		let text = emit!(PluginPreview(name.to_owned(), file, mime.to_owned())).await?;
+		Self::paginate(&text, skip)
+	}
+
+	// The lines of a text output shown at the given skip
+	fn paginate(text: &str, skip: usize) -> Result<String, PeekError> {
+		let limit = MANAGER.layout.preview_height();
+		let total = text.lines().count();
+		if skip > 0 && total < skip + limit {
+			return Err(PeekError::Exceed(total.saturating_sub(limit)));
+		}
+		Ok(text.lines().skip(skip).take(limit).collect::<Vec<_>>().join("\n"))
+	}
+
+	pub(super) async fn hexdump(path: &Path, skip: usize) -> Result<String, PeekError> {
+		let Some(cap) = PREVIEW.hexdump_size else {
+			return Err("Unsupported mimetype".into());
+		};
+
+		// Opening a FIFO blocks until a writer shows up, and device nodes
+		// shouldn't be read at all, so only regular files are dumped.
+		let meta = fs::metadata(path).await?;
+		if !meta.is_file() {
+			return Err("Unsupported mimetype".into());
+		}
+
+		let len = meta.len().min(cap);
+		let rows = len.div_ceil(16) as usize;
+
+		let limit = MANAGER.layout.preview_height();
+		if skip > 0 && rows < skip + limit {
+			return Err(PeekError::Exceed(rows.saturating_sub(limit)));
+		}
+
+		let offset = skip as u64 * 16;
+		let mut buf = Vec::with_capacity(limit * 16);
+		let mut file = File::open(path).await?;
+		file.seek(SeekFrom::Start(offset)).await?;
+		file.take((limit as u64 * 16).min(len.saturating_sub(offset))).read_to_end(&mut buf).await?;
+
+		let mut s = String::with_capacity(limit * 80);
+		for (i, chunk) in buf.chunks(16).enumerate() {
+			s.push_str(&format!("{:08x} ", offset + i as u64 * 16));
+			for j in 0..16 {
+				if j % 8 == 0 {
+					s.push(' ');
+				}
+				match chunk.get(j) {
+					Some(b) => s.push_str(&format!("{b:02x} ")),
+					None => s.push_str("   "),
+				}
+			}
+
+			s.push_str(" |");
+			let ascii = |&b: &u8| if b == b' ' || b.is_ascii_graphic() { b as char } else { '.' };
+			s.extend(chunk.iter().map(ascii));
+			s.push_str("|\n");
+		}
+		Ok(s)
+	}
 }
@@ -0,0 +1,82 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::anyhow;
+use rusqlite::{types::ValueRef, Connection, OpenFlags};
+use yazi_config::MANAGER;
+use yazi_shared::PeekError;
+
+use super::Table;
+
+pub(super) struct Sqlite {
+	path: PathBuf,
+}
+
+impl Sqlite {
+	#[inline]
+	pub(super) fn new(path: &Path) -> Self { Self { path: path.to_owned() } }
+
+	// Each step of `skip` selects the next table
+	pub(super) async fn render(self, skip: usize) -> Result<String, PeekError> {
+		tokio::task::spawn_blocking(move || self.read(skip)).await?
+	}
+
+	fn read(&self, skip: usize) -> Result<String, PeekError> {
+		let flags = OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX;
+		let conn = Connection::open_with_flags(&self.path, flags).map_err(|e| anyhow!(e))?;
+
+		let tables = Self::tables(&conn).map_err(|e| anyhow!(e))?;
+		if tables.is_empty() {
+			return Ok("No tables".to_owned());
+		} else if skip >= tables.len() {
+			return Err(PeekError::Exceed(tables.len() - 1));
+		}
+
+		Ok(Self::dump(&conn, &tables, skip).map_err(|e| anyhow!(e))?)
+	}
+
+	fn tables(conn: &Connection) -> rusqlite::Result<Vec<String>> {
+		conn
+			.prepare("SELECT name FROM sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%' ORDER BY name")?
+			.query_map([], |row| row.get(0))?
+			.collect()
+	}
+
+	fn dump(conn: &Connection, tables: &[String], skip: usize) -> rusqlite::Result<String> {
+		let mut s = String::new();
+		for (i, name) in tables.iter().enumerate() {
+			let count: i64 =
+				conn.query_row(&format!("SELECT COUNT(*) FROM {}", Self::quote(name)), [], |row| {
+					row.get(0)
+				})?;
+			let mark = if i == skip { "▶" } else { " " };
+			s.push_str(&format!("{mark} {name}  \x1b[2m{count} rows\x1b[0m\n"));
+		}
+
+		// The table list, a blank line, the header and its underline
+		let limit = MANAGER.layout.preview_height().saturating_sub(tables.len() + 3).max(1);
+		let mut stmt =
+			conn.prepare(&format!("SELECT * FROM {} LIMIT {limit}", Self::quote(&tables[skip])))?;
+
+		let header: Vec<_> = stmt.column_names().into_iter().map(Table::cell).collect();
+		let rows = stmt
+			.query_map([], |row| (0..header.len()).map(|i| row.get_ref(i).map(Self::value)).collect())?
+			.collect::<Result<Vec<_>, _>>()?;
+
+		s.push('\n');
+		s.push_str(&Table::grid(&header, &rows));
+		Ok(s)
+	}
+
+	#[inline]
+	fn quote(name: &str) -> String { format!("\"{}\"", name.replace('"', "\"\"")) }
+
+	fn value(v: ValueRef) -> String {
+		match v {
+			ValueRef::Null => "NULL".to_owned(),
+			ValueRef::Integer(n) => n.to_string(),
+			ValueRef::Real(f) => f.to_string(),
+			ValueRef::Text(t) => Table::cell(&String::from_utf8_lossy(t)),
+			ValueRef::Blob(b) => format!("<{} bytes>", b.len()),
+		}
+	}
+}
@@ -0,0 +1,130 @@
+use std::{fs::File, io::{BufRead, BufReader}, path::{Path, PathBuf}};
+
+use anyhow::anyhow;
+use csv::ReaderBuilder;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+use yazi_config::MANAGER;
+use yazi_shared::PeekError;
+
+const COLUMN_MAX: usize = 24;
+
+pub(super) struct Table {
+	path: PathBuf,
+}
+
+struct Page {
+	header: Vec<String>,
+	rows:   Vec<Vec<String>>,
+	total:  usize,
+}
+
+impl Table {
+	#[inline]
+	pub(super) fn new(path: &Path) -> Self { Self { path: path.to_owned() } }
+
+	pub(super) async fn render(self, skip: usize) -> Result<String, PeekError> {
+		// The header and its underline are pinned, the rest scrolls
+		let limit = MANAGER.layout.preview_height().saturating_sub(2).max(1);
+		let Page { header, rows, total } =
+			tokio::task::spawn_blocking(move || self.read(skip, limit)).await??;
+
+		if skip > 0 && total < skip + limit {
+			return Err(PeekError::Exceed(total.saturating_sub(limit)));
+		}
+		Ok(Self::grid(&header, &rows))
+	}
+
+	// Align the cells into columns, with the header in bold and underlined
+	pub(super) fn grid(header: &[String], rows: &[Vec<String>]) -> String {
+		let mut widths = vec![0; header.len()];
+		for row in rows.iter().map(|r| r.as_slice()).chain([header]) {
+			if widths.len() < row.len() {
+				widths.resize(row.len(), 0);
+			}
+			for (w, cell) in widths.iter_mut().zip(row) {
+				*w = (*w).max(cell.width().min(COLUMN_MAX));
+			}
+		}
+
+		let mut s = format!("\x1b[1m{}\x1b[0m\n", Self::line(header, &widths));
+		s.push_str(&widths.iter().map(|&w| "─".repeat(w)).collect::<Vec<_>>().join("  "));
+		for row in rows {
+			s.push('\n');
+			s.push_str(&Self::line(row, &widths));
+		}
+		s
+	}
+
+	fn read(&self, skip: usize, limit: usize) -> Result<Page, PeekError> {
+		let delimiter = self.delimiter()?;
+		let mut reader = ReaderBuilder::new()
+			.delimiter(delimiter)
+			.quoting(delimiter != b'\t')
+			.has_headers(true)
+			.flexible(true)
+			.from_path(&self.path)
+			.map_err(|e| anyhow!(e))?;
+
+		let header = reader.headers().map_err(|e| anyhow!(e))?.iter().map(Self::cell).collect();
+
+		let mut rows = Vec::with_capacity(limit);
+		let mut total = 0;
+		for record in reader.records() {
+			total += 1;
+			if total > skip + limit {
+				break;
+			} else if total > skip {
+				rows.push(record.map_err(|e| anyhow!(e))?.iter().map(Self::cell).collect());
+			}
+		}
+		Ok(Page { header, rows, total })
+	}
+
+	fn delimiter(&self) -> Result<u8, PeekError> {
+		let ext = self.path.extension().map(|e| e.to_ascii_lowercase());
+		if ext.as_ref().is_some_and(|e| e == "tsv" || e == "tab") {
+			return Ok(b'\t');
+		} else if ext.as_ref().is_some_and(|e| e == "csv") {
+			return Ok(b',');
+		}
+
+		// Otherwise, go with whichever is more common in the first line
+		let mut line = String::new();
+		BufReader::new(File::open(&self.path)?).read_line(&mut line)?;
+		let tabs = line.matches('\t').count();
+		Ok(if tabs > line.matches(',').count() { b'\t' } else { b',' })
+	}
+
+	#[inline]
+	pub(super) fn cell(s: &str) -> String { s.replace(['\r', '\n', '\t'], " ") }
+
+	fn line(row: &[String], widths: &[usize]) -> String {
+		let mut cells = Vec::with_capacity(widths.len());
+		for (i, &w) in widths.iter().enumerate() {
+			let cell = row.get(i).map(|s| s.as_str()).unwrap_or_default();
+			cells.push(Self::fit(cell, w));
+		}
+		cells.join("  ").trim_end().to_owned()
+	}
+
+	// Pad or truncate the cell to exactly `width` columns
+	fn fit(s: &str, width: usize) -> String {
+		if s.width() <= width {
+			return format!("{s}{}", " ".repeat(width - s.width()));
+		}
+
+		let mut out = String::with_capacity(width);
+		let mut used = 0;
+		for c in s.chars() {
+			let w = c.width().unwrap_or(0);
+			if used + w + 1 > width {
+				break;
+			}
+			out.push(c);
+			used += w;
+		}
+		out.push('…');
+		out.push_str(&" ".repeat(width.saturating_sub(used + 1)));
+		out
+	}
+}
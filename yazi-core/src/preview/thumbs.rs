@@ -0,0 +1,97 @@
+use std::{fs, path::{Path, PathBuf}};
+
+use anyhow::Result;
+use rusqlite::{params, Connection, OpenFlags, OptionalExtension};
+use yazi_config::PREVIEW;
+
+use super::Cache;
+
+const DB_NAME: &str = ".yazi-thumbs.db";
+
+// The preview cache entries of a directory tree, saved into a database at its
+// root. Entries are keyed by the relative path and a hash of the contents, so
+// they still apply after the tree is copied over to another machine.
+pub(crate) struct Thumbs;
+
+impl Thumbs {
+	pub(crate) fn export(root: &Path) -> Result<usize> {
+		let conn = Connection::open(root.join(DB_NAME))?;
+		conn.execute_batch(
+			"CREATE TABLE IF NOT EXISTS thumbs (
+				path TEXT NOT NULL,
+				skip INTEGER NOT NULL,
+				hash TEXT NOT NULL,
+				data BLOB NOT NULL,
+				PRIMARY KEY (path, skip)
+			)",
+		)?;
+
+		let mut count = 0;
+		let mut stmt = conn.prepare("INSERT OR REPLACE INTO thumbs VALUES (?1, ?2, ?3, ?4)")?;
+		for path in Self::files(root) {
+			let Some(rel) = Self::relative(root, &path) else { continue };
+
+			let Ok(hash) = Cache::contents_hash(&path) else { continue };
+			for skip in 0.. {
+				let Ok(data) = fs::read(PREVIEW.cache(&hash, skip)) else { break };
+				stmt.execute(params![rel, skip, hash, data])?;
+				count += 1;
+			}
+		}
+		Ok(count)
+	}
+
+	pub(crate) fn import(root: &Path) -> Result<Vec<PathBuf>> {
+		let conn = Connection::open_with_flags(root.join(DB_NAME), OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+
+		let mut written = vec![];
+		let mut stmt = conn.prepare("SELECT hash, data FROM thumbs WHERE path = ?1 AND skip = ?2")?;
+		for path in Self::files(root) {
+			let Some(rel) = Self::relative(root, &path) else { continue };
+
+			let mut hash = None;
+			for skip in 0.. {
+				let row: Option<(String, Vec<u8>)> =
+					stmt.query_row(params![rel, skip], |r| Ok((r.get(0)?, r.get(1)?))).optional()?;
+				let Some((expected, data)) = row else { break };
+
+				// Stale entries of files changed since the export are left out
+				let hash = match &hash {
+					Some(h) => h,
+					None => hash.insert(Cache::contents_hash(&path)?),
+				};
+				if *hash != expected {
+					break;
+				}
+
+				let cache = PREVIEW.cache(hash, skip);
+				fs::write(&cache, data)?;
+				written.push(cache);
+			}
+		}
+		Ok(written)
+	}
+
+	fn files(root: &Path) -> Vec<PathBuf> {
+		let mut files = vec![];
+		let mut stack = vec![root.to_owned()];
+		while let Some(dir) = stack.pop() {
+			let Ok(it) = fs::read_dir(dir) else { continue };
+			for entry in it.flatten() {
+				match entry.file_type() {
+					Ok(t) if t.is_dir() => stack.push(entry.path()),
+					Ok(t) if t.is_file() && entry.file_name() != DB_NAME => files.push(entry.path()),
+					_ => {}
+				}
+			}
+		}
+		files
+	}
+
+	// With `/` as the separator, so it's the same key on every platform
+	fn relative(root: &Path, path: &Path) -> Option<String> {
+		let rel = path.strip_prefix(root).ok()?;
+		let parts: Option<Vec<_>> = rel.components().map(|c| c.as_os_str().to_str()).collect();
+		Some(parts?.join("/"))
+	}
+}
@@ -0,0 +1,216 @@
+use std::{env, path::PathBuf};
+
+use anyhow::{bail, Result};
+use yazi_config::keymap::Exec;
+
+#[cfg(unix)]
+const READ_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+// Commands sent from `yazi --remote` to a running instance, through a socket
+// named after its pid, which is also exported as `YAZI_ID` to the processes
+// spawned from it, so those talk to the right one.
+pub struct Remote;
+
+impl Remote {
+	fn dir() -> PathBuf {
+		match env::var_os("XDG_RUNTIME_DIR") {
+			Some(d) if !d.is_empty() => PathBuf::from(d).join("yazi"),
+			#[cfg(unix)]
+			_ => env::temp_dir().join(format!("yazi-{}", unsafe { libc::getuid() })),
+			#[cfg(windows)]
+			_ => env::temp_dir().join("yazi"),
+		}
+	}
+
+	#[inline]
+	fn path(id: &str) -> PathBuf { Self::dir().join(format!("{id}.sock")) }
+}
+
+#[cfg(unix)]
+impl Remote {
+	// Must be called before the runtime starts, as setting the environment while
+	// other threads may read it is a data race
+	pub fn export() { env::set_var("YAZI_ID", std::process::id().to_string()); }
+
+	// Made private to the user, and refused unless it is, as anyone else able to
+	// write into it could plant sockets of their own there
+	fn secure_dir() -> Result<PathBuf> {
+		use std::os::unix::fs::{DirBuilderExt, MetadataExt};
+
+		let dir = Self::dir();
+		std::fs::DirBuilder::new().recursive(true).mode(0o700).create(&dir).ok();
+
+		let meta = std::fs::symlink_metadata(&dir)?;
+		if !meta.is_dir() || meta.uid() != unsafe { libc::getuid() } || meta.mode() & 0o077 != 0 {
+			bail!("Not a private directory for the sockets: {}", dir.display());
+		}
+		Ok(dir)
+	}
+
+	// Shared between the instances in fields each ended by a NUL, after a leading
+	// one that tells it apart from the commands of `yazi --remote`: the command,
+	// the named ones as `key=value`, an empty one, and the args. Nothing's split
+	// as shell words, so paths with newlines, or starting with `--`, come through.
+	fn encode(exec: &Exec) -> Vec<u8> {
+		let mut buf = vec![0];
+		let named = exec.named.iter().map(|(k, v)| format!("{k}={v}"));
+		for field in [exec.cmd.clone()].into_iter().chain(named).chain([String::new()]) {
+			buf.extend(field.as_bytes());
+			buf.push(0);
+		}
+		for arg in &exec.args {
+			buf.extend(arg.as_bytes());
+			buf.push(0);
+		}
+		buf
+	}
+
+	fn decode(buf: &[u8]) -> Option<Exec> {
+		let buf = buf.strip_prefix(&[0])?.strip_suffix(&[0])?;
+		let mut fields = buf.split(|&b| b == 0).map(|b| String::from_utf8_lossy(b).into_owned());
+
+		let mut exec = Exec::call(&fields.next().filter(|c| !c.is_empty())?, vec![]);
+		for field in fields.by_ref().take_while(|f| !f.is_empty()) {
+			let (k, v) = field.split_once('=')?;
+			exec = exec.with(k, v);
+		}
+		exec.args = fields.collect();
+		Some(exec)
+	}
+
+	pub fn serve() {
+		use std::os::unix::fs::PermissionsExt;
+
+		use tokio::net::UnixListener;
+		use tracing::warn;
+
+		if let Err(e) = Self::secure_dir() {
+			return warn!("{e}");
+		}
+
+		let id = std::process::id().to_string();
+		let path = Self::path(&id);
+		std::fs::remove_file(&path).ok();
+
+		let Ok(listener) = UnixListener::bind(&path) else {
+			return;
+		};
+		std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600)).ok();
+
+		tokio::spawn(async move {
+			while let Ok((stream, _)) = listener.accept().await {
+				tokio::spawn(Self::handle(stream));
+			}
+		});
+	}
+
+	// Each client on its own, and given up on if it doesn't finish sending in
+	// time, so an idle or slow one can't hold up the others
+	async fn handle(mut stream: tokio::net::UnixStream) {
+		use tokio::{io::{AsyncReadExt, AsyncWriteExt}, time};
+		use yazi_config::keymap::KeymapLayer;
+
+		use crate::emit;
+
+		let mut buf = vec![];
+		match time::timeout(READ_TIMEOUT, stream.read_to_end(&mut buf)).await {
+			Ok(Ok(_)) => {}
+			_ => return,
+		}
+
+		// Shared by the other instances, which don't wait for a reply
+		if buf.first() == Some(&0) {
+			if let Some(exec) = Self::decode(&buf) {
+				emit!(Call(exec.vec(), KeymapLayer::Manager));
+			}
+			return;
+		}
+
+		let s = String::from_utf8_lossy(&buf);
+		let mut errors = String::new();
+		for line in s.lines().map(str::trim).filter(|l| !l.is_empty()) {
+			match Exec::chain(line) {
+				Ok(execs) => {
+					emit!(Call(execs, KeymapLayer::Manager));
+				}
+				Err(e) => errors.push_str(&format!("{line}: {e}\n")),
+			}
+		}
+		stream.write_all(errors.as_bytes()).await.ok();
+	}
+
+	pub fn stop() { std::fs::remove_file(Self::path(&std::process::id().to_string())).ok(); }
+
+	// To all the other running instances, without waiting for their replies
+	pub fn broadcast(exec: Exec) {
+		use tokio::{io::AsyncWriteExt, net::UnixStream};
+
+		let buf = Self::encode(&exec);
+		let own = Self::path(&std::process::id().to_string());
+		tokio::spawn(async move {
+			let Ok(it) = Self::secure_dir().and_then(|d| Ok(std::fs::read_dir(d)?)) else {
+				return;
+			};
+
+			let socks = it.flatten().map(|e| e.path());
+			for path in socks.filter(|p| *p != own && p.extension().is_some_and(|e| e == "sock")) {
+				if let Ok(mut stream) = UnixStream::connect(&path).await {
+					stream.write_all(&buf).await.ok();
+					stream.shutdown().await.ok();
+				}
+			}
+		});
+	}
+
+	// To the instance of `YAZI_ID`, or the most recently started one
+	pub async fn send(cmd: &str) -> Result<()> {
+		use tokio::{io::{AsyncReadExt, AsyncWriteExt}, net::UnixStream};
+
+		Self::secure_dir()?;
+		let mut stream = match env::var("YAZI_ID") {
+			Ok(id) if !id.is_empty() => UnixStream::connect(Self::path(&id)).await?,
+			_ => Self::latest().await?,
+		};
+
+		stream.write_all(cmd.as_bytes()).await?;
+		stream.shutdown().await?;
+
+		let mut errors = String::new();
+		stream.read_to_string(&mut errors).await?;
+		if !errors.is_empty() {
+			bail!("{}", errors.trim_end());
+		}
+		Ok(())
+	}
+
+	async fn latest() -> Result<tokio::net::UnixStream> {
+		let mut socks: Vec<_> = std::fs::read_dir(Self::secure_dir()?)?
+			.flatten()
+			.filter(|e| e.path().extension().is_some_and(|e| e == "sock"))
+			.filter_map(|e| Some((e.metadata().ok()?.modified().ok()?, e.path())))
+			.collect();
+
+		socks.sort_unstable_by_key(|&(mtime, _)| std::cmp::Reverse(mtime));
+		for (_, path) in socks {
+			match tokio::net::UnixStream::connect(&path).await {
+				Ok(stream) => return Ok(stream),
+				// Left behind by an instance that didn't exit cleanly
+				Err(_) => _ = std::fs::remove_file(path),
+			}
+		}
+		bail!("No running instance found")
+	}
+}
+
+#[cfg(windows)]
+impl Remote {
+	pub fn export() {}
+
+	pub fn serve() {}
+
+	pub fn stop() {}
+
+	pub fn broadcast(_: Exec) {}
+
+	pub async fn send(_: &str) -> Result<()> { bail!("Remote control is not supported on Windows") }
+}
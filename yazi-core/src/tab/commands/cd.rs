@@ -1,4 +1,4 @@
-use std::{mem, time::Duration};
+use std::time::Duration;
 
 use tokio::{fs, pin};
 use tokio_stream::{wrappers::UnboundedReceiverStream, StreamExt};
@@ -44,14 +44,14 @@ impl Tab {
 
 		// Take parent to history
 		if let Some(rep) = self.parent.take() {
-			self.history.insert(rep.cwd.clone(), rep);
+			self.history_put(rep);
 		}
 
 		// Current
 		let rep = self.history_new(&opt.target);
-		let rep = mem::replace(&mut self.current, rep);
+		let rep = self.replace_current(rep);
 		if rep.cwd.is_regular() {
-			self.history.insert(rep.cwd.clone(), rep);
+			self.history_put(rep);
 		}
 
 		// Parent
@@ -1,5 +1,3 @@
-use std::mem;
-
 use yazi_config::keymap::Exec;
 
 use crate::{manager::Manager, tab::Tab};
@@ -20,14 +18,14 @@ impl Tab {
 
 		// Current
 		let rep = self.history_new(&hovered);
-		let rep = mem::replace(&mut self.current, rep);
+		let rep = self.replace_current(rep);
 		if rep.cwd.is_regular() {
-			self.history.insert(rep.cwd.clone(), rep);
+			self.history_put(rep);
 		}
 
 		// Parent
 		if let Some(rep) = self.parent.take() {
-			self.history.insert(rep.cwd.clone(), rep);
+			self.history_put(rep);
 		}
 		self.parent = Some(self.history_new(&hovered.parent_url().unwrap()));
 
@@ -39,8 +39,14 @@ impl Tab {
 		false
 	}
 
-	#[inline]
-	fn escape_select(&mut self) -> bool { self.select_all(Some(false)) }
+	fn escape_select(&mut self) -> bool {
+		let b = self.select_all(Some(false));
+		if self.elsewhere.is_empty() {
+			return b;
+		}
+		self.elsewhere.clear();
+		true
+	}
 
 	#[inline]
 	fn escape_search(&mut self) -> bool { self.search_stop() }
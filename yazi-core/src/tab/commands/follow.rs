@@ -0,0 +1,34 @@
+use tokio::fs;
+use yazi_config::keymap::Exec;
+use yazi_shared::Url;
+
+use crate::tab::Tab;
+
+pub struct Opt;
+
+impl From<&Exec> for Opt {
+	fn from(_: &Exec) -> Self { Self }
+}
+
+impl Tab {
+	// Reveal the target of the hovered symlink, a relative target is resolved
+	// from the directory of the link, which is canonicalized to drop the `..`
+	pub fn follow(&self, _: impl Into<Opt>) -> bool {
+		let Some(hovered) = self.current.hovered() else {
+			return false;
+		};
+		let (Some(parent), Some(target)) = (hovered.url.parent(), hovered.link_to()) else {
+			return false;
+		};
+
+		let target = parent.join(target);
+		tokio::spawn(async move {
+			let (Some(dir), Some(name)) = (target.parent(), target.file_name()) else {
+				return;
+			};
+			let dir = fs::canonicalize(dir).await.unwrap_or_else(|_| dir.to_owned());
+			Tab::_reveal(&Url::from(dir.join(name)));
+		});
+		false
+	}
+}
@@ -0,0 +1,18 @@
+use yazi_config::keymap::Exec;
+
+use crate::{emit, tab::Tab};
+
+impl Tab {
+	pub fn ignored(&mut self, e: &Exec) -> bool {
+		self.conf.show_ignored = match e.args.first().map(|s| s.as_bytes()) {
+			Some(b"show") => true,
+			Some(b"hide") => false,
+			_ => !self.conf.show_ignored,
+		};
+		if self.apply_files_attrs(false) {
+			emit!(Peek);
+			return true;
+		}
+		false
+	}
+}
@@ -1,10 +1,11 @@
-use yazi_config::keymap::Exec;
+use yazi_config::{keymap::Exec, popup::SelectOpt};
 use yazi_shared::{ends_with_slash, Defer};
 
 use crate::{emit, external::{self, FzfOpt, ZoxideOpt}, tab::Tab, Event, BLOCKER};
 
 pub struct Opt {
-	type_: OptType,
+	type_:  OptType,
+	select: bool,
 }
 
 #[derive(PartialEq, Eq)]
@@ -17,11 +18,12 @@ pub enum OptType {
 impl From<&Exec> for Opt {
 	fn from(e: &Exec) -> Self {
 		Self {
-			type_: match e.args.first().map(|s| s.as_str()) {
+			type_:  match e.args.first().map(|s| s.as_str()) {
 				Some("fzf") => OptType::Fzf,
 				Some("zoxide") => OptType::Zoxide,
 				_ => OptType::None,
 			},
+			select: e.named.contains_key("select"),
 		}
 	}
 }
@@ -34,6 +36,23 @@ impl Tab {
 		}
 
 		let cwd = self.current.cwd.clone();
+		if opt.select && opt.type_ == OptType::Zoxide {
+			tokio::spawn(async move {
+				let Ok(urls) = external::zoxide_list(ZoxideOpt { cwd }).await else {
+					return;
+				};
+				if urls.is_empty() {
+					return;
+				}
+
+				let items = urls.iter().map(|u| u.to_string_lossy().into_owned()).collect();
+				if let Ok(choice) = emit!(Select(SelectOpt::zoxide(items))).await {
+					Tab::_cd(&urls[choice]);
+				}
+			});
+			return false;
+		}
+
 		tokio::spawn(async move {
 			let _guard = BLOCKER.acquire().await.unwrap();
 			let _defer = Defer::new(|| Event::Stop(false, None).emit());
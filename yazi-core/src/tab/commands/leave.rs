@@ -1,5 +1,3 @@
-use std::mem;
-
 use yazi_config::keymap::Exec;
 
 use crate::{manager::Manager, tab::Tab};
@@ -27,7 +25,7 @@ impl Tab {
 
 		// Parent
 		if let Some(rep) = self.parent.take() {
-			self.history.insert(rep.cwd.clone(), rep);
+			self.history_put(rep);
 		}
 		if let Some(parent) = current.parent_url() {
 			self.parent = Some(self.history_new(&parent));
@@ -35,9 +33,9 @@ impl Tab {
 
 		// Current
 		let rep = self.history_new(&current);
-		let rep = mem::replace(&mut self.current, rep);
+		let rep = self.replace_current(rep);
 		if rep.cwd.is_regular() {
-			self.history.insert(rep.cwd.clone(), rep);
+			self.history_put(rep);
 		}
 
 		// Backstack
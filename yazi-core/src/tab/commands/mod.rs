@@ -5,7 +5,9 @@ mod copy;
 mod enter;
 mod escape;
 mod find;
+mod follow;
 mod hidden;
+mod ignored;
 mod jump;
 mod leave;
 mod linemode;
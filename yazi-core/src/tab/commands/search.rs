@@ -1,11 +1,11 @@
-use std::{mem, time::Duration};
+use std::time::Duration;
 
 use anyhow::bail;
 use tokio::pin;
 use tokio_stream::{wrappers::UnboundedReceiverStream, StreamExt};
 use yazi_config::{keymap::Exec, popup::InputOpt};
 
-use crate::{emit, external, files::FilesOp, manager::Manager, tab::Tab};
+use crate::{emit, external, files::FilesStream, manager::Manager, tab::Tab};
 
 pub struct Opt {
 	pub type_: OptType,
@@ -57,15 +57,16 @@ impl Tab {
 			let rx = UnboundedReceiverStream::new(rx).chunks_timeout(1000, Duration::from_millis(300));
 			pin!(rx);
 
-			let ticket = FilesOp::prepare(&cwd);
+			let stream = FilesStream::new(&cwd);
 			let mut first = true;
 			while let Some(chunk) = rx.next().await {
 				if first {
 					Tab::_cd(&cwd);
 					first = false;
 				}
-				emit!(Files(FilesOp::Part(cwd.clone(), ticket, chunk)));
+				stream.part(chunk);
 			}
+			stream.done();
 			Ok(())
 		}));
 		true
@@ -79,7 +80,7 @@ impl Tab {
 			self.preview.reset(|l| l.is_image());
 
 			let rep = self.history_new(&self.current.cwd.to_regular());
-			drop(mem::replace(&mut self.current, rep));
+			drop(self.replace_current(rep));
 			Manager::_refresh();
 		}
 		false
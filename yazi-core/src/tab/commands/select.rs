@@ -1,30 +1,44 @@
-use yazi_config::keymap::Exec;
+use regex::Regex;
+use yazi_config::{keymap::{Exec, KeymapLayer}, popup::InputOpt, Pattern};
 
-use crate::tab::Tab;
+use crate::{emit, tab::Tab};
 
 pub struct Opt {
-	state: Option<bool>,
+	state:    Option<bool>,
+	pattern:  Option<String>,
+	regex:    bool,
+	deselect: bool,
 }
 
 impl From<&Exec> for Opt {
 	fn from(e: &Exec) -> Self {
 		Self {
-			state: match e.named.get("state").map(|s| s.as_bytes()) {
+			state:    match e.named.get("state").map(|s| s.as_bytes()) {
 				Some(b"true") => Some(true),
 				Some(b"false") => Some(false),
 				_ => None,
 			},
+			pattern:  e.named.get("pattern").cloned(),
+			regex:    e.named.contains_key("regex"),
+			deselect: e.named.contains_key("deselect"),
 		}
 	}
 }
 impl From<Option<bool>> for Opt {
-	fn from(state: Option<bool>) -> Self { Self { state } }
+	fn from(state: Option<bool>) -> Self {
+		Self { state, pattern: None, regex: false, deselect: false }
+	}
 }
 
 impl Tab {
 	pub fn select(&mut self, opt: impl Into<Opt>) -> bool {
+		let opt = opt.into() as Opt;
+		if let Some(pattern) = &opt.pattern {
+			return self.select_pattern(pattern, opt.regex, opt.deselect);
+		}
+
 		if let Some(u) = self.current.hovered().map(|h| h.url()) {
-			return self.current.files.select(&u, opt.into().state);
+			return self.current.files.select(&u, opt.state);
 		}
 		false
 	}
@@ -32,4 +46,72 @@ impl Tab {
 	pub fn select_all(&mut self, opt: impl Into<Opt>) -> bool {
 		self.current.files.select_all(opt.into().state)
 	}
+
+	// Ask for the pattern, starting with `value`, and why the last one was
+	// refused as the title if any, then give it back to `select`
+	fn select_pattern_ask(value: String, error: Option<String>, regex: bool, deselect: bool) {
+		tokio::spawn(async move {
+			let mut opt = InputOpt::select(deselect).with_value(value);
+			if let Some(error) = error {
+				opt = opt.with_title(error);
+			}
+
+			let mut result = emit!(Input(opt));
+			if let Some(Ok(pattern)) = result.recv().await {
+				emit!(Call(
+					Exec::call("select", vec![])
+						.with("pattern", pattern)
+						.with_bool("regex", regex)
+						.with_bool("deselect", deselect)
+						.vec(),
+					KeymapLayer::Manager
+				));
+			}
+		});
+	}
+
+	// Select the files in the current folder whose names match the pattern,
+	// which is asked for if empty, or again if invalid with why
+	fn select_pattern(&mut self, pattern: &str, regex: bool, deselect: bool) -> bool {
+		if pattern.is_empty() {
+			Self::select_pattern_ask(String::new(), None, regex, deselect);
+			return false;
+		}
+
+		let matches: Box<dyn Fn(&str) -> bool> = if regex {
+			match Regex::new(pattern) {
+				Ok(re) => Box::new(move |s| re.is_match(s)),
+				Err(e) => {
+					let reason = e.to_string();
+					let reason = reason.lines().last().unwrap_or_default().trim_start_matches("error: ");
+					let error = format!("Invalid regex, {reason}:");
+					Self::select_pattern_ask(pattern.to_owned(), Some(error), regex, deselect);
+					return false;
+				}
+			}
+		} else {
+			match Pattern::try_from(pattern) {
+				Ok(glob) => Box::new(move |s| glob.matches(s)),
+				Err(e) => {
+					let error = format!("Invalid glob, {e}:");
+					Self::select_pattern_ask(pattern.to_owned(), Some(error), regex, deselect);
+					return false;
+				}
+			}
+		};
+
+		let urls: Vec<_> = self
+			.current
+			.files
+			.iter()
+			.filter(|f| f.url.file_name().is_some_and(|n| matches(&n.to_string_lossy())))
+			.map(|f| f.url())
+			.collect();
+
+		let mut applied = false;
+		for u in urls {
+			applied |= self.current.files.select(&u, Some(!deselect));
+		}
+		applied
+	}
 }
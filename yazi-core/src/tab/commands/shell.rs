@@ -26,6 +26,9 @@ impl Tab {
 			.map(|f| (f.url.as_os_str().to_owned(), Default::default()))
 			.collect();
 
+		// `$0` is the hovered file, while `$@` are the selected ones
+		let hovered = self.current.hovered().map(|h| h.url.as_os_str().to_owned());
+
 		let mut opt = opt.into() as Opt;
 		tokio::spawn(async move {
 			if !opt.confirm || opt.cmd.is_empty() {
@@ -39,12 +42,14 @@ impl Tab {
 			emit!(Open(
 				selected,
 				Some(Opener {
-					exec:   opt.cmd,
-					block:  opt.block,
-					orphan: false,
-					desc:   Default::default(),
-					for_:   None,
-					spread: true,
+					exec:    opt.cmd,
+					block:   opt.block,
+					orphan:  false,
+					desc:    Default::default(),
+					for_:    None,
+					spread:  true,
+					extract: None,
+					arg0:    hovered,
 				})
 			));
 		});
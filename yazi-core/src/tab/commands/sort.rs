@@ -12,6 +12,7 @@ impl Tab {
 		self.conf.sort_sensitive = e.named.contains_key("sensitive");
 		self.conf.sort_reverse = e.named.contains_key("reverse");
 		self.conf.sort_dir_first = e.named.contains_key("dir_first");
+		self.conf.sort_translit = e.named.contains_key("translit");
 
 		self.apply_files_attrs(false)
 	}
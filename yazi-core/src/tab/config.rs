@@ -9,10 +9,12 @@ pub struct Config {
 	pub sort_sensitive: bool,
 	pub sort_reverse:   bool,
 	pub sort_dir_first: bool,
+	pub sort_translit:  bool,
 
 	// Display
-	pub linemode:    String,
-	pub show_hidden: bool,
+	pub linemode:     String,
+	pub show_hidden:  bool,
+	pub show_ignored: bool,
 }
 
 impl Default for Config {
@@ -23,10 +25,12 @@ impl Default for Config {
 			sort_sensitive: MANAGER.sort_sensitive,
 			sort_reverse:   MANAGER.sort_reverse,
 			sort_dir_first: MANAGER.sort_dir_first,
+			sort_translit:  MANAGER.sort_translit,
 
 			// Display
-			linemode:    MANAGER.linemode.to_owned(),
-			show_hidden: MANAGER.show_hidden,
+			linemode:     MANAGER.linemode.to_owned(),
+			show_hidden:  MANAGER.show_hidden,
+			show_ignored: MANAGER.show_ignored,
 		}
 	}
 }
@@ -45,6 +49,7 @@ impl Config {
 			sensitive: self.sort_sensitive,
 			reverse:   self.sort_reverse,
 			dir_first: self.sort_dir_first,
+			translit:  self.sort_translit,
 		}
 	}
 }
@@ -13,6 +13,10 @@ pub struct Folder {
 	pub cursor: usize,
 
 	pub page: usize,
+
+	// The hovered file and its row in the viewport when put into the history,
+	// kept until restored, as the files may still be loading when back
+	viewport: Option<(Url, usize)>,
 }
 
 impl From<Url> for Folder {
@@ -25,10 +29,15 @@ impl From<&Url> for Folder {
 
 impl Folder {
 	pub fn update(&mut self, op: FilesOp) -> bool {
+		let pending = self.viewport.is_some();
+		let viewport = self.viewport.take().or_else(|| self.viewport());
 		let b = match op {
 			FilesOp::Full(_, items) => self.files.update_full(items),
 			FilesOp::Part(_, ticket, items) => self.files.update_part(ticket, items),
+			FilesOp::Done(_, ticket) => self.files.update_done(ticket),
+			FilesOp::Abort(_, ticket) => self.files.update_abort(ticket),
 			FilesOp::Size(_, items) => self.files.update_size(items),
+			FilesOp::Git(_, items) => self.files.update_git(items),
 
 			FilesOp::Creating(_, items) => self.files.update_creating(items),
 			FilesOp::Deleting(_, items) => self.files.update_deleting(items),
@@ -39,6 +48,13 @@ impl Folder {
 			return false;
 		}
 
+		// Keep the hovered file at the same row of the viewport
+		if let Some((url, rel)) = viewport {
+			if !self.restore(&url, rel) && pending && self.files.is_loading() {
+				self.viewport = Some((url, rel));
+			}
+		}
+
 		let old = self.page;
 		self.prev(Default::default());
 
@@ -121,6 +137,34 @@ impl Folder {
 		}
 	}
 
+	fn restore(&mut self, url: &Url, rel: usize) -> bool {
+		let Some(new) = self.files.position(url) else {
+			return false;
+		};
+
+		let limit = MANAGER.layout.folder_height();
+		self.cursor = new;
+		self.offset = new.saturating_sub(rel).min(self.files.len().saturating_sub(limit));
+		true
+	}
+
+	#[inline]
+	fn viewport(&self) -> Option<(Url, usize)> {
+		self.hovered().map(|h| (h.url(), self.cursor.saturating_sub(self.offset)))
+	}
+
+	// Remember where the folder was viewed from, when it's put into the history
+	pub(super) fn save_viewport(&mut self) { self.viewport = self.viewport(); }
+
+	// And put it back when it's taken out, or later once the file has loaded
+	pub(super) fn restore_viewport(&mut self) {
+		if let Some((url, rel)) = self.viewport.take() {
+			if !self.restore(&url, rel) {
+				self.viewport = Some((url, rel));
+			}
+		}
+	}
+
 	#[inline]
 	pub fn repos(&mut self, url: Option<impl AsRef<Url>>) -> bool {
 		if let Some(u) = url { self.hover(u.as_ref()) } else { self.prev(Default::default()) }
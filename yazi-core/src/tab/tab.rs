@@ -1,7 +1,9 @@
-use std::{borrow::Cow, collections::BTreeMap};
+use std::{borrow::Cow, collections::{BTreeMap, HashSet}, mem};
 
 use anyhow::Result;
+use indexmap::IndexMap;
 use tokio::task::JoinHandle;
+use yazi_config::MANAGER;
 use yazi_shared::Url;
 
 use super::{Backstack, Config, Finder, Folder, Mode};
@@ -10,11 +12,14 @@ use crate::{files::File, preview::{Preview, PreviewLock}};
 pub struct Tab {
 	pub mode:    Mode,
 	pub conf:    Config,
+	pub usage:   Option<Config>,
 	pub current: Folder,
 	pub parent:  Option<Folder>,
 
 	pub backstack: Backstack<Url>,
-	pub history:   BTreeMap<Url, Folder>,
+	pub history:   IndexMap<Url, Folder>,
+	pub yanked:    (bool, HashSet<Url>),
+	pub elsewhere: BTreeMap<Url, File>,
 
 	pub preview:       Preview,
 	pub finder:        Option<Finder>,
@@ -32,12 +37,15 @@ impl From<Url> for Tab {
 
 			backstack: Backstack::new(url),
 			history: Default::default(),
+			yanked: Default::default(),
+			elsewhere: Default::default(),
 
 			preview: Default::default(),
 			finder: None,
 			search: None,
 
 			conf: Default::default(),
+			usage: None,
 		}
 	}
 }
@@ -76,7 +84,9 @@ impl Tab {
 impl Tab {
 	// --- Mode
 	#[inline]
-	pub fn in_selecting(&self) -> bool { self.mode.is_visual() || self.current.files.has_selected() }
+	pub fn in_selecting(&self) -> bool {
+		self.mode.is_visual() || self.current.files.has_selected() || !self.elsewhere.is_empty()
+	}
 
 	// --- Current
 	pub fn selected(&self) -> Vec<&File> {
@@ -90,13 +100,100 @@ impl Tab {
 		}
 	}
 
+	/// Like [`Self::selected`], but with the files selected in other folders as
+	/// well, only for the commands that gather or list them, i.e. `yank` and
+	/// `remove`, since the rest would act on files out of sight.
+	pub fn selected_all(&self) -> Vec<&File> {
+		let pending = self.mode.visual().map(|(_, p)| Cow::Borrowed(p)).unwrap_or_default();
+		let mut selected = self.current.files.selected(&pending, self.mode.is_unset());
+		selected.extend(self.selected_elsewhere());
+
+		if selected.is_empty() {
+			self.current.hovered().map(|h| vec![h]).unwrap_or_default()
+		} else {
+			selected
+		}
+	}
+
+	/// Selected files that live in folders other than the current one, so that a
+	/// selection can be built up across directories before yanking it.
+	#[inline]
+	pub fn selected_elsewhere(&self) -> impl Iterator<Item = &File> { self.elsewhere.values() }
+
+	// The selection of the folder left behind is kept by path, and the part of
+	// it in the folder coming in is given back to its files
+	pub(super) fn replace_current(&mut self, mut rep: Folder) -> Folder {
+		for file in self.current.files.selected(&Default::default(), false) {
+			self.elsewhere.insert(file.url(), file.clone());
+		}
+		self.current.files.select_all(Some(false));
+
+		let cwd = Some(&rep.cwd);
+		self.elsewhere.retain(|u, _| {
+			if u.parent_url().as_ref() != cwd {
+				return true;
+			}
+			rep.files.select(u, Some(true));
+			false
+		});
+		mem::replace(&mut self.current, rep)
+	}
+
 	// --- History
 	#[inline]
 	pub fn history(&self, url: &Url) -> Option<&Folder> { self.history.get(url) }
 
-	#[inline]
+	// With the cursor and the offset it had when put into the history
 	pub fn history_new(&mut self, url: &Url) -> Folder {
-		self.history.remove(url).unwrap_or_else(|| Folder::from(url))
+		let Some(mut folder) = self.history.shift_remove(url) else {
+			return Folder::from(url);
+		};
+		folder.restore_viewport();
+		folder
+	}
+
+	// Kept as the most recently used one
+	pub fn history_put(&mut self, mut folder: Folder) {
+		folder.save_viewport();
+		self.history.shift_remove(&folder.cwd);
+		self.history.insert(folder.cwd.clone(), folder);
+		self.history_evict();
+	}
+
+	// Made if not there yet, and moved to be the most recently used one
+	pub fn history_touch(&mut self, url: &Url) -> &mut Folder {
+		match self.history.get_index_of(url) {
+			Some(i) => self.history.move_index(i, self.history.len() - 1),
+			None => {
+				self.history.insert(url.clone(), Folder::from(url));
+				self.history_evict();
+			}
+		}
+		let last = self.history.len() - 1;
+		&mut self.history[last]
+	}
+
+	// Drop the folders and the selection of `url` and everything under it, as
+	// they're gone
+	pub fn history_forget(&mut self, url: &Url) {
+		self.history.retain(|u, _| !u.starts_with(url));
+		self.elsewhere.retain(|u, _| !u.starts_with(url));
+	}
+
+	// The least recently used folders go first
+	fn history_evict(&mut self) {
+		let mut over = self.history.len().saturating_sub(MANAGER.history_max);
+		if over == 0 {
+			return;
+		}
+
+		self.history.retain(|_, _| {
+			if over == 0 {
+				return true;
+			}
+			over -= 1;
+			false
+		});
 	}
 
 	pub fn apply_files_attrs(&mut self, just_preview: bool) -> bool {
@@ -104,6 +201,7 @@ impl Tab {
 			let hovered = f.hovered().map(|h| h.url());
 
 			let mut b = f.files.set_show_hidden(self.conf.show_hidden);
+			b |= f.files.set_show_ignored(self.conf.show_ignored);
 			b |= f.files.set_sorter(self.conf.sorter());
 			b | f.repos(hovered)
 		};
@@ -3,6 +3,7 @@ use std::collections::BTreeMap;
 use futures::future::BoxFuture;
 
 use super::{Task, TaskStage};
+use crate::{EventLog, Hooks};
 
 #[derive(Default)]
 pub(super) struct Running {
@@ -16,6 +17,7 @@ pub(super) struct Running {
 impl Running {
 	pub(super) fn add(&mut self, name: String) -> usize {
 		self.incr += 1;
+		EventLog::task_start(self.incr, &name);
 		self.all.insert(self.incr, Task::new(self.incr, name));
 		self.incr
 	}
@@ -64,7 +66,10 @@ impl Running {
 				TaskStage::Hooked => {}
 			}
 
-			self.all.remove(&id);
+			if let Some(task) = self.all.remove(&id) {
+				EventLog::task_end(id, &task.name, task.fail, false);
+				Hooks::task(&task.name, task.fail, false);
+			}
 		}
 		None
 	}
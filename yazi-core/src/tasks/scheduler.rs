@@ -1,13 +1,13 @@
-use std::{ffi::OsStr, sync::Arc, time::Duration};
+use std::{ffi::OsStr, sync::{atomic::Ordering, Arc}, time::Duration};
 
 use futures::{future::BoxFuture, FutureExt};
 use parking_lot::RwLock;
 use tokio::{fs, select, sync::{mpsc::{self, UnboundedReceiver}, oneshot}, time::sleep};
-use yazi_config::{open::Opener, TASKS};
+use yazi_config::{open::Opener, tasks::{Normalize, PasteOrder}, TASKS};
 use yazi_shared::{unique_path, Throttle, Url};
 
-use super::{workers::{File, FileOpDelete, FileOpLink, FileOpPaste, FileOpTrash, Precache, PrecacheOpMime, PrecacheOpSize, Process, ProcessOpOpen}, Running, TaskOp, TaskStage, TasksProgress};
-use crate::emit;
+use super::{workers::{File, FileOpDelete, FileOpDownload, FileOpExtract, FileOpHardlink, FileOpLink, FileOpNormalize, FileOpOwn, FileOpPaste, FileOpTrash, FileOpVerify, Precache, PrecacheOpMime, PrecacheOpSize, PrecacheOpThumbs, Process, ProcessOpOpen}, Running, TaskOp, TaskStage, TasksProgress};
+use crate::{emit, EventLog, Hooks};
 
 pub struct Scheduler {
 	file:     Arc<File>,
@@ -165,15 +165,18 @@ impl Scheduler {
 
 	pub(super) fn cancel(&self, id: usize) -> bool {
 		let mut running = self.running.write();
-		let b = running.all.remove(&id).is_some();
+		let b = running.all.remove(&id).map(|t| {
+			EventLog::task_end(id, &t.name, t.fail, true);
+			Hooks::task(&t.name, t.fail, true);
+		});
 
 		if let Some(hook) = running.hooks.remove(&id) {
 			self.todo.send_blocking(hook(true)).ok();
 		}
-		b
+		b.is_some()
 	}
 
-	pub(super) fn file_cut(&self, from: Url, mut to: Url, force: bool) {
+	pub(super) fn file_cut(&self, from: Url, mut to: Url, force: bool, order: PasteOrder) {
 		let mut running = self.running.write();
 		let id = running.add(format!("Cut {:?} to {:?}", from, to));
 
@@ -198,13 +201,16 @@ impl Scheduler {
 				if !force {
 					to = unique_path(to).await;
 				}
-				file.paste(FileOpPaste { id, from, to, cut: true, follow: false, retry: 0 }).await.ok();
+				file
+					.paste(FileOpPaste { id, from, to, cut: true, follow: false, retry: 0, order })
+					.await
+					.ok();
 			}
 			.boxed()
 		});
 	}
 
-	pub(super) fn file_copy(&self, from: Url, mut to: Url, force: bool) {
+	pub(super) fn file_copy(&self, from: Url, mut to: Url, force: bool, order: PasteOrder) {
 		let name = format!("Copy {:?} to {:?}", from, to);
 		let id = self.running.write().add(name);
 
@@ -214,7 +220,10 @@ impl Scheduler {
 				if !force {
 					to = unique_path(to).await;
 				}
-				file.paste(FileOpPaste { id, from, to, cut: false, follow: true, retry: 0 }).await.ok();
+				file
+					.paste(FileOpPaste { id, from, to, cut: false, follow: true, retry: 0, order })
+					.await
+					.ok();
 			}
 			.boxed()
 		});
@@ -239,6 +248,22 @@ impl Scheduler {
 		});
 	}
 
+	pub(super) fn file_hardlink(&self, from: Url, mut to: Url, force: bool) {
+		let name = format!("Hardlink {from:?} to {to:?}");
+		let id = self.running.write().add(name);
+
+		_ = self.todo.send_blocking({
+			let file = self.file.clone();
+			async move {
+				if !force {
+					to = unique_path(to).await;
+				}
+				file.hardlink(FileOpHardlink { id, from, to }).await.ok();
+			}
+			.boxed()
+		});
+	}
+
 	pub(super) fn file_delete(&self, target: Url) {
 		let mut running = self.running.write();
 		let id = running.add(format!("Delete {:?}", target));
@@ -280,7 +305,146 @@ impl Scheduler {
 		});
 	}
 
-	pub(super) fn process_open(&self, opener: &Opener, args: &[impl AsRef<OsStr>]) {
+	pub(super) fn file_normalize(&self, target: Url, preset: &Normalize, dry_run: bool) {
+		let name = format!(
+			"Normalize {:?} with `{}`{}",
+			target,
+			preset.name,
+			if dry_run { " (dry run)" } else { "" }
+		);
+		let id = self.running.write().add(name);
+
+		#[cfg(unix)]
+		let Some((uid, gid)) = self.owner_ids(id, preset.owner()) else {
+			return;
+		};
+		#[cfg(windows)]
+		let (uid, gid) = (None, None);
+
+		_ = self.todo.send_blocking({
+			let file = self.file.clone();
+			let (dir_mode, file_mode, strip_setuid) =
+				(preset.dir_mode, preset.file_mode, preset.strip_setuid);
+			async move {
+				file
+					.normalize(FileOpNormalize {
+						id,
+						target,
+						dir_mode,
+						file_mode,
+						strip_setuid,
+						uid,
+						gid,
+						dry_run,
+					})
+					.await
+					.ok();
+			}
+			.boxed()
+		});
+	}
+
+	// Look up the IDs of the user and group given by name or number, failing
+	// the task if either of them doesn't exist
+	#[cfg(unix)]
+	fn owner_ids(
+		&self,
+		id: usize,
+		(user, group): (Option<&str>, Option<&str>),
+	) -> Option<(Option<u32>, Option<u32>)> {
+		let user = user.map(|u| (u, yazi_shared::user_id(u)));
+		let group = group.map(|g| (g, yazi_shared::group_id(g)));
+		match (user, group) {
+			(Some((u, None)), _) | (_, Some((u, None))) => {
+				self.prog.send(TaskOp::New(id, 0)).ok();
+				self.prog.send(TaskOp::Fail(id, format!("No such user or group: {u}"))).ok();
+				None
+			}
+			(user, group) => Some((user.and_then(|u| u.1), group.and_then(|g| g.1))),
+		}
+	}
+
+	pub(super) fn file_own(
+		&self,
+		target: Url,
+		user: Option<&str>,
+		group: Option<&str>,
+		recursive: bool,
+	) {
+		let name = format!(
+			"Change owner of {:?} to {}:{}{}",
+			target,
+			user.unwrap_or_default(),
+			group.unwrap_or_default(),
+			if recursive { " (recursive)" } else { "" }
+		);
+		let id = self.running.write().add(name);
+
+		#[cfg(unix)]
+		let Some((uid, gid)) = self.owner_ids(id, (user, group)) else {
+			return;
+		};
+		#[cfg(windows)]
+		let (uid, gid) = (None, None);
+
+		_ = self.todo.send_blocking({
+			let file = self.file.clone();
+			async move {
+				file.own(FileOpOwn { id, target, uid, gid, recursive }).await.ok();
+			}
+			.boxed()
+		});
+	}
+
+	pub(super) fn file_download(&self, url: String, dest: Url) {
+		let name = format!("Download {url}");
+		let id = self.running.write().add(name);
+
+		_ = self.todo.send_blocking({
+			let (file, prog) = (self.file.clone(), self.prog.clone());
+			async move {
+				let task = FileOpDownload { id, url, target: dest, part: Url::default(), length: 0 };
+				if let Err(e) = file.download(task).await {
+					prog.send(TaskOp::New(id, 0)).ok();
+					prog.send(TaskOp::Fail(id, format!("Failed to download: {e}"))).ok();
+				}
+			}
+			.boxed()
+		});
+	}
+
+	pub(super) fn file_verify(&self, target: Url) {
+		let name = format!("Verify {:?}", target);
+		let id = self.running.write().add(name);
+
+		_ = self.todo.send_blocking({
+			let file = self.file.clone();
+			async move {
+				file.verify(FileOpVerify { id, target }).await.ok();
+			}
+			.boxed()
+		});
+	}
+
+	pub(super) fn file_extract(&self, target: Url, dest: Url, subdir: Option<bool>) {
+		let name = format!("Extract {:?} to {:?}", target, dest);
+		let id = self.running.write().add(name);
+
+		_ = self.todo.send_blocking({
+			let file = self.file.clone();
+			async move {
+				file.extract(FileOpExtract { id, target, dest, subdir }).await.ok();
+			}
+			.boxed()
+		});
+	}
+
+	pub(super) fn process_open(
+		&self,
+		opener: &Opener,
+		args: &[impl AsRef<OsStr>],
+		input: Option<Vec<u8>>,
+	) {
 		let name = {
 			let s = format!("Execute `{}`", opener.exec);
 			let args = args.iter().map(|a| a.as_ref().to_string_lossy()).collect::<Vec<_>>().join(" ");
@@ -313,9 +477,11 @@ impl Scheduler {
 					.open(ProcessOpOpen {
 						id,
 						cmd: opener.exec.into(),
+						arg0: opener.arg0.unwrap_or_default(),
 						args,
 						block: opener.block,
 						orphan: opener.orphan,
+						input,
 						cancel: cancel_tx,
 					})
 					.await
@@ -349,14 +515,34 @@ impl Scheduler {
 		}
 	}
 
-	pub(super) fn precache_mime(&self, targets: Vec<Url>) {
+	pub(super) fn precache_mime(&self, targets: Vec<Url>, page: usize, viewing: usize) {
+		self.precache.mime_page.store(viewing, Ordering::Relaxed);
+		if targets.is_empty() {
+			return;
+		}
+
 		let name = format!("Preload mimetype for {} files", targets.len());
 		let id = self.running.write().add(name);
 
 		_ = self.todo.send_blocking({
 			let precache = self.precache.clone();
 			async move {
-				precache.mime(PrecacheOpMime { id, targets }).await.ok();
+				precache.mime(PrecacheOpMime { id, page, targets }).await.ok();
+			}
+			.boxed()
+		});
+	}
+
+	pub(super) fn precache_thumbs(&self, root: Url, import: bool) {
+		let name = format!("{} preview cache of {:?}", if import { "Import" } else { "Export" }, root);
+		let id = self.running.write().add(name);
+
+		_ = self.todo.send_blocking({
+			let (precache, prog) = (self.precache.clone(), self.prog.clone());
+			async move {
+				if let Err(e) = precache.thumbs(PrecacheOpThumbs { id, root, import }).await {
+					prog.send(TaskOp::Fail(id, format!("Failed to work on this task: {e}"))).ok();
+				}
 			}
 			.boxed()
 		});
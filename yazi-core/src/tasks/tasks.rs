@@ -1,12 +1,13 @@
-use std::{collections::{BTreeMap, HashMap, HashSet}, ffi::OsStr, path::Path, sync::Arc};
+use std::{cmp::Reverse, collections::{BTreeMap, HashSet}, ffi::OsStr, path::Path, sync::Arc};
 
 use serde::Serialize;
+use tokio::fs;
 use tracing::debug;
-use yazi_config::{manager::SortBy, open::Opener, popup::InputOpt, OPEN};
-use yazi_shared::{MimeKind, Term, Url};
+use yazi_config::{manager::SortBy, open::{Extract, Opener}, popup::{ConfirmOpt, InputOpt}, tasks::{Normalize, PasteOrder}, OPEN};
+use yazi_shared::{expand_path, relative_link_src, MimeKind, Term, Url};
 
 use super::{running::Running, task::TaskSummary, Scheduler, TASKS_PADDING, TASKS_PERCENT};
-use crate::{emit, files::{File, Files}};
+use crate::{emit, files::Files, input::Input, manager::Mimetype, tab::Folder};
 
 pub struct Tasks {
 	pub(super) scheduler: Arc<Scheduler>,
@@ -50,53 +51,160 @@ impl Tasks {
 	}
 
 	pub fn file_open_with(&self, opener: &Opener, args: &[impl AsRef<OsStr>]) -> bool {
+		if let Some(extract) = opener.extract {
+			let targets = args.iter().map(|a| Url::from(Path::new(a))).collect();
+			return self.file_extract(targets, extract);
+		}
 		if opener.spread {
-			self.scheduler.process_open(opener, args);
+			self.scheduler.process_open(opener, args, None);
 			return false;
 		}
 		for target in args {
-			self.scheduler.process_open(opener, &[target]);
+			self.scheduler.process_open(opener, &[target], None);
 		}
 		false
 	}
 
-	pub fn file_cut(&self, src: &HashSet<Url>, dest: &Url, force: bool) -> bool {
-		for u in src {
-			let to = dest.join(u.file_name().unwrap());
-			if force && u == &to {
-				debug!("file_cut: same file, skipping {:?}", to);
-			} else {
-				self.scheduler.file_cut(u.clone(), to, force);
+	// Run the prompted command with `input` as its stdin
+	pub fn process_pipe(&self, input: Vec<u8>) -> bool {
+		let scheduler = self.scheduler.clone();
+		tokio::spawn(async move {
+			let mut result = emit!(Input(InputOpt::export(true)));
+			let Some(Ok(cmd)) = result.recv().await else {
+				return;
+			};
+			if cmd.trim().is_empty() {
+				return;
 			}
-		}
+
+			let opener = Opener {
+				exec:    cmd,
+				block:   false,
+				orphan:  false,
+				desc:    Default::default(),
+				for_:    None,
+				spread:  true,
+				extract: None,
+				arg0:    None,
+			};
+			scheduler.process_open(&opener, &[] as &[&OsStr], Some(input));
+		});
 		false
 	}
 
-	pub fn file_copy(&self, src: &HashSet<Url>, dest: &Url, force: bool) -> bool {
+	pub fn file_cut(&self, src: &HashSet<Url>, dest: &Url, force: bool, order: PasteOrder) -> bool {
+		let (scheduler, dest) = (self.scheduler.clone(), dest.clone());
+		let src = src.iter().cloned().collect();
+		tokio::spawn(async move {
+			for u in Self::paste_order(src, order).await {
+				let to = dest.join(u.file_name().unwrap());
+				if force && u == to {
+					debug!("file_cut: same file, skipping {:?}", to);
+				} else {
+					scheduler.file_cut(u, to, force, order);
+				}
+			}
+		});
+		false
+	}
+
+	pub fn file_copy(&self, src: &HashSet<Url>, dest: &Url, force: bool, order: PasteOrder) -> bool {
+		let (scheduler, dest) = (self.scheduler.clone(), dest.clone());
+		let src = src.iter().cloned().collect();
+		tokio::spawn(async move {
+			for u in Self::paste_order(src, order).await {
+				let to = dest.join(u.file_name().unwrap());
+				if force && u == to {
+					debug!("file_copy: same file, skipping {:?}", to);
+				} else {
+					scheduler.file_copy(u, to, force, order);
+				}
+			}
+		});
+		false
+	}
+
+	// The yanked files are pasted in the order of their paths, or of their sizes
+	// like the files inside the directories are, with the paths breaking ties
+	async fn paste_order(mut src: Vec<Url>, order: PasteOrder) -> Vec<Url> {
+		src.sort_unstable();
+		if order == PasteOrder::Dir {
+			return src;
+		}
+
+		let mut sized = Vec::with_capacity(src.len());
+		for u in src {
+			let len = fs::metadata(&u).await.map(|m| m.len()).unwrap_or(0);
+			sized.push((len, u));
+		}
+		match order {
+			PasteOrder::Dir => {}
+			PasteOrder::Smallest => sized.sort_by_key(|&(len, _)| len),
+			PasteOrder::Largest => sized.sort_by_key(|&(len, _)| Reverse(len)),
+		}
+		sized.into_iter().map(|(_, u)| u).collect()
+	}
+
+	pub fn file_link(&self, src: &HashSet<Url>, dest: &Url, relative: bool, force: bool) -> bool {
+		let mut todo = Vec::with_capacity(src.len());
 		for u in src {
 			let to = dest.join(u.file_name().unwrap());
-			if force && u == &to {
-				debug!("file_copy: same file, skipping {:?}", to);
+			if force && *u == to {
+				debug!("file_link: same file, skipping {:?}", to);
 			} else {
-				self.scheduler.file_copy(u.clone(), to, force);
+				todo.push((u.clone(), to));
 			}
 		}
+
+		if todo.len() < 2 {
+			for (from, to) in todo {
+				self.scheduler.file_link(from, to, relative, force);
+			}
+			return false;
+		}
+
+		let scheduler = self.scheduler.clone();
+		tokio::spawn(async move {
+			let (from, to) = &todo[0];
+			let target = if relative {
+				relative_link_src(from, to).await.unwrap_or_else(|_| from.to_path_buf())
+			} else {
+				from.to_path_buf()
+			};
+
+			let mut result = emit!(Input(InputOpt::link(todo.len(), &target)));
+			if let Some(Ok(choice)) = result.recv().await {
+				if choice != "y" && choice != "Y" {
+					return;
+				}
+				for (from, to) in todo {
+					scheduler.file_link(from, to, relative, force);
+				}
+			}
+		});
 		false
 	}
 
-	pub fn file_link(&self, src: &HashSet<Url>, dest: &Url, relative: bool, force: bool) -> bool {
+	pub fn file_hardlink(&self, src: &HashSet<Url>, dest: &Url, force: bool) -> bool {
 		for u in src {
 			let to = dest.join(u.file_name().unwrap());
 			if force && *u == to {
-				debug!("file_link: same file, skipping {:?}", to);
+				debug!("file_hardlink: same file, skipping {:?}", to);
 			} else {
-				self.scheduler.file_link(u.clone(), to, relative, force);
+				self.scheduler.file_hardlink(u.clone(), to, force);
 			}
 		}
 		false
 	}
 
-	pub fn file_remove(&self, targets: Vec<Url>, force: bool, permanently: bool) -> bool {
+	// The targets at the `marked` indices are highlighted in the confirm
+	pub fn file_remove(
+		&self,
+		targets: Vec<Url>,
+		marked: Vec<usize>,
+		force: bool,
+		permanently: bool,
+	) -> bool {
 		if force {
 			for u in targets {
 				if permanently {
@@ -110,28 +218,119 @@ impl Tasks {
 
 		let scheduler = self.scheduler.clone();
 		tokio::spawn(async move {
-			let mut result = emit!(Input(if permanently {
-				InputOpt::delete(targets.len())
-			} else {
-				InputOpt::trash(targets.len())
-			}));
+			let opt =
+				if permanently { ConfirmOpt::delete(&targets) } else { ConfirmOpt::trash(&targets) };
 
-			if let Some(Ok(choice)) = result.recv().await {
-				if choice != "y" && choice != "Y" {
+			let confirmed = emit!(Confirm(opt.with_marked(marked))).await;
+
+			if !confirmed {
+				return;
+			}
+			for u in targets {
+				if permanently {
+					scheduler.file_delete(u);
+				} else {
+					scheduler.file_trash(u);
+				}
+			}
+		});
+		false
+	}
+
+	pub fn file_normalize(&self, targets: Vec<Url>, preset: &Normalize, dry_run: bool) -> bool {
+		for u in targets {
+			self.scheduler.file_normalize(u, preset, dry_run);
+		}
+		false
+	}
+
+	// The owner is given as `user:group`, where either part can be left out
+	pub fn file_own(&self, targets: Vec<Url>, owner: &str, recursive: bool) -> bool {
+		let (user, group) = owner.split_once(':').unwrap_or((owner, ""));
+		let (user, group) = (user.trim(), group.trim());
+		if user.is_empty() && group.is_empty() {
+			return false;
+		}
+
+		let user = Some(user).filter(|s| !s.is_empty());
+		let group = Some(group).filter(|s| !s.is_empty());
+		for u in targets {
+			self.scheduler.file_own(u, user, group, recursive);
+		}
+		false
+	}
+
+	pub fn file_download(&self, url: Option<String>, dest: &Url) -> bool {
+		if let Some(url) = url {
+			self.scheduler.file_download(url, dest.clone());
+			return false;
+		}
+
+		let (scheduler, dest) = (self.scheduler.clone(), dest.clone());
+		tokio::spawn(async move {
+			let mut result = emit!(Input(InputOpt::download()));
+			if let Some(Ok(url)) = result.recv().await {
+				let url = url.trim();
+				if !url.is_empty() {
+					scheduler.file_download(url.to_owned(), dest);
+				}
+			}
+		});
+		false
+	}
+
+	pub fn file_extract(&self, targets: Vec<Url>, extract: Extract) -> bool {
+		let dest = |u: &Url| u.parent_url().unwrap_or_else(|| u.clone());
+		if extract != Extract::Prompt {
+			for u in targets {
+				let dest = dest(&u);
+				self.scheduler.file_extract(u, dest, Some(extract == Extract::Subdir));
+			}
+			return false;
+		}
+
+		let Some(first) = targets.first() else {
+			return false;
+		};
+
+		let scheduler = self.scheduler.clone();
+		let value = dest(first).to_string_lossy().into_owned();
+		tokio::spawn(async move {
+			let mut result = emit!(Input(InputOpt::extract().with_value(value)));
+			if let Some(dest) = Input::_recv(&mut result).await {
+				if dest.trim().is_empty() {
 					return;
 				}
+				let dest = Url::from(expand_path(dest.trim()));
 				for u in targets {
-					if permanently {
-						scheduler.file_delete(u);
-					} else {
-						scheduler.file_trash(u);
-					}
+					scheduler.file_extract(u, dest.clone(), None);
 				}
 			}
 		});
 		false
 	}
 
+	pub fn file_verify(&self, targets: Vec<Url>) -> bool {
+		for u in targets {
+			self.scheduler.file_verify(u);
+		}
+		false
+	}
+
+	#[inline]
+	pub fn precache_thumbs(&self, root: &Url, import: bool) -> bool {
+		self.scheduler.precache_thumbs(root.clone(), import);
+		false
+	}
+
+	#[inline]
+	pub fn file_size(&self, targets: Vec<&Url>) -> bool {
+		if !targets.is_empty() {
+			self.scheduler.precache_size(targets);
+		}
+		false
+	}
+
 	#[inline]
 	pub fn precache_size(&self, targets: &Files) -> bool {
 		if targets.sorter().by != SortBy::Size {
@@ -144,24 +343,19 @@ impl Tasks {
 			.map(|f| &f.url)
 			.collect();
 
-		if !targets.is_empty() {
-			self.scheduler.precache_size(targets);
-		}
-
-		false
+		self.file_size(targets)
 	}
 
 	#[inline]
-	pub fn precache_mime(&self, targets: &[File], mimetype: &HashMap<Url, String>) -> bool {
-		let targets: Vec<_> = targets
+	pub fn precache_mime(&self, folder: &Folder, page: usize, mimetype: &Mimetype) -> bool {
+		let targets: Vec<_> = folder
+			.paginate(page)
 			.iter()
-			.filter(|f| !f.is_dir() && !mimetype.contains_key(&f.url))
+			.filter(|f| !f.is_dir() && !mimetype.contains(&f.url))
 			.map(|f| f.url())
 			.collect();
 
-		if !targets.is_empty() {
-			self.scheduler.precache_mime(targets);
-		}
+		self.scheduler.precache_mime(targets, page, folder.page);
 		false
 	}
 
@@ -208,6 +402,11 @@ impl Tasks {
 impl Tasks {
 	#[inline]
 	pub fn len(&self) -> usize { self.scheduler.running.read().len() }
+
+	#[inline]
+	pub fn names(&self) -> Vec<String> {
+		self.scheduler.running.read().values().map(|t| t.name.clone()).collect()
+	}
 }
 
 #[derive(Clone, Copy, Default, Eq, PartialEq, Serialize)]
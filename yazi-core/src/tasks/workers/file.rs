@@ -1,13 +1,16 @@
-use std::{borrow::Cow, collections::VecDeque, fs::Metadata, path::{Path, PathBuf}};
+use std::{borrow::Cow, cmp::Reverse, collections::VecDeque, fs::Metadata, mem, path::{Path, PathBuf}, time::Duration};
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use futures::{future::BoxFuture, FutureExt};
-use tokio::{fs, io::{self, ErrorKind::{AlreadyExists, NotFound}}, sync::mpsc};
+use md5::{Digest, Md5};
+use tokio::{fs, io::{self, AsyncReadExt, ErrorKind::{AlreadyExists, NotFound}}, select, sync::mpsc, time::sleep};
 use tracing::warn;
-use yazi_config::TASKS;
-use yazi_shared::{calculate_size, copy_with_progress, path_relative_to, Url};
+use yazi_config::{tasks::PasteOrder, TASKS};
+use yazi_shared::{calculate_size, copy_with_progress, relative_link_src, unique_path, Url};
 
-use crate::tasks::TaskOp;
+use crate::{external, tasks::TaskOp};
+
+const TINY_SIZE: u64 = 64 * 1024;
 
 pub(crate) struct File {
 	tx: async_channel::Sender<FileOp>,
@@ -19,9 +22,16 @@ pub(crate) struct File {
 #[derive(Debug)]
 pub(crate) enum FileOp {
 	Paste(FileOpPaste),
+	PasteBatch(Vec<FileOpPaste>),
 	Link(FileOpLink),
+	Hardlink(FileOpHardlink),
 	Delete(FileOpDelete),
 	Trash(FileOpTrash),
+	Normalize(FileOpNormalize),
+	Own(FileOpOwn),
+	Verify(FileOpVerify),
+	Download(FileOpDownload),
+	Extract(FileOpExtract),
 }
 
 #[derive(Clone, Debug)]
@@ -32,6 +42,7 @@ pub(crate) struct FileOpPaste {
 	pub cut:    bool,
 	pub follow: bool,
 	pub retry:  u8,
+	pub order:  PasteOrder,
 }
 
 #[derive(Clone, Debug)]
@@ -45,6 +56,13 @@ pub(crate) struct FileOpLink {
 	pub delete:   bool,
 }
 
+#[derive(Clone, Debug)]
+pub(crate) struct FileOpHardlink {
+	pub id:   usize,
+	pub from: Url,
+	pub to:   Url,
+}
+
 #[derive(Clone, Debug)]
 pub(crate) struct FileOpDelete {
 	pub id:     usize,
@@ -59,6 +77,50 @@ pub(crate) struct FileOpTrash {
 	pub length: u64,
 }
 
+#[derive(Clone, Debug)]
+pub(crate) struct FileOpNormalize {
+	pub id:           usize,
+	pub target:       Url,
+	pub dir_mode:     Option<u32>,
+	pub file_mode:    Option<u32>,
+	pub strip_setuid: bool,
+	pub uid:          Option<u32>,
+	pub gid:          Option<u32>,
+	pub dry_run:      bool,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct FileOpOwn {
+	pub id:        usize,
+	pub target:    Url,
+	pub uid:       Option<u32>,
+	pub gid:       Option<u32>,
+	pub recursive: bool,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct FileOpVerify {
+	pub id:     usize,
+	pub target: Url,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct FileOpDownload {
+	pub id:     usize,
+	pub url:    String,
+	pub target: Url,
+	pub part:   Url,
+	pub length: u64,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct FileOpExtract {
+	pub id:     usize,
+	pub target: Url,
+	pub dest:   Url,
+	pub subdir: Option<bool>,
+}
+
 impl File {
 	pub(crate) fn new(sch: mpsc::UnboundedSender<TaskOp>) -> Self {
 		let (tx, rx) = async_channel::unbounded();
@@ -69,9 +131,16 @@ impl File {
 	pub(crate) async fn recv(&self) -> Result<(usize, FileOp)> {
 		Ok(match self.rx.recv().await? {
 			FileOp::Paste(t) => (t.id, FileOp::Paste(t)),
+			FileOp::PasteBatch(t) => (t[0].id, FileOp::PasteBatch(t)),
 			FileOp::Link(t) => (t.id, FileOp::Link(t)),
+			FileOp::Hardlink(t) => (t.id, FileOp::Hardlink(t)),
 			FileOp::Delete(t) => (t.id, FileOp::Delete(t)),
 			FileOp::Trash(t) => (t.id, FileOp::Trash(t)),
+			FileOp::Normalize(t) => (t.id, FileOp::Normalize(t)),
+			FileOp::Own(t) => (t.id, FileOp::Own(t)),
+			FileOp::Verify(t) => (t.id, FileOp::Verify(t)),
+			FileOp::Download(t) => (t.id, FileOp::Download(t)),
+			FileOp::Extract(t) => (t.id, FileOp::Extract(t)),
 		})
 	}
 
@@ -83,7 +152,7 @@ impl File {
 					_ => {}
 				}
 
-				let mut it = copy_with_progress(&task.from, &task.to);
+				let mut it = copy_with_progress(&task.from, &task.to, Self::parallelism(task).await);
 				while let Some(res) = it.recv().await {
 					match res {
 						Ok(0) => {
@@ -112,6 +181,28 @@ impl File {
 				}
 				self.sch.send(TaskOp::Adv(task.id, 1, 0))?;
 			}
+			FileOp::PasteBatch(tasks) => {
+				let (mut succ, mut bytes) = (0, 0);
+				for task in tasks.iter() {
+					fs::remove_file(&task.to).await.ok();
+					match fs::copy(&task.from, &task.to).await {
+						Ok(n) => {
+							if task.cut {
+								fs::remove_file(&task.from).await.ok();
+							}
+							succ += 1;
+							bytes += n;
+						}
+						Err(e) if e.kind() == NotFound => {
+							warn!("Paste task partially done: {:?}", task);
+							succ += 1;
+						}
+						// Leave the rest of the error handling to a separate task
+						Err(_) => self.tx.send(FileOp::Paste(task.clone())).await?,
+					}
+				}
+				self.sch.send(TaskOp::Adv(tasks[0].id, succ, bytes))?;
+			}
 			FileOp::Link(task) => {
 				let meta = task.meta.as_ref().unwrap();
 
@@ -129,7 +220,8 @@ impl File {
 				};
 
 				let src = if task.relative {
-					path_relative_to(&src, &fs::canonicalize(task.to.parent().unwrap()).await?)
+					let src = task.from.parent().unwrap().join(src); // No-op if `src` is already absolute
+					Cow::Owned(relative_link_src(&src, &task.to).await?)
 				} else {
 					src
 				};
@@ -157,6 +249,32 @@ impl File {
 				}
 				self.sch.send(TaskOp::Adv(task.id, 1, meta.len()))?;
 			}
+			FileOp::Hardlink(task) => {
+				// Link under a free name first, and only then replace an existing `to`,
+				// so it's kept if the linking fails
+				let tmp = unique_path(task.to.clone()).await;
+				match fs::hard_link(&task.from, &tmp).await {
+					Ok(_) => {}
+					Err(e) if e.kind() == NotFound => {
+						warn!("Hardlink task partially done: {:?}", task);
+						return Ok(self.sch.send(TaskOp::Adv(task.id, 1, 0))?);
+					}
+					// Invalid cross-device link (os error 18)
+					// The system cannot move the file to a different disk drive (os error 17)
+					Err(e) if e.raw_os_error() == Some(if cfg!(windows) { 17 } else { 18 }) => {
+						Err(anyhow!("Hardlinks can't cross filesystems, {:?} is on another one", task.from))?
+					}
+					Err(e) => Err(e)?,
+				}
+
+				if tmp != task.to {
+					let result = fs::rename(&tmp, &task.to).await;
+					// Renaming does nothing if both are links to the same file already
+					fs::remove_file(&tmp).await.ok();
+					result?;
+				}
+				self.sch.send(TaskOp::Adv(task.id, 1, 0))?;
+			}
 			FileOp::Delete(task) => {
 				if let Err(e) = fs::remove_file(&task.target).await {
 					if e.kind() != NotFound && fs::symlink_metadata(&task.target).await.is_ok() {
@@ -180,6 +298,87 @@ impl File {
 				}
 				self.sch.send(TaskOp::Adv(task.id, 1, task.length))?;
 			}
+			FileOp::Normalize(task) => {
+				#[cfg(unix)]
+				{
+					use std::os::unix::fs::{lchown, MetadataExt, PermissionsExt};
+
+					let meta = fs::symlink_metadata(&task.target).await?;
+					let uid = task.uid.filter(|&u| u != meta.uid());
+					let gid = task.gid.filter(|&g| g != meta.gid());
+					if uid.is_some() || gid.is_some() {
+						if task.dry_run {
+							self.log(
+								task.id,
+								format!("Would change owner of {:?} to {uid:?}:{gid:?}", task.target),
+							)?;
+						} else {
+							lchown(&task.target, uid, gid)?;
+						}
+					}
+
+					let old = meta.permissions().mode() & 0o7777;
+					let new = task.mode(old, meta.is_dir());
+					if !meta.is_symlink() && new != old {
+						if task.dry_run {
+							self.log(
+								task.id,
+								format!("Would change mode of {:?}: {old:o} -> {new:o}", task.target),
+							)?;
+						} else {
+							fs::set_permissions(&task.target, std::fs::Permissions::from_mode(new)).await?;
+						}
+					}
+				}
+				self.sch.send(TaskOp::Adv(task.id, 1, 0))?;
+			}
+			FileOp::Own(task) => {
+				#[cfg(unix)]
+				std::os::unix::fs::lchown(&task.target, task.uid, task.gid)?;
+				self.sch.send(TaskOp::Adv(task.id, 1, 0))?;
+			}
+			FileOp::Verify(task) => {
+				let problems = external::archive_test(&task.target).await?;
+				if problems.is_empty() {
+					return Ok(self.sch.send(TaskOp::Adv(task.id, 1, 0))?);
+				}
+				for line in problems {
+					self.log(task.id, line)?;
+				}
+				self.fail(task.id, format!("{:?} failed the integrity test", task.target))?;
+			}
+			FileOp::Download(task) => {
+				let size = || async { fs::metadata(&task.part).await.map_or(0, |m| m.len()) };
+				let mut done = size().await;
+				self.sch.send(TaskOp::Adv(task.id, 0, done))?;
+
+				let mut child = external::curl(&task.url, &task.part)?;
+				let status = loop {
+					select! {
+						status = child.wait() => break status?,
+						_ = sleep(Duration::from_millis(500)) => {}
+					}
+
+					let now = size().await;
+					self.sch.send(TaskOp::Adv(task.id, 0, now.saturating_sub(done)))?;
+					done = done.max(now);
+				};
+
+				let now = size().await;
+				if !status.success() {
+					let mut stderr = String::new();
+					child.stderr.take().unwrap().read_to_string(&mut stderr).await.ok();
+					Err(anyhow!("{}", stderr.trim()))?;
+				}
+
+				// Never over an existing file, which may have been created in the meantime
+				fs::rename(&task.part, unique_path(task.target.clone()).await).await?;
+				self.sch.send(TaskOp::Adv(task.id, 1, now.saturating_sub(done)))?;
+			}
+			FileOp::Extract(task) => {
+				external::unar(&task.target, &task.dest, task.subdir).await?;
+				self.sch.send(TaskOp::Adv(task.id, 1, 0))?;
+			}
 		}
 		Ok(())
 	}
@@ -221,7 +420,8 @@ impl File {
 
 		let root = task.to.clone();
 		let skip = task.from.components().count();
-		let mut dirs = VecDeque::from([task.from]);
+		let mut dirs = VecDeque::from([task.from.clone()]);
+		let (mut files, mut batch) = (vec![], vec![]);
 
 		while let Some(src) = dirs.pop_front() {
 			let dest = root.join(src.components().skip(skip).collect::<PathBuf>());
@@ -242,18 +442,96 @@ impl File {
 
 				task.to = dest.join(src.file_name().unwrap());
 				task.from = src;
-				self.sch.send(TaskOp::New(task.id, meta.len()))?;
+				if task.order == PasteOrder::Dir {
+					self.queue(task.clone(), meta, &mut batch).await?;
+				} else {
+					files.push((task.clone(), meta));
+				}
+			}
+		}
+
+		match task.order {
+			PasteOrder::Dir => {}
+			PasteOrder::Smallest => files.sort_by_key(|(_, m)| m.len()),
+			PasteOrder::Largest => files.sort_by_key(|(_, m)| Reverse(m.len())),
+		}
+		for (task, meta) in files {
+			self.queue(task, meta, &mut batch).await?;
+		}
+		if !batch.is_empty() {
+			self.tx.send(FileOp::PasteBatch(batch)).await?;
+		}
+		self.succ(task.id)
+	}
 
-				if meta.is_file() {
-					self.tx.send(FileOp::Paste(task.clone())).await?;
-				} else if meta.is_symlink() {
-					self.tx.send(FileOp::Link(task.to_link(meta))).await?;
+	// Directories can't be hardlinked, so they're made anew with the files in
+	// them hardlinked instead
+	pub(crate) async fn hardlink(&self, task: FileOpHardlink) -> Result<()> {
+		let meta = fs::symlink_metadata(&task.from).await?;
+		if !meta.is_dir() {
+			let id = task.id;
+			self.sch.send(TaskOp::New(id, 0))?;
+			self.tx.send(FileOp::Hardlink(task)).await?;
+			return self.succ(id);
+		}
+
+		let skip = task.from.components().count();
+		let mut dirs = VecDeque::from([task.from.clone()]);
+		while let Some(src) = dirs.pop_front() {
+			let dest = task.to.join(src.components().skip(skip).collect::<PathBuf>());
+			let result = match fs::create_dir(&dest).await {
+				Err(e) if e.kind() != AlreadyExists => Err(e),
+				_ => fs::read_dir(&src).await,
+			};
+
+			let mut it = match result {
+				Ok(it) => it,
+				Err(e) => {
+					self.sch.send(TaskOp::New(task.id, 0))?;
+					self.fail(task.id, format!("An error occurred while hardlinking: {e}"))?;
+					continue;
 				}
+			};
+
+			while let Ok(Some(entry)) = it.next_entry().await {
+				let src = Url::from(entry.path());
+				if entry.file_type().await.is_ok_and(|t| t.is_dir()) {
+					dirs.push_back(src);
+					continue;
+				}
+
+				self.sch.send(TaskOp::New(task.id, 0))?;
+				let to = dest.join(src.file_name().unwrap());
+				self.tx.send(FileOp::Hardlink(FileOpHardlink { id: task.id, from: src, to })).await?;
 			}
 		}
 		self.succ(task.id)
 	}
 
+	// Tiny files are collected into batches, to save the overhead of a task for
+	// each of them, which adds up on network filesystems.
+	async fn queue(
+		&self,
+		task: FileOpPaste,
+		meta: Metadata,
+		batch: &mut Vec<FileOpPaste>,
+	) -> Result<()> {
+		self.sch.send(TaskOp::New(task.id, meta.len()))?;
+		if meta.is_symlink() {
+			return Ok(self.tx.send(FileOp::Link(task.to_link(meta))).await?);
+		} else if !meta.is_file() {
+			return Ok(());
+		} else if TASKS.paste_batch < 2 || meta.len() >= TINY_SIZE {
+			return Ok(self.tx.send(FileOp::Paste(task)).await?);
+		}
+
+		batch.push(task);
+		if batch.len() >= TASKS.paste_batch {
+			self.tx.send(FileOp::PasteBatch(mem::take(batch))).await?;
+		}
+		Ok(())
+	}
+
 	pub(crate) async fn link(&self, mut task: FileOpLink) -> Result<()> {
 		let id = task.id;
 		if task.meta.is_none() {
@@ -311,6 +589,136 @@ impl File {
 		self.succ(id)
 	}
 
+	pub(crate) async fn normalize(&self, mut task: FileOpNormalize) -> Result<()> {
+		let id = task.id;
+		let meta = fs::symlink_metadata(&task.target).await?;
+
+		self.sch.send(TaskOp::New(id, 0))?;
+		self.tx.send(FileOp::Normalize(task.clone())).await?;
+		if !meta.is_dir() {
+			return self.succ(id);
+		}
+
+		let mut dirs = VecDeque::from([task.target.clone()]);
+		while let Some(dir) = dirs.pop_front() {
+			let mut it = match fs::read_dir(dir).await {
+				Ok(it) => it,
+				Err(_) => continue,
+			};
+
+			while let Ok(Some(entry)) = it.next_entry().await {
+				let Ok(meta) = entry.metadata().await else {
+					continue;
+				};
+
+				task.target = Url::from(entry.path());
+				if meta.is_dir() {
+					dirs.push_back(task.target.clone());
+				}
+
+				self.sch.send(TaskOp::New(id, 0))?;
+				self.tx.send(FileOp::Normalize(task.clone())).await?;
+			}
+		}
+		self.succ(id)
+	}
+
+	pub(crate) async fn own(&self, mut task: FileOpOwn) -> Result<()> {
+		let id = task.id;
+		let meta = fs::symlink_metadata(&task.target).await?;
+
+		self.sch.send(TaskOp::New(id, 0))?;
+		self.tx.send(FileOp::Own(task.clone())).await?;
+		if !task.recursive || !meta.is_dir() {
+			return self.succ(id);
+		}
+
+		let mut dirs = VecDeque::from([task.target.clone()]);
+		while let Some(dir) = dirs.pop_front() {
+			let Ok(mut it) = fs::read_dir(dir).await else {
+				continue;
+			};
+
+			while let Ok(Some(entry)) = it.next_entry().await {
+				let Ok(file_type) = entry.file_type().await else {
+					continue;
+				};
+
+				task.target = Url::from(entry.path());
+				if file_type.is_dir() {
+					dirs.push_back(task.target.clone());
+				}
+
+				self.sch.send(TaskOp::New(id, 0))?;
+				self.tx.send(FileOp::Own(task.clone())).await?;
+			}
+		}
+		self.succ(id)
+	}
+
+	pub(crate) async fn verify(&self, task: FileOpVerify) -> Result<()> {
+		let id = task.id;
+		self.sch.send(TaskOp::New(id, 0))?;
+		self.tx.send(FileOp::Verify(task)).await?;
+		self.succ(id)
+	}
+
+	pub(crate) async fn extract(&self, task: FileOpExtract) -> Result<()> {
+		let id = task.id;
+		self.sch.send(TaskOp::New(id, 0))?;
+		self.tx.send(FileOp::Extract(task)).await?;
+		self.succ(id)
+	}
+
+	pub(crate) async fn download(&self, mut task: FileOpDownload) -> Result<()> {
+		let id = task.id;
+		let head = external::curl_head(&task.url).await?;
+
+		let name = head
+			.name
+			.or_else(|| {
+				let path = task.url.split(['?', '#']).next()?.split_once("://")?.1;
+				path.split_once('/').map(|(_, p)| p.rsplit('/').next().unwrap_or_default().to_owned())
+			})
+			.and_then(|n| Path::new(&n).file_name().map(|n| n.to_string_lossy().into_owned()))
+			.unwrap_or_else(|| "download".to_owned());
+
+		// The partial file is named after the URL, so only the same download resumes it
+		let hash = format!("{:x}", Md5::digest(&task.url));
+		task.part = task.target.join(format!("{name}.{}.part", &hash[..8]));
+		task.target = task.target.join(name);
+		task.length = head.length.unwrap_or(0);
+
+		self.sch.send(TaskOp::New(id, task.length))?;
+		self.tx.send(FileOp::Download(task)).await?;
+		self.succ(id)
+	}
+
+	// Ranged writers only pay off across filesystems, on the same one `fs::copy`
+	// can offload the work with `copy_file_range` or reflinks.
+	async fn parallelism(task: &FileOpPaste) -> usize {
+		#[cfg(unix)]
+		{
+			use std::os::unix::fs::MetadataExt;
+
+			if TASKS.parallel_copy < 2 {
+				return 1;
+			}
+			let Ok(meta) = Self::metadata(&task.from, task.follow).await else {
+				return 1;
+			};
+			if !meta.is_file() || meta.len() < TASKS.parallel_copy_size {
+				return 1;
+			}
+
+			let dir = task.to.parent().unwrap_or(&task.to);
+			if fs::metadata(dir).await.is_ok_and(|m| m.dev() != meta.dev()) {
+				return TASKS.parallel_copy as usize;
+			}
+		}
+		1
+	}
+
 	async fn metadata(path: &Path, follow: bool) -> io::Result<Metadata> {
 		if !follow {
 			return fs::symlink_metadata(path).await;
@@ -354,6 +762,19 @@ impl File {
 	fn log(&self, id: usize, line: String) -> Result<()> { Ok(self.sch.send(TaskOp::Log(id, line))?) }
 }
 
+impl FileOpNormalize {
+	#[cfg(unix)]
+	fn mode(&self, old: u32, is_dir: bool) -> u32 {
+		let mode = if is_dir { self.dir_mode } else { self.file_mode };
+		let mut mode = mode.unwrap_or(old);
+		if self.strip_setuid {
+			// Keep the setgid bit of directories, it's used for shared group ownership
+			mode &= if is_dir { !0o4000 } else { !0o6000 };
+		}
+		mode
+	}
+}
+
 impl FileOpPaste {
 	fn to_link(&self, meta: Metadata) -> FileOpLink {
 		FileOpLink {
@@ -1,13 +1,12 @@
-use std::{collections::{BTreeMap, BTreeSet}, sync::Arc};
+use std::{collections::{BTreeMap, BTreeSet}, sync::{atomic::{AtomicUsize, Ordering}, Arc}};
 
 use anyhow::Result;
 use parking_lot::Mutex;
-use tokio::{fs, sync::mpsc};
+use tokio::sync::mpsc;
 use yazi_adaptor::Image;
-use yazi_config::PREVIEW;
 use yazi_shared::{calculate_size, Throttle, Url};
 
-use crate::{emit, external, files::FilesOp, tasks::TaskOp};
+use crate::{emit, external, files::FilesOp, preview::{Cache, Thumbs}, tasks::TaskOp};
 
 pub(crate) struct Precache {
 	tx: async_channel::Sender<PrecacheOp>,
@@ -16,6 +15,7 @@ pub(crate) struct Precache {
 	sch: mpsc::UnboundedSender<TaskOp>,
 
 	pub(crate) size_handing: Mutex<BTreeSet<Url>>,
+	pub(crate) mime_page:    AtomicUsize,
 }
 
 #[derive(Debug)]
@@ -35,9 +35,17 @@ pub(crate) struct PrecacheOpSize {
 #[derive(Debug)]
 pub(crate) struct PrecacheOpMime {
 	pub id:      usize,
+	pub page:    usize,
 	pub targets: Vec<Url>,
 }
 
+#[derive(Debug)]
+pub(crate) struct PrecacheOpThumbs {
+	pub id:     usize,
+	pub root:   Url,
+	pub import: bool,
+}
+
 #[derive(Debug)]
 pub(crate) struct PrecacheOpImage {
 	pub id:     usize,
@@ -59,7 +67,7 @@ pub(crate) struct PrecacheOpPDF {
 impl Precache {
 	pub(crate) fn new(sch: mpsc::UnboundedSender<TaskOp>) -> Self {
 		let (tx, rx) = async_channel::unbounded();
-		Self { tx, rx, sch, size_handing: Default::default() }
+		Self { tx, rx, sch, size_handing: Default::default(), mime_page: Default::default() }
 	}
 
 	#[inline]
@@ -74,29 +82,35 @@ impl Precache {
 	pub(crate) async fn work(&self, task: &mut PrecacheOp) -> Result<()> {
 		match task {
 			PrecacheOp::Image(task) => {
-				let cache = PREVIEW.cache(&task.target, 0);
-				if fs::symlink_metadata(&cache).await.is_ok() {
+				let cache = Cache::path(&task.target, 0).await;
+				if Cache::hit(&cache).await {
 					return Ok(self.sch.send(TaskOp::Adv(task.id, 1, 0))?);
 				}
-				Image::precache(&task.target, cache).await.ok();
+				if Image::precache(&task.target, cache.clone()).await.is_ok() {
+					Cache::put(&cache).await;
+				}
 				self.sch.send(TaskOp::Adv(task.id, 1, 0))?;
 			}
 			PrecacheOp::Video(task) => {
-				let cache = PREVIEW.cache(&task.target, 0);
-				if fs::symlink_metadata(&cache).await.is_ok() {
+				let cache = Cache::path(&task.target, 0).await;
+				if Cache::hit(&cache).await {
 					return Ok(self.sch.send(TaskOp::Adv(task.id, 1, 0))?);
 				}
 
-				external::ffmpegthumbnailer(&task.target, &cache, 0).await.ok();
+				if external::ffmpeg(&task.target, &cache, 0, None).await.is_ok() {
+					Cache::put(&cache).await;
+				}
 				self.sch.send(TaskOp::Adv(task.id, 1, 0))?;
 			}
 			PrecacheOp::Pdf(task) => {
-				let cache = PREVIEW.cache(&task.target, 0);
-				if fs::symlink_metadata(&cache).await.is_ok() {
+				let cache = Cache::path(&task.target, 0).await;
+				if Cache::hit(&cache).await {
 					return Ok(self.sch.send(TaskOp::Adv(task.id, 1, 0))?);
 				}
 
-				external::pdftoppm(&task.target, &cache, 0).await.ok();
+				if external::pdftoppm(&task.target, &cache, 0).await.is_ok() {
+					Cache::put(&cache).await;
+				}
 				self.sch.send(TaskOp::Adv(task.id, 1, 0))?;
 			}
 		}
@@ -105,8 +119,12 @@ impl Precache {
 
 	pub(crate) async fn mime(&self, task: PrecacheOpMime) -> Result<()> {
 		self.sch.send(TaskOp::New(task.id, 0))?;
-		if let Ok(mimes) = external::file(&task.targets).await {
-			emit!(Mimetype(mimes));
+
+		// Skip the pages that were scrolled out of view before their turn came
+		if task.page.abs_diff(self.mime_page.load(Ordering::Relaxed)) <= 1 {
+			if let Ok(mimes) = external::file(&task.targets).await {
+				emit!(Mimetype(mimes));
+			}
 		}
 
 		self.sch.send(TaskOp::Adv(task.id, 1, 0))?;
@@ -131,6 +149,26 @@ impl Precache {
 		self.succ(task.id)
 	}
 
+	pub(crate) async fn thumbs(&self, task: PrecacheOpThumbs) -> Result<()> {
+		self.sch.send(TaskOp::New(task.id, 0))?;
+
+		let root = task.root.to_path_buf();
+		let line = if task.import {
+			let written = tokio::task::spawn_blocking(move || Thumbs::import(&root)).await??;
+			for cache in &written {
+				Cache::put(cache).await;
+			}
+			format!("Imported {} cache entries", written.len())
+		} else {
+			let n = tokio::task::spawn_blocking(move || Thumbs::export(&root)).await??;
+			format!("Exported {n} cache entries")
+		};
+
+		self.sch.send(TaskOp::Log(task.id, line))?;
+		self.sch.send(TaskOp::Adv(task.id, 1, 0))?;
+		self.succ(task.id)
+	}
+
 	pub(crate) fn image(&self, id: usize, targets: Vec<Url>) -> Result<()> {
 		for target in targets {
 			self.sch.send(TaskOp::New(id, 0))?;
@@ -1,7 +1,7 @@
 use std::{ffi::OsString, mem};
 
 use anyhow::Result;
-use tokio::{io::{AsyncBufReadExt, BufReader}, select, sync::{mpsc, oneshot}};
+use tokio::{io::{AsyncBufReadExt, AsyncWriteExt, BufReader}, select, sync::{mpsc, oneshot}};
 
 use crate::{emit, external::{self, ShellOpt}, tasks::TaskOp, BLOCKER};
 
@@ -13,9 +13,11 @@ pub(crate) struct Process {
 pub(crate) struct ProcessOpOpen {
 	pub id:     usize,
 	pub cmd:    OsString,
+	pub arg0:   OsString,
 	pub args:   Vec<OsString>,
 	pub block:  bool,
 	pub orphan: bool,
+	pub input:  Option<Vec<u8>>,
 	pub cancel: oneshot::Sender<()>,
 }
 
@@ -23,7 +25,9 @@ impl From<&mut ProcessOpOpen> for ShellOpt {
 	fn from(value: &mut ProcessOpOpen) -> Self {
 		Self {
 			cmd:    mem::take(&mut value.cmd),
+			arg0:   mem::take(&mut value.arg0),
 			args:   mem::take(&mut value.args),
+			env:    Default::default(),
 			piped:  false,
 			orphan: value.orphan,
 		}
@@ -65,6 +69,9 @@ impl Process {
 
 		self.sch.send(TaskOp::New(task.id, 0))?;
 		let mut child = external::shell(opt.with_piped())?;
+		if let (Some(input), Some(mut stdin)) = (task.input.take(), child.stdin.take()) {
+			tokio::spawn(async move { stdin.write_all(&input).await.ok() });
+		}
 
 		let mut stdout = BufReader::new(child.stdout.take().unwrap()).lines();
 		let mut stderr = BufReader::new(child.stderr.take().unwrap()).lines();
@@ -1,11 +1,13 @@
 use std::ffi::OsString;
 
 use anyhow::{Ok, Result};
-use crossterm::event::KeyEvent;
+use crossterm::event::{KeyEvent, MouseEvent};
 use ratatui::prelude::Rect;
 use tokio::sync::oneshot;
-use yazi_config::{keymap::{Exec, Key, KeymapLayer}, BOOT};
-use yazi_core::{emit, files::FilesOp, input::InputMode, manager::Manager, Ctx, Event};
+use tracing::error;
+use yazi_adaptor::ADAPTOR;
+use yazi_config::{keymap::{Exec, Key, KeymapLayer}, BOOT, KEYMAP, MANAGER};
+use yazi_core::{emit, files::FilesOp, input::InputMode, manager::Manager, Ctx, Event, Hooks, Remote};
 use yazi_shared::Term;
 
 use crate::{Executor, Logs, Panic, Root, Signals};
@@ -20,9 +22,10 @@ impl App {
 	pub(super) async fn run() -> Result<()> {
 		Panic::install();
 		let _log = Logs::init()?;
-		let term = Term::start()?;
+		let term = Term::start(MANAGER.mouse && KEYMAP.has_mouse())?;
 
 		let signals = Signals::start()?;
+		Remote::serve();
 		let mut app = Self { cx: Ctx::make(), term: Some(term), signals };
 
 		while let Some(event) = app.signals.recv().await {
@@ -32,6 +35,7 @@ impl App {
 					break;
 				}
 				Event::Key(key) => app.dispatch_key(key),
+				Event::Mouse(mouse) => app.dispatch_mouse(mouse),
 				Event::Paste(str) => app.dispatch_paste(str),
 				Event::Render(_) => app.dispatch_render(),
 				Event::Resize(cols, rows) => app.dispatch_resize(cols, rows),
@@ -57,6 +61,11 @@ impl App {
 				std::fs::write(p, cwd.as_bytes()).ok();
 			}
 		}
+		let hooks = Hooks::quit(self.cx.manager.cwd());
+		Executor::new(&mut self.cx).dispatch(&hooks, KeymapLayer::Manager);
+
+		Remote::stop();
+		ADAPTOR.stop();
 		Term::goodbye(|| false).unwrap();
 	}
 
@@ -67,6 +76,15 @@ impl App {
 		}
 	}
 
+	fn dispatch_mouse(&mut self, mouse: MouseEvent) {
+		let Some(key) = Key::from_mouse(mouse) else {
+			return;
+		};
+		if Executor::new(&mut self.cx).handle_mouse(key, mouse.column, mouse.row) {
+			emit!(Render);
+		}
+	}
+
 	fn dispatch_paste(&mut self, str: String) {
 		if self.cx.input.visible {
 			let input = &mut self.cx.input;
@@ -97,7 +115,7 @@ impl App {
 
 		self.cx.manager.current_mut().set_page(true);
 		self.cx.manager.active_mut().preview.reset(|_| true);
-		self.cx.manager.peek(true, self.cx.image_layer());
+		self.cx.manager.peek(true, self.cx.image_layer(), false);
 		emit!(Render);
 	}
 
@@ -107,7 +125,7 @@ impl App {
 			self.signals.stop_term(true);
 			self.term = None;
 		} else {
-			self.term = Some(Term::start().unwrap());
+			self.term = Some(Term::start(MANAGER.mouse && KEYMAP.has_mouse()).unwrap());
 			self.signals.stop_term(false);
 			emit!(Render);
 			Manager::_hover(None);
@@ -129,7 +147,7 @@ impl App {
 		let tasks = &mut self.cx.tasks;
 		match event {
 			Event::Files(op) => {
-				let calc = !matches!(op, FilesOp::Size(..) | FilesOp::IOErr(_));
+				let calc = !matches!(op, FilesOp::Size(..) | FilesOp::Git(..) | FilesOp::IOErr(_));
 				let b = match op {
 					FilesOp::IOErr(..) => manager.update_ioerr(op),
 					_ => manager.update_read(op),
@@ -142,11 +160,10 @@ impl App {
 				}
 			}
 			Event::Pages(page) => {
-				let targets = self.cx.manager.current().paginate(page);
-				tasks.precache_mime(targets, &self.cx.manager.mimetype);
+				tasks.precache_mime(manager.current(), page, &manager.mimetype);
 			}
 			Event::Mimetype(mimes) => {
-				if manager.update_mimetype(mimes, tasks) {
+				if manager.update_mimetype(yazi_plugin::fetch(mimes), tasks) {
 					emit!(Render);
 					emit!(Peek);
 				}
@@ -154,9 +171,9 @@ impl App {
 			Event::Peek(sequent) => {
 				if let Some((max, url)) = sequent {
 					manager.active_mut().update_peek(max, url);
-					self.cx.manager.peek(true, self.cx.image_layer());
+					self.cx.manager.peek(true, self.cx.image_layer(), false);
 				} else {
-					self.cx.manager.peek(false, self.cx.image_layer());
+					self.cx.manager.peek(false, self.cx.image_layer(), false);
 				}
 			}
 			Event::Preview(lock) => {
@@ -164,6 +181,13 @@ impl App {
 					emit!(Render);
 				}
 			}
+			Event::PluginPreview(name, file, mime, tx) => {
+				let result = yazi_plugin::preview(&name, &file, &mime);
+				if let Err(e) = &result {
+					error!("previewer `{name}` failed: {e}");
+				}
+				tx.send(result).ok();
+			}
 
 			Event::Select(opt, tx) => {
 				self.cx.select.show(opt, tx);
@@ -173,6 +197,10 @@ impl App {
 				self.cx.input.show(opt, tx);
 				emit!(Render);
 			}
+			Event::Confirm(opt, tx) => {
+				self.cx.confirm.show(opt, tx);
+				emit!(Render);
+			}
 
 			Event::Open(targets, opener) => {
 				if let Some(p) = &BOOT.chooser_file {
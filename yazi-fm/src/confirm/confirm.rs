@@ -0,0 +1,64 @@
+use ratatui::{buffer::Buffer, layout::{Alignment, Constraint, Direction, Layout, Rect}, text::{Line, Span}, widgets::{Block, BorderType, Borders, Clear, Paragraph, Widget}};
+use yazi_config::THEME;
+use yazi_core::Ctx;
+
+pub(crate) struct Confirm<'a> {
+	cx: &'a Ctx,
+}
+
+impl<'a> Confirm<'a> {
+	pub(crate) fn new(cx: &'a Ctx) -> Self { Self { cx } }
+}
+
+impl<'a> Widget for Confirm<'a> {
+	fn render(self, _: Rect, buf: &mut Buffer) {
+		let confirm = &self.cx.confirm;
+		let area = self.cx.area(&confirm.position);
+
+		Clear.render(area, buf);
+		let block = Block::new()
+			.title({
+				let mut line = Line::from(confirm.title());
+				line.patch_style(THEME.confirm.title.into());
+				line
+			})
+			.borders(Borders::ALL)
+			.border_type(BorderType::Rounded)
+			.border_style(THEME.confirm.border.into());
+
+		let inner = block.inner(area);
+		block.render(area, buf);
+
+		let chunks = Layout::new()
+			.direction(Direction::Vertical)
+			.constraints([Constraint::Min(0), Constraint::Length(1), Constraint::Length(1)])
+			.split(inner);
+
+		let lines: Vec<_> = confirm
+			.window()
+			.into_iter()
+			.map(|(s, marked)| {
+				let s = format!(" {s}");
+				if marked {
+					Line::styled(s, THEME.confirm.marked.into())
+				} else {
+					Line::from(s)
+				}
+			})
+			.collect();
+		Paragraph::new(lines).style(THEME.confirm.content.into()).render(chunks[0], buf);
+
+		let (yes, no) = if confirm.yes() {
+			(THEME.confirm.btn_active, THEME.confirm.btn_inactive)
+		} else {
+			(THEME.confirm.btn_inactive, THEME.confirm.btn_active)
+		};
+		Paragraph::new(Line::from(vec![
+			Span::styled(" [Y]es ", yes.into()),
+			Span::raw("   "),
+			Span::styled(" [N]o ", no.into()),
+		]))
+		.alignment(Alignment::Center)
+		.render(chunks[2], buf);
+	}
+}
@@ -0,0 +1,3 @@
+mod confirm;
+
+pub(super) use confirm::*;
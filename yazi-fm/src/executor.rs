@@ -1,4 +1,5 @@
-use yazi_config::{keymap::{Control, Exec, Key, KeymapLayer}, KEYMAP};
+use tracing::error;
+use yazi_config::{keymap::{Control, Exec, Key, KeymapLayer, Mouse, Region}, KEYMAP, MANAGER};
 use yazi_core::{input::InputMode, Ctx};
 
 pub(super) struct Executor<'a> {
@@ -26,6 +27,8 @@ impl<'a> Executor<'a> {
 			self.matches(KeymapLayer::Help, key)
 		} else if self.cx.input.visible {
 			self.matches(KeymapLayer::Input, key)
+		} else if self.cx.confirm.visible {
+			self.matches(KeymapLayer::Confirm, key)
 		} else if self.cx.select.visible {
 			self.matches(KeymapLayer::Select, key)
 		} else if self.cx.tasks.visible {
@@ -36,6 +39,56 @@ impl<'a> Executor<'a> {
 		b == Some(true)
 	}
 
+	// Mouse events only go to the manager, when none of the popups is visible
+	pub(super) fn handle_mouse(&mut self, key: Key, x: u16, y: u16) -> bool {
+		let cx = &self.cx;
+		if cx.which.visible
+			|| cx.help.visible
+			|| cx.input.visible
+			|| cx.select.visible
+			|| cx.confirm.visible
+			|| cx.tasks.visible
+			|| cx.completion.visible
+		{
+			return false;
+		}
+
+		// The regions are those of the three columns, which the dual-pane layout
+		// doesn't have
+		if cx.manager.tabs.other.is_some() {
+			return false;
+		}
+
+		let Some(region) = MANAGER.layout.region(x, y) else {
+			return false;
+		};
+
+		// Clicking a file in the current pane hovers it, before running the binding
+		let scroll = matches!(key.mouse, Some(Mouse::ScrollUp | Mouse::ScrollDown));
+		let mut render = false;
+		if region == Region::Current && !scroll {
+			let rect = MANAGER.layout.folder_rect();
+			let current = self.cx.manager.current();
+			let idx = current.offset + y.saturating_sub(rect.y) as usize;
+			if idx < current.files.len() {
+				let step = idx as isize - current.cursor as isize;
+				render = self.cx.manager.active_mut().arrow(step);
+			}
+		}
+
+		let controls =
+			KEYMAP.get(KeymapLayer::Manager).iter().filter(|c| c.on.len() == 1 && c.on[0] == key);
+		let control = controls
+			.clone()
+			.find(|c| c.region == Some(region))
+			.or_else(|| controls.clone().find(|c| c.region.is_none()));
+
+		match control {
+			Some(c) => self.dispatch(&c.exec, KeymapLayer::Manager) | render,
+			None => render,
+		}
+	}
+
 	#[inline]
 	fn matches(&mut self, layer: KeymapLayer, key: Key) -> Option<bool> {
 		for Control { on, exec, .. } in KEYMAP.get(layer) {
@@ -57,9 +110,10 @@ impl<'a> Executor<'a> {
 		let mut render = false;
 		for e in exec {
 			render |= match layer {
-				KeymapLayer::Manager => self.manager(e),
+				KeymapLayer::Manager => self.manager(e) | self.cx.manager.tabs.sync(),
 				KeymapLayer::Tasks => self.tasks(e),
 				KeymapLayer::Select => self.select(e),
+				KeymapLayer::Confirm => self.confirm(e),
 				KeymapLayer::Input => self.input(e),
 				KeymapLayer::Help => self.help(e),
 				KeymapLayer::Completion => self.completion(e),
@@ -93,6 +147,8 @@ impl<'a> Executor<'a> {
 		on!(MANAGER, quit, &self.cx.tasks);
 		on!(MANAGER, close, &self.cx.tasks);
 		on!(MANAGER, suspend);
+		on!(MANAGER, subshell);
+		on!(MANAGER, debug);
 		on!(ACTIVE, escape);
 
 		// Navigation
@@ -103,6 +159,7 @@ impl<'a> Executor<'a> {
 		on!(ACTIVE, forward);
 		on!(ACTIVE, cd);
 		on!(ACTIVE, reveal);
+		on!(ACTIVE, follow);
 
 		// Selection
 		on!(ACTIVE, select);
@@ -112,14 +169,28 @@ impl<'a> Executor<'a> {
 		// Operation
 		on!(MANAGER, open);
 		on!(MANAGER, yank);
+		on!(MANAGER, unyank);
 		on!(MANAGER, paste, &self.cx.tasks);
 		on!(MANAGER, link, &self.cx.tasks);
 		on!(MANAGER, remove, &self.cx.tasks);
+		on!(MANAGER, calc_size, &self.cx.tasks);
+		on!(MANAGER, normalize, &self.cx.tasks);
+		on!(MANAGER, own, &self.cx.tasks);
+		on!(MANAGER, verify_archive, &self.cx.tasks);
+		on!(MANAGER, download, &self.cx.tasks);
+		on!(MANAGER, thumbs, &self.cx.tasks);
+		on!(MANAGER, selection, &self.cx.tasks);
 		on!(MANAGER, create);
 		on!(MANAGER, rename);
+		on!(MANAGER, edit_link);
+		on!(MANAGER, compare);
+		on!(MANAGER, info);
+		on!(MANAGER, xattr);
+		on!(MANAGER, usage, &self.cx.tasks);
 		on!(ACTIVE, copy);
 		on!(ACTIVE, shell);
 		on!(ACTIVE, hidden);
+		on!(ACTIVE, ignored);
 		on!(ACTIVE, linemode);
 		on!(ACTIVE, search);
 		on!(ACTIVE, jump);
@@ -137,12 +208,30 @@ impl<'a> Executor<'a> {
 		on!(TABS, close);
 		on!(TABS, switch);
 		on!(TABS, swap);
+		on!(TABS, dual);
+
+		// Layout
+		on!(MANAGER, resize);
 
 		match exec.cmd.as_bytes() {
 			b"peek" => {
 				let step = exec.args.first().and_then(|s| s.parse().ok()).unwrap_or(0);
-				self.cx.manager.active_mut().preview.arrow(step);
-				self.cx.manager.peek(true, self.cx.image_layer())
+				self.cx.manager.active_mut().preview.arrow(step, exec.named.contains_key("line"));
+				self.cx.manager.peek(true, self.cx.image_layer(), false)
+			}
+			b"preview" => {
+				let force = exec.named.contains_key("force");
+				self.cx.manager.peek(false, self.cx.image_layer(), force)
+			}
+			b"exif" => {
+				self.cx.manager.active_mut().preview.exif(exec.named.contains_key("only"));
+				self.cx.manager.peek(false, self.cx.image_layer(), true)
+			}
+			b"plugin" => {
+				if let Err(e) = yazi_plugin::command(self.cx, exec) {
+					error!("`{exec}` failed: {e}");
+				}
+				true
 			}
 			// Tasks
 			b"tasks_show" => self.cx.tasks.toggle(()),
@@ -195,6 +284,24 @@ impl<'a> Executor<'a> {
 		}
 	}
 
+	fn confirm(&mut self, exec: &Exec) -> bool {
+		macro_rules! on {
+			($name:ident) => {
+				if exec.cmd == stringify!($name) {
+					return self.cx.confirm.$name(exec);
+				}
+			};
+		}
+
+		on!(close);
+		on!(arrow);
+
+		match exec.cmd.as_str() {
+			"help" => self.cx.help.toggle(KeymapLayer::Confirm),
+			_ => false,
+		}
+	}
+
 	fn input(&mut self, exec: &Exec) -> bool {
 		macro_rules! on {
 			($name:ident) => {
@@ -214,6 +321,7 @@ impl<'a> Executor<'a> {
 		on!(move_, "move");
 		on!(backward);
 		on!(forward);
+		on!(history);
 
 		if exec.cmd.as_str() == "complete" {
 			return if exec.named.contains_key("trigger") {
@@ -1,7 +1,7 @@
 use std::ops::Range;
 
 use ansi_to_tui::IntoText;
-use ratatui::{buffer::Buffer, layout::Rect, text::{Line, Text}, widgets::{Block, BorderType, Borders, Clear, Paragraph, Widget}};
+use ratatui::{buffer::Buffer, layout::{Alignment, Rect}, text::{Line, Text}, widgets::{block::{self, Title}, Block, BorderType, Borders, Clear, Paragraph, Widget}};
 use yazi_config::THEME;
 use yazi_core::{input::InputMode, Ctx};
 use yazi_shared::Term;
@@ -36,7 +36,16 @@ impl<'a> Widget for Input<'a> {
 						let mut line = Line::from(input.title.as_str());
 						line.patch_style(THEME.input.title.into());
 						line
-					}),
+					})
+					.title(
+						Title::from(match input.mode() {
+							InputMode::Insert => " INSERT ",
+							InputMode::Normal if input.is_visual() => " VISUAL ",
+							InputMode::Normal => " NORMAL ",
+						})
+						.alignment(Alignment::Right)
+						.position(block::Position::Bottom),
+					),
 			)
 			.style(THEME.input.value.into())
 			.render(area, buf);
@@ -2,6 +2,7 @@
 
 mod app;
 mod completion;
+mod confirm;
 mod executor;
 mod help;
 mod input;
@@ -20,12 +21,24 @@ use panic::*;
 use root::*;
 use signals::*;
 
-#[tokio::main]
-async fn main() -> anyhow::Result<()> {
+fn main() -> anyhow::Result<()> {
 	_ = fdlimit::raise_fd_limit();
-	// console_subscriber::init();
 
 	yazi_config::init();
+	if let Some(cmd) = &yazi_config::BOOT.remote {
+		return run_remote(cmd);
+	}
+
+	yazi_core::Remote::export();
+	run()
+}
+
+#[tokio::main]
+async fn run_remote(cmd: &str) -> anyhow::Result<()> { yazi_core::Remote::send(cmd).await }
+
+#[tokio::main]
+async fn run() -> anyhow::Result<()> {
+	// console_subscriber::init();
 
 	yazi_core::init();
 
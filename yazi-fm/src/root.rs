@@ -2,7 +2,7 @@ use ratatui::{buffer::Buffer, layout::{Constraint, Direction, Layout, Rect}, wid
 use yazi_core::Ctx;
 use yazi_plugin::components;
 
-use super::{completion, input, select, tasks, which};
+use super::{completion, confirm, input, select, tasks, which};
 use crate::help;
 
 pub(super) struct Root<'a> {
@@ -32,6 +32,10 @@ impl<'a> Widget for Root<'a> {
 			select::Select::new(self.cx).render(area, buf);
 		}
 
+		if self.cx.confirm.visible {
+			confirm::Confirm::new(self.cx).render(area, buf);
+		}
+
 		if self.cx.input.visible {
 			input::Input::new(self.cx).render(area, buf);
 		}
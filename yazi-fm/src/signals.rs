@@ -91,6 +91,7 @@ impl Signals {
 							// We need to check key event kind;
 							// otherwise event will be dispatched twice.
 							CrosstermEvent::Key(key @ KeyEvent { kind: KeyEventKind::Press, .. }) => Event::Key(key),
+							CrosstermEvent::Mouse(mouse) => Event::Mouse(mouse),
 							CrosstermEvent::Paste(str) => Event::Paste(str),
 							CrosstermEvent::Resize(cols, rows) => Event::Resize(cols, rows),
 							_ => continue,
@@ -2,6 +2,7 @@ Folder = {
 	PARENT = 0,
 	CURRENT = 1,
 	PREVIEW = 2,
+	OTHER = 3,
 }
 
 function Folder:by_kind(kind)
@@ -11,10 +12,16 @@ function Folder:by_kind(kind)
 		return cx.active.current
 	elseif kind == self.PREVIEW then
 		return cx.active.preview.folder
+	elseif kind == self.OTHER then
+		return cx.active.other
 	end
 end
 
-function Folder:icon(file) return ui.Span(" " .. file:icon() .. " ") end
+function Folder:icon(file)
+	local icon, style = file:icon()
+	local span = ui.Span(" " .. icon .. " ")
+	return style and span:style(style) or span
+end
 
 function Folder:highlighted_name(file)
 	-- Complete prefix when searching across directories
@@ -44,22 +51,38 @@ function Folder:highlighted_name(file)
 	return spans
 end
 
+function Folder:git(file)
+	local status = file:git()
+	if status == nil then
+		return {}
+	end
+
+	local symbols = { modified = "M", staged = "S", untracked = "?", ignored = "!" }
+	return { ui.Span(" " .. symbols[status]):style(THEME.manager["git_" .. status]) }
+end
+
 function Folder:linemode(area)
 	local mode = cx.active.conf.linemode
 	if mode == "none" then
 		return {}
 	end
 
+	local total = mode == "usage" and self:by_kind(self.CURRENT).files.total_size or 0
 	local lines = {}
 	for _, f in ipairs(self:by_kind(self.CURRENT).window) do
 		local spans = { ui.Span(" ") }
 		if mode == "size" then
 			local size = f:size()
 			spans[#spans + 1] = ui.Span(size and utils.readable_size(size) or "")
+		elseif mode == "usage" then
+			spans[#spans + 1] = ui.Span(self:usage(f:size(), total))
 		elseif mode == "mtime" then
 			spans[#spans + 1] = ui.Span(os.date("%y-%m-%d %H:%M", f.modified))
 		elseif mode == "permissions" then
 			spans[#spans + 1] = ui.Span(f:permissions() or "")
+		elseif mode == "owner" then
+			local owner, group = f:owner(), f:group()
+			spans[#spans + 1] = ui.Span(owner and string.format("%s:%s", owner, group or "") or "")
 		end
 
 		spans[#spans + 1] = ui.Span(" ")
@@ -68,6 +91,16 @@ function Folder:linemode(area)
 	return ui.Paragraph(area, lines):align(ui.Alignment.RIGHT)
 end
 
+function Folder:usage(size, total)
+	if not size then
+		return "calculating..."
+	end
+
+	local ratio = total > 0 and size / total or 0
+	local bar = string.rep("#", math.floor(ratio * 10 + 0.5))
+	return string.format("%5.1f%% [%-10s] %10s", ratio * 100, bar, utils.readable_size(size))
+end
+
 function Folder:markers(area, markers)
 	if #markers == 0 then
 		return {}
@@ -109,6 +142,16 @@ function Folder:markers(area, markers)
 	return elements
 end
 
+function Folder:yanked(f, line)
+	local yanked = f:is_yanked()
+	if yanked == 1 then
+		return line:style(THEME.manager.yanked_copied)
+	elseif yanked == 2 then
+		return line:style(THEME.manager.yanked_cut)
+	end
+	return line
+end
+
 function Folder:parent(area)
 	local folder = self:by_kind(self.PARENT)
 	if folder == nil then
@@ -117,7 +160,8 @@ function Folder:parent(area)
 
 	local items = {}
 	for _, f in ipairs(folder.window) do
-		local item = ui.ListItem(ui.Line { self:icon(f), ui.Span(f.name) })
+		local line = ui.Line { self:icon(f), ui.Span(f.name), table.unpack(self:git(f)) }
+		local item = ui.ListItem(self:yanked(f, line))
 		if f:is_hovered() then
 			item = item:style(THEME.manager.hovered)
 		else
@@ -134,10 +178,10 @@ function Folder:current(area)
 	local markers = {}
 	local items = {}
 	for i, f in ipairs(self:by_kind(self.CURRENT).window) do
-		local name = self:highlighted_name(f)
+		local name = utils.flat { self:highlighted_name(f), self:git(f) }
 
 		-- Highlight hovered file
-		local item = ui.ListItem(ui.Line { self:icon(f), table.unpack(name) })
+		local item = ui.ListItem(self:yanked(f, ui.Line { self:icon(f), table.unpack(name) }))
 		if f:is_hovered() then
 			item = item:style(THEME.manager.hovered)
 		else
@@ -156,6 +200,34 @@ function Folder:current(area)
 	return utils.flat { ui.List(area, items), self:linemode(area), table.unpack(self:markers(area, markers)) }
 end
 
+function Folder:other(area)
+	local folder = self:by_kind(self.OTHER)
+	if folder == nil then
+		return {}
+	end
+
+	local markers = {}
+	local items = {}
+	for i, f in ipairs(folder.window) do
+		local line = ui.Line { self:icon(f), ui.Span(f.name), table.unpack(self:git(f)) }
+		local item = ui.ListItem(self:yanked(f, line))
+		if f:is_hovered() then
+			item = item:style(THEME.manager.preview_hovered)
+		else
+			item = item:style(f:style())
+		end
+		items[#items + 1] = item
+
+		local yanked = f:is_yanked()
+		if yanked ~= 0 then
+			markers[#markers + 1] = { i, yanked }
+		elseif f:is_selected() then
+			markers[#markers + 1] = { i, 3 }
+		end
+	end
+	return utils.flat { ui.List(area, items), table.unpack(self:markers(area, markers)) }
+end
+
 function Folder:preview(area)
 	local folder = self:by_kind(self.PREVIEW)
 	if folder == nil then
@@ -164,7 +236,7 @@ function Folder:preview(area)
 
 	local items = {}
 	for _, f in ipairs(folder.window) do
-		local item = ui.ListItem(ui.Line { self:icon(f), ui.Span(f.name) })
+		local item = ui.ListItem(self:yanked(f, ui.Line { self:icon(f), ui.Span(f.name) }))
 		if f:is_hovered() then
 			item = item:style(THEME.manager.preview_hovered)
 		else
@@ -183,5 +255,7 @@ function Folder:render(area, args)
 		return self:current(area)
 	elseif args.kind == self.PREVIEW then
 		return self:preview(area)
+	elseif args.kind == self.OTHER then
+		return self:other(area)
 	end
 end
@@ -12,6 +12,13 @@ function Header:cwd()
 	return span:style(THEME.manager.cwd)
 end
 
+function Header:loading()
+	if not cx.active.current.files.is_loading then
+		return ui.Span("")
+	end
+	return ui.Span(" (loading…)"):style(THEME.manager.cwd)
+end
+
 function Header:tabs()
 	local spans = {}
 	for i = 1, #cx.tabs do
@@ -19,6 +26,9 @@ function Header:tabs()
 		if THEME.manager.tab_width > 2 then
 			text = utils.truncate(text .. " " .. cx.tabs[i]:name(), THEME.manager.tab_width)
 		end
+		if cx.tabs[i]:has_cut() then
+			text = text .. "✂"
+		end
 		if i == cx.tabs.idx + 1 then
 			spans[#spans + 1] = ui.Span(" " .. text .. " "):style(THEME.manager.tab_active)
 		else
@@ -34,7 +44,7 @@ function Header:render(area)
 		:constraints({ ui.Constraint.Percentage(50), ui.Constraint.Percentage(50) })
 		:split(area)
 
-	local left = ui.Line { self:cwd() }
+	local left = ui.Line { self:cwd(), self:loading() }
 	local right = ui.Line { self:tabs() }
 	return {
 		ui.Paragraph(chunks[1], { left }),
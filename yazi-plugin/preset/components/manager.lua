@@ -1,6 +1,29 @@
 Manager = {}
 
+function Manager:dual(area)
+	local chunks = ui.Layout()
+		:direction(ui.Direction.HORIZONTAL)
+		:constraints({ ui.Constraint.Percentage(50), ui.Constraint.Percentage(50) })
+		:split(area)
+
+	-- The panes keep the order of their tabs
+	local left, right = Folder.CURRENT, Folder.OTHER
+	if cx.tabs.other < cx.tabs.idx then
+		left, right = right, left
+	end
+
+	return utils.flat {
+		ui.Bar(chunks[1], ui.Position.RIGHT):symbol(THEME.manager.border_symbol):style(THEME.manager.border_style),
+		Folder:render(chunks[1]:padding(ui.Padding.x(1)), { kind = left }),
+		Folder:render(chunks[2]:padding(ui.Padding.x(1)), { kind = right }),
+	}
+end
+
 function Manager:render(area)
+	if cx.tabs.other ~= nil then
+		return self:dual(area)
+	end
+
 	local chunks = ui.Layout()
 		:direction(ui.Direction.HORIZONTAL)
 		:constraints({
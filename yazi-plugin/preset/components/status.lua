@@ -42,7 +42,71 @@ function Status:name()
 		return ui.Span("")
 	end
 
-	return ui.Span(" " .. h.name)
+	if h.link_to == nil then
+		return ui.Span(" " .. h.name)
+	end
+	return ui.Line {
+		ui.Span(" " .. h.name),
+		ui.Span(" -> " .. tostring(h.link_to)):italic(),
+	}
+end
+
+function Status:encoding()
+	local encoding = cx.active.preview.encoding
+	if encoding == nil then
+		return ui.Span("")
+	end
+
+	return ui.Span(encoding .. " "):style(THEME.status.permissions_s)
+end
+
+function Status:video()
+	local video = cx.active.preview.video
+	if video == nil then
+		return ui.Span("")
+	end
+
+	return ui.Span(video .. " "):style(THEME.status.permissions_s)
+end
+
+function Status:yanked()
+	local n = cx.active.yanked
+	if n == 0 then
+		return ui.Span("")
+	end
+
+	if cx.active.is_cut then
+		return ui.Span(string.format("%d cut ", n)):fg(THEME.manager.marker_cut.fg)
+	end
+	return ui.Span(string.format("%d copied ", n)):fg(THEME.manager.marker_copied.fg)
+end
+
+function Status:elsewhere()
+	local n = cx.active.elsewhere
+	if n == 0 then
+		return ui.Span("")
+	end
+
+	return ui.Span(string.format("+%d selected elsewhere ", n)):style(THEME.status.permissions_s)
+end
+
+function Status:ignored()
+	if cx.active.conf.show_ignored then
+		return ui.Span("")
+	end
+
+	return ui.Span("git-ignored hidden "):style(THEME.status.permissions_s)
+end
+
+function Status:stale()
+	if not cx.active.stale then
+		return ui.Span("")
+	end
+
+	if cx.active.watch_exhausted then
+		return ui.Span("out of file watches, `refresh` to reload "):style(THEME.status.stale)
+	end
+	return ui.Span("stale view possible, `refresh` to reload "):style(THEME.status.stale)
 end
 
 function Status:permissions()
@@ -140,7 +204,17 @@ end
 
 function Status:render(area)
 	local left = ui.Line { self:mode(), self:size(), self:name() }
-	local right = ui.Line { self:permissions(), self:percentage(), self:position() }
+	local right = ui.Line {
+		self:yanked(),
+		self:elsewhere(),
+		self:ignored(),
+		self:stale(),
+		self:encoding(),
+		self:video(),
+		self:permissions(),
+		self:percentage(),
+		self:position(),
+	}
 	local progress = self:progress(area, right:width())
 	return {
 		ui.Paragraph(area, { left }),
@@ -28,9 +28,11 @@ impl<'a, 'b> Active<'a, 'b> {
 			reg.add_field_method_get("sort_sensitive", |_, me| Ok(me.sort_sensitive));
 			reg.add_field_method_get("sort_reverse", |_, me| Ok(me.sort_reverse));
 			reg.add_field_method_get("sort_dir_first", |_, me| Ok(me.sort_dir_first));
+			reg.add_field_method_get("sort_translit", |_, me| Ok(me.sort_translit));
 
 			reg.add_field_method_get("linemode", |_, me| Ok(me.linemode.to_owned()));
 			reg.add_field_method_get("show_hidden", |_, me| Ok(me.show_hidden));
+			reg.add_field_method_get("show_ignored", |_, me| Ok(me.show_ignored));
 		})?;
 
 		LUA.register_userdata_type::<yazi_core::tab::Folder>(|reg| {
@@ -45,6 +47,8 @@ impl<'a, 'b> Active<'a, 'b> {
 
 		LUA.register_userdata_type::<yazi_core::preview::Preview>(|reg| {
 			reg.add_field_function_get("folder", |_, me| me.named_user_value::<Value>("folder"));
+			reg.add_field_function_get("encoding", |_, me| me.named_user_value::<Value>("encoding"));
+			reg.add_field_function_get("video", |_, me| me.named_user_value::<Value>("video"));
 		})?;
 
 		Ok(())
@@ -64,6 +68,15 @@ impl<'a, 'b> Active<'a, 'b> {
 		)?;
 		ud.set_named_user_value("current", self.folder(&self.inner.current, None)?)?;
 		ud.set_named_user_value("preview", self.preview(self.inner)?)?;
+		ud.set_named_user_value(
+			"other",
+			self.cx.manager.tabs.other().and_then(|t| self.folder(&t.current, None).ok()),
+		)?;
+		ud.set_named_user_value("yanked", self.cx.manager.yanked().1.len())?;
+		ud.set_named_user_value("is_cut", self.cx.manager.yanked().0)?;
+		ud.set_named_user_value("elsewhere", self.inner.selected_elsewhere().count())?;
+		ud.set_named_user_value("stale", self.cx.manager.stale())?;
+		ud.set_named_user_value("watch_exhausted", self.cx.manager.watch_exhausted())?;
 
 		Ok(ud)
 	}
@@ -128,6 +141,24 @@ impl<'a, 'b> Active<'a, 'b> {
 				.and_then(|l| tab.history(&l.url))
 				.and_then(|f| self.folder(f, Some((f.offset, MANAGER.layout.preview_height()))).ok()),
 		)?;
+		ud.set_named_user_value(
+			"encoding",
+			inner
+				.lock
+				.as_ref()
+				.filter(|l| tab.current.hovered().is_some_and(|h| h.url == l.url))
+				.and_then(|l| l.encoding)
+				.map(|e| e.to_string()),
+		)?;
+		ud.set_named_user_value(
+			"video",
+			inner
+				.lock
+				.as_ref()
+				.filter(|l| tab.current.hovered().is_some_and(|h| h.url == l.url))
+				.and_then(|l| l.video)
+				.map(|v| v.to_string()),
+		)?;
 
 		Ok(ud)
 	}
@@ -26,6 +26,14 @@ pub struct Files;
 impl Files {
 	pub(crate) fn init() -> mlua::Result<()> {
 		LUA.register_userdata_type::<yazi_core::files::Files>(|reg| {
+			reg.add_field_method_get("is_loading", |_, me| Ok(me.is_loading()));
+			reg.add_field_method_get("total_size", |_, me| {
+				let size = |f: &yazi_core::files::File| match f.is_dir() {
+					true => me.sizes.get(&f.url).copied().unwrap_or(0),
+					false => f.len,
+				};
+				Ok(me.iter().map(size).sum::<u64>())
+			});
 			reg.add_meta_method(MetaMethod::Len, |_, me, ()| Ok(me.len()));
 
 			reg.add_meta_function(MetaMethod::Pairs, |lua, me: AnyUserData| {
@@ -81,6 +89,22 @@ impl Files {
 					None::<String>,
 				)
 			});
+			reg.add_method("owner", |_, me, ()| {
+				Ok(
+					#[cfg(unix)]
+					yazi_shared::user_name(me.uid),
+					#[cfg(windows)]
+					None::<String>,
+				)
+			});
+			reg.add_method("group", |_, me, ()| {
+				Ok(
+					#[cfg(unix)]
+					yazi_shared::group_name(me.gid),
+					#[cfg(windows)]
+					None::<String>,
+				)
+			});
 
 			// Extension
 			reg.add_field_method_get("name", |_, me| {
@@ -95,6 +119,11 @@ impl Files {
 				let folder = me.named_user_value::<UserDataRef<yazi_core::tab::Folder>>("folder")?;
 				Ok(folder.files.sizes.get(&file.url).copied())
 			});
+			reg.add_function("git", |_, me: AnyUserData| {
+				let folder = me.named_user_value::<UserDataRef<yazi_core::tab::Folder>>("folder")?;
+				let file = me.borrow::<yazi_core::files::File>()?;
+				Ok(folder.files.git.get(&file.url).map(|s| s.as_str()))
+			});
 			reg.add_function("mime", |_, me: AnyUserData| {
 				let manager = me.named_user_value::<UserDataRef<yazi_core::manager::Manager>>("manager")?;
 				let file = me.borrow::<yazi_core::files::File>()?;
@@ -111,14 +140,16 @@ impl Files {
 				p.next_back();
 				Ok(Some(p.as_path().to_string_lossy().to_string()))
 			});
-			reg.add_method("icon", |_, me, ()| {
-				Ok(
-					THEME
-						.icons
-						.iter()
-						.find(|&x| x.name.match_path(&me.url, Some(me.is_dir())))
-						.map(|x| x.display.to_string()),
-				)
+			reg.add_function("icon", |_, me: AnyUserData| {
+				let manager = me.named_user_value::<UserDataRef<yazi_core::manager::Manager>>("manager")?;
+				let file = me.borrow::<yazi_core::files::File>()?;
+				let mime = manager.mimetype.get(&file.url);
+
+				let Some(icon) = THEME.icons.iter().find(|&x| x.matches(&file.url, mime, file.is_dir()))
+				else {
+					return Ok((None, None));
+				};
+				Ok((Some(icon.display.to_string()), icon.style.map(Style::from)))
 			});
 			reg.add_function("style", |_, me: AnyUserData| {
 				let manager = me.named_user_value::<UserDataRef<yazi_core::manager::Manager>>("manager")?;
@@ -128,7 +159,7 @@ impl Files {
 					THEME
 						.filetypes
 						.iter()
-						.find(|&x| x.matches(&file.url, mime, file.is_dir()))
+						.find(|&x| x.matches(&file.url, mime, &file.cha))
 						.map(|x| Style::from(x.style)),
 				)
 			});
@@ -140,9 +171,10 @@ impl Files {
 			reg.add_function("is_yanked", |_, me: AnyUserData| {
 				let manager = me.named_user_value::<UserDataRef<yazi_core::manager::Manager>>("manager")?;
 				let file = me.borrow::<yazi_core::files::File>()?;
-				Ok(if !manager.yanked.1.contains(&file.url) {
+				let yanked = manager.yanked();
+				Ok(if !yanked.1.contains(&file.url) {
 					0u8
-				} else if manager.yanked.0 {
+				} else if yanked.0 {
 					2u8
 				} else {
 					1u8
@@ -154,7 +186,7 @@ impl Files {
 				let file = me.borrow::<yazi_core::files::File>()?;
 
 				let selected = folder.files.is_selected(&file.url);
-				Ok(if !manager.active().mode.is_visual() {
+				Ok(if !manager.active().mode.is_visual() || folder.cwd != *manager.cwd() {
 					selected
 				} else {
 					let idx: usize = me.named_user_value("idx")?;
@@ -12,6 +12,7 @@ impl<'a, 'b> Tabs<'a, 'b> {
 	pub(crate) fn init() -> mlua::Result<()> {
 		LUA.register_userdata_type::<yazi_core::manager::Tabs>(|reg| {
 			reg.add_field_method_get("idx", |_, me| Ok(me.idx));
+			reg.add_field_method_get("other", |_, me| Ok(me.other));
 			reg.add_meta_method(MetaMethod::Len, |_, me, ()| Ok(me.len()));
 			reg.add_meta_function(MetaMethod::Index, |_, (me, index): (AnyUserData, usize)| {
 				let items = me.named_user_value::<Vec<AnyUserData>>("items")?;
@@ -32,11 +33,23 @@ impl<'a, 'b> Tabs<'a, 'b> {
 				)
 			});
 
+			reg.add_method("has_cut", |_, me, ()| Ok(me.yanked.0 && !me.yanked.1.is_empty()));
+
 			reg.add_field_function_get("mode", |_, me| me.named_user_value::<AnyUserData>("mode"));
 			reg.add_field_function_get("conf", |_, me| me.named_user_value::<AnyUserData>("conf"));
 			reg.add_field_function_get("parent", |_, me| me.named_user_value::<Value>("parent"));
 			reg.add_field_function_get("current", |_, me| me.named_user_value::<AnyUserData>("current"));
 			reg.add_field_function_get("preview", |_, me| me.named_user_value::<AnyUserData>("preview"));
+
+			// Only set for the active tab
+			reg.add_field_function_get("yanked", |_, me| me.named_user_value::<Value>("yanked"));
+			reg.add_field_function_get("is_cut", |_, me| me.named_user_value::<Value>("is_cut"));
+			reg.add_field_function_get("other", |_, me| me.named_user_value::<Value>("other"));
+			reg.add_field_function_get("elsewhere", |_, me| me.named_user_value::<Value>("elsewhere"));
+			reg.add_field_function_get("stale", |_, me| me.named_user_value::<Value>("stale"));
+			reg.add_field_function_get("watch_exhausted", |_, me| {
+				me.named_user_value::<Value>("watch_exhausted")
+			});
 		})?;
 
 		Ok(())
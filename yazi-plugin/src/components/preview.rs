@@ -28,11 +28,14 @@ impl<'a> Widget for Preview<'a> {
 			PreviewData::Folder => {
 				Folder::preview(self.cx).render(area, buf);
 			}
-			PreviewData::Text(s) => {
+			PreviewData::Text(s) | PreviewData::Media(s) => {
 				let p = Paragraph::new(s.as_bytes().into_text().unwrap());
 				p.render(area, buf);
 			}
 			PreviewData::Image => {}
+			PreviewData::Slow => {
+				Paragraph::new("Slow filesystem, `preview --force` to load").render(area, buf);
+			}
 		}
 	}
 }
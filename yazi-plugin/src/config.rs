@@ -1,4 +1,4 @@
-use mlua::{LuaSerdeExt, SerializeOptions, Table};
+use mlua::{LuaSerdeExt, SerializeOptions, Table, Value};
 use yazi_config::{MANAGER, THEME};
 
 use crate::{layout::Rect, GLOBALS, LUA};
@@ -35,6 +35,25 @@ impl Config {
 				.set("folder_rect", LUA.create_function(|_, ()| Ok(Rect(MANAGER.layout.folder_rect())))?)?;
 			layout
 				.set("folder_height", LUA.create_function(|_, ()| Ok(MANAGER.layout.folder_height()))?)?;
+
+			// The ratios can be resized at runtime, so they're read on each access
+			for key in ["parent", "current", "preview", "all"] {
+				layout.raw_remove(key)?;
+			}
+			let meta = LUA.create_table()?;
+			meta.set(
+				"__index",
+				LUA.create_function(|_, (_, key): (Table, mlua::String)| {
+					Ok(match key.as_bytes() {
+						b"parent" => Value::Integer(MANAGER.layout.parent() as i64),
+						b"current" => Value::Integer(MANAGER.layout.current() as i64),
+						b"preview" => Value::Integer(MANAGER.layout.preview() as i64),
+						b"all" => Value::Integer(MANAGER.layout.all() as i64),
+						_ => Value::Nil,
+					})
+				})?,
+			)?;
+			layout.set_metatable(Some(meta));
 		}
 
 		GLOBALS.set("MANAGER", manager)
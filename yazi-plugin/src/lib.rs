@@ -5,9 +5,11 @@ pub mod components;
 mod config;
 pub mod layout;
 mod plugin;
+mod registry;
 mod scope;
 mod utils;
 
 use config::*;
 pub use plugin::*;
+pub use registry::{command, fetch, preview};
 pub use scope::*;
@@ -3,7 +3,7 @@ use mlua::{Lua, Table};
 use yazi_config::PLUGINS;
 use yazi_shared::RoCell;
 
-use crate::{bindings, components, layout, utils};
+use crate::{bindings, components, layout, registry, utils};
 
 pub(crate) static LUA: RoCell<Lua> = RoCell::new();
 pub(crate) static GLOBALS: RoCell<Table> = RoCell::new();
@@ -27,6 +27,7 @@ pub fn init() {
 		LUA.init(lua);
 		GLOBALS.init(LUA.globals());
 		utils::init()?;
+		registry::init()?;
 		bindings::init()?;
 		components::init()?;
 
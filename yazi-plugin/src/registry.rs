@@ -0,0 +1,114 @@
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Result};
+use mlua::{Function, Table, Value};
+use tracing::error;
+use yazi_config::keymap::{Exec, KeymapLayer};
+use yazi_core::{emit, Ctx};
+
+use crate::{bindings::{File, Url}, scope, GLOBALS, LUA};
+
+const PREVIEWERS: &str = "yazi_previewers";
+const COMMANDS: &str = "yazi_commands";
+const FETCHERS: &str = "yazi_fetchers";
+
+pub(super) fn init() -> mlua::Result<()> {
+	LUA.set_named_registry_value(PREVIEWERS, LUA.create_table()?)?;
+	LUA.set_named_registry_value(COMMANDS, LUA.create_table()?)?;
+	LUA.set_named_registry_value(FETCHERS, LUA.create_table()?)?;
+
+	let utils: Table = GLOBALS.get("utils")?;
+
+	utils.set(
+		"previewer",
+		LUA.create_function(|lua, (name, f): (String, Function)| {
+			lua.named_registry_value::<Table>(PREVIEWERS)?.set(name, f)
+		})?,
+	)?;
+
+	utils.set(
+		"command",
+		LUA.create_function(|lua, (name, f): (String, Function)| {
+			lua.named_registry_value::<Table>(COMMANDS)?.set(name, f)
+		})?,
+	)?;
+
+	utils.set(
+		"fetcher",
+		LUA.create_function(|lua, f: Function| {
+			let fetchers = lua.named_registry_value::<Table>(FETCHERS)?;
+			fetchers.raw_set(fetchers.raw_len() + 1, f)
+		})?,
+	)?;
+
+	utils.set(
+		"emit",
+		LUA.create_function(|_, s: String| {
+			let execs = Exec::chain(&s).map_err(mlua::Error::external)?;
+			emit!(Call(execs, KeymapLayer::Manager));
+			Ok(())
+		})?,
+	)?;
+
+	Ok(())
+}
+
+// Run the previewer registered under the name, which returns the text to show
+pub fn preview(name: &str, file: &yazi_core::files::File, mime: &str) -> Result<String> {
+	let previewers = LUA.named_registry_value::<Table>(PREVIEWERS)?;
+	let Some(f) = previewers.get::<_, Option<Function>>(name)? else {
+		bail!("no such previewer: {name}");
+	};
+
+	Ok(f.call::<_, Option<String>>((File::from(file), mime))?.unwrap_or_default())
+}
+
+// Run the command named by the first arg of `plugin`, with the hovered file,
+// and a table of the rest of the args along with the named ones
+pub fn command(cx: &Ctx, exec: &Exec) -> Result<()> {
+	let Some(name) = exec.args.first() else {
+		bail!("no command given");
+	};
+
+	let commands = LUA.named_registry_value::<Table>(COMMANDS)?;
+	let Some(f) = commands.get::<_, Option<Function>>(name.as_str())? else {
+		bail!("no such command: {name}");
+	};
+
+	let args = LUA.create_sequence_from(exec.args.iter().skip(1).cloned())?;
+	for (k, v) in &exec.named {
+		args.raw_set(k.as_str(), v.as_str())?;
+	}
+
+	let mut result = Ok(());
+	scope(cx, |_| {
+		let file = cx.manager.hovered().map(File::from);
+		result = f.call::<_, ()>((file, args)).map_err(Into::into);
+	});
+	result
+}
+
+// Pass the mimetypes through the fetchers in turn, the first one returning a
+// mimetype for a file wins
+pub fn fetch(mut mimes: BTreeMap<yazi_shared::Url, String>) -> BTreeMap<yazi_shared::Url, String> {
+	let Ok(fetchers) = LUA.named_registry_value::<Table>(FETCHERS) else {
+		return mimes;
+	};
+	if fetchers.raw_len() == 0 {
+		return mimes;
+	}
+
+	for (url, mime) in &mut mimes {
+		for f in fetchers.clone().sequence_values::<Function>().flatten() {
+			match f.call::<_, Value>((Url::from(url), mime.as_str())) {
+				Ok(Value::String(s)) => {
+					*mime = s.to_string_lossy().into_owned();
+					break;
+				}
+				Ok(_) => {}
+				Err(e) => error!("fetcher failed for {url:?}: {e}"),
+			}
+		}
+	}
+	mimes
+}
@@ -27,6 +27,10 @@ pub struct Cha {
 	pub modified:    Option<SystemTime>,
 	#[cfg(unix)]
 	pub permissions: u32,
+	#[cfg(unix)]
+	pub uid:         u32,
+	#[cfg(unix)]
+	pub gid:         u32,
 }
 
 impl From<Metadata> for Cha {
@@ -66,6 +70,16 @@ impl From<Metadata> for Cha {
 				use std::os::unix::prelude::PermissionsExt;
 				m.permissions().mode()
 			},
+			#[cfg(unix)]
+			uid:                      {
+				use std::os::unix::fs::MetadataExt;
+				m.uid()
+			},
+			#[cfg(unix)]
+			gid:                      {
+				use std::os::unix::fs::MetadataExt;
+				m.gid()
+			},
 		}
 	}
 }
@@ -91,6 +105,10 @@ impl Cha {
 	#[inline]
 	pub fn is_bad_link(self) -> bool { self.meta.contains(ChaMeta::BAD_LINK) }
 
+	#[cfg(unix)]
+	#[inline]
+	pub fn is_exec(self) -> bool { !self.is_dir() && self.permissions & 0o111 != 0 }
+
 	#[cfg(unix)]
 	#[inline]
 	pub fn is_block_device(self) -> bool { self.meta.contains(ChaMeta::BLOCK_DEVICE) }
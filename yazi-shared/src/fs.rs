@@ -1,4 +1,4 @@
-use std::{collections::VecDeque, path::{Path, PathBuf}};
+use std::{collections::VecDeque, path::{Path, PathBuf}, sync::{atomic::{AtomicU64, Ordering}, Arc}};
 
 use anyhow::Result;
 use tokio::{fs, io, select, sync::{mpsc, oneshot}, time};
@@ -35,18 +35,28 @@ pub async fn calculate_size(path: &Path) -> u64 {
 	total
 }
 
-pub fn copy_with_progress(from: &Path, to: &Path) -> mpsc::Receiver<Result<u64, io::Error>> {
+// Copy a file and report the progress, with `parallel` concurrent ranged
+// writers if greater than 1, which is only supported on Unix.
+pub fn copy_with_progress(
+	from: &Path,
+	to: &Path,
+	parallel: usize,
+) -> mpsc::Receiver<Result<u64, io::Error>> {
 	let (tx, rx) = mpsc::channel(1);
 	let (tick_tx, mut tick_rx) = oneshot::channel();
+	let written = (parallel > 1).then(|| Arc::new(AtomicU64::new(0)));
 
 	tokio::spawn({
 		let (from, to) = (from.to_path_buf(), to.to_path_buf());
+		let written = written.clone();
 
 		async move {
-			_ = match fs::copy(from, to).await {
-				Ok(len) => tick_tx.send(Ok(len)),
-				Err(e) => tick_tx.send(Err(e)),
+			let res = match written {
+				#[cfg(unix)]
+				Some(written) => copy_ranged(from, to, parallel, written).await,
+				_ => fs::copy(from, to).await,
 			};
+			_ = tick_tx.send(res);
 		}
 	});
 
@@ -79,7 +89,11 @@ pub fn copy_with_progress(from: &Path, to: &Path) -> mpsc::Receiver<Result<u64,
 					None => {}
 				}
 
-				let len = fs::symlink_metadata(&to).await.map(|m| m.len()).unwrap_or(0);
+				// The destination is allocated up front by the ranged writers
+				let len = match &written {
+					Some(n) => n.load(Ordering::Relaxed),
+					None => fs::symlink_metadata(&to).await.map(|m| m.len()).unwrap_or(0),
+				};
 				if len > last {
 					tx.send(Ok(len - last)).await.ok();
 					last = len;
@@ -91,6 +105,61 @@ pub fn copy_with_progress(from: &Path, to: &Path) -> mpsc::Receiver<Result<u64,
 	rx
 }
 
+#[cfg(unix)]
+async fn copy_ranged(
+	from: PathBuf,
+	to: PathBuf,
+	parallel: usize,
+	written: Arc<AtomicU64>,
+) -> io::Result<u64> {
+	use std::{fs::{File, OpenOptions}, os::unix::fs::FileExt, thread};
+
+	const BUF_SIZE: usize = 1 << 20;
+
+	tokio::task::spawn_blocking(move || {
+		let src = File::open(&from)?;
+		let meta = src.metadata()?;
+		let len = meta.len();
+
+		let dest = OpenOptions::new().write(true).create(true).truncate(true).open(&to)?;
+		dest.set_len(len)?;
+
+		let chunk = len.div_ceil(parallel as u64).max(BUF_SIZE as u64);
+		thread::scope(|s| {
+			let handles: Vec<_> = (0..len)
+				.step_by(chunk as usize)
+				.map(|start| {
+					let (src, dest, written) = (&src, &dest, &written);
+					s.spawn(move || -> io::Result<()> {
+						let end = (start + chunk).min(len);
+						let mut buf = vec![0; BUF_SIZE];
+						let mut pos = start;
+						while pos < end {
+							let want = BUF_SIZE.min((end - pos) as usize);
+							let n = src.read_at(&mut buf[..want], pos)?;
+							if n == 0 {
+								return Err(io::ErrorKind::UnexpectedEof.into());
+							}
+							dest.write_all_at(&buf[..n], pos)?;
+							written.fetch_add(n as u64, Ordering::Relaxed);
+							pos += n as u64;
+						}
+						Ok(())
+					})
+				})
+				.collect();
+
+			handles
+				.into_iter()
+				.try_for_each(|h| h.join().unwrap_or_else(|_| Err(io::ErrorKind::Other.into())))
+		})?;
+
+		dest.set_permissions(meta.permissions())?;
+		Ok(len)
+	})
+	.await?
+}
+
 // Convert a file mode to a string representation
 #[cfg(unix)]
 #[allow(clippy::collapsible_else_if)]
@@ -141,6 +210,195 @@ pub fn permissions(mode: u32) -> String {
 	s
 }
 
+// Call one of the reentrant `getpw*_r` or `getgr*_r`, with a buffer grown as
+// long as it's too small for the entry, and map the entry found with `f`
+#[cfg(unix)]
+fn nss_entry<T, R>(
+	get: impl Fn(*mut T, *mut libc::c_char, usize, *mut *mut T) -> libc::c_int,
+	f: impl FnOnce(&T) -> R,
+) -> Option<R> {
+	let mut buf = vec![0; 1024];
+	let mut entry = std::mem::MaybeUninit::<T>::uninit();
+	let mut result = std::ptr::null_mut();
+
+	loop {
+		let r = get(entry.as_mut_ptr(), buf.as_mut_ptr(), buf.len(), &mut result);
+		if r == libc::ERANGE && buf.len() < 1 << 20 {
+			buf.resize(buf.len() * 2, 0);
+			continue;
+		}
+		if r != 0 || result.is_null() {
+			return None;
+		}
+		return Some(f(unsafe { &*result }));
+	}
+}
+
+// The names are looked up for every file on every render, and each lookup can
+// be a round trip to LDAP and alike, so they're cached for the whole session
+#[cfg(unix)]
+type Names = parking_lot::Mutex<Option<std::collections::HashMap<u32, Option<String>>>>;
+#[cfg(unix)]
+static USER_NAMES: Names = Names::new(None);
+#[cfg(unix)]
+static GROUP_NAMES: Names = Names::new(None);
+
+// Get the name of the user with the given uid
+#[cfg(unix)]
+pub fn user_name(uid: u32) -> Option<String> {
+	if let Some(name) = USER_NAMES.lock().as_ref().and_then(|m| m.get(&uid)) {
+		return name.clone();
+	}
+
+	let name = nss_entry(
+		|pwd, buf, len, result| unsafe { libc::getpwuid_r(uid, pwd, buf, len, result) },
+		|pwd: &libc::passwd| {
+			unsafe { std::ffi::CStr::from_ptr(pwd.pw_name) }.to_string_lossy().into_owned()
+		},
+	);
+	USER_NAMES.lock().get_or_insert_with(Default::default).insert(uid, name.clone());
+	name
+}
+
+// Get the name of the group with the given gid
+#[cfg(unix)]
+pub fn group_name(gid: u32) -> Option<String> {
+	if let Some(name) = GROUP_NAMES.lock().as_ref().and_then(|m| m.get(&gid)) {
+		return name.clone();
+	}
+
+	let name = nss_entry(
+		|grp, buf, len, result| unsafe { libc::getgrgid_r(gid, grp, buf, len, result) },
+		|grp: &libc::group| {
+			unsafe { std::ffi::CStr::from_ptr(grp.gr_name) }.to_string_lossy().into_owned()
+		},
+	);
+	GROUP_NAMES.lock().get_or_insert_with(Default::default).insert(gid, name.clone());
+	name
+}
+
+// Get the uid of the user with the given name, or the number itself
+#[cfg(unix)]
+pub fn user_id(name: &str) -> Option<u32> {
+	if let Ok(uid) = name.parse() {
+		return Some(uid);
+	}
+
+	let name = std::ffi::CString::new(name).ok()?;
+	nss_entry(
+		|pwd, buf, len, result| unsafe { libc::getpwnam_r(name.as_ptr(), pwd, buf, len, result) },
+		|pwd: &libc::passwd| pwd.pw_uid,
+	)
+}
+
+// Get the gid of the group with the given name, or the number itself
+#[cfg(unix)]
+pub fn group_id(name: &str) -> Option<u32> {
+	if let Ok(gid) = name.parse() {
+		return Some(gid);
+	}
+
+	let name = std::ffi::CString::new(name).ok()?;
+	nss_entry(
+		|grp, buf, len, result| unsafe { libc::getgrnam_r(name.as_ptr(), grp, buf, len, result) },
+		|grp: &libc::group| grp.gr_gid,
+	)
+}
+
+// Get the names of all the users known to the system, including those from NSS
+#[cfg(unix)]
+pub fn user_names() -> Vec<String> {
+	let mut names = Vec::new();
+	unsafe {
+		libc::setpwent();
+		loop {
+			let pwd = libc::getpwent();
+			if pwd.is_null() {
+				break;
+			}
+			names.push(std::ffi::CStr::from_ptr((*pwd).pw_name).to_string_lossy().into_owned());
+		}
+		libc::endpwent();
+	}
+	names
+}
+
+// Get the names of all the groups known to the system, including those from NSS
+#[cfg(unix)]
+pub fn group_names() -> Vec<String> {
+	let mut names = Vec::new();
+	unsafe {
+		libc::setgrent();
+		loop {
+			let grp = libc::getgrent();
+			if grp.is_null() {
+				break;
+			}
+			names.push(std::ffi::CStr::from_ptr((*grp).gr_name).to_string_lossy().into_owned());
+		}
+		libc::endgrent();
+	}
+	names
+}
+
+// Get the umask of the process, without changing it in the meantime on Linux
+#[cfg(unix)]
+pub fn umask() -> u32 {
+	#[cfg(target_os = "linux")]
+	{
+		let status = std::fs::read_to_string("/proc/self/status").unwrap_or_default();
+		let mask = status.lines().find_map(|l| l.strip_prefix("Umask:"));
+		if let Some(m) = mask.and_then(|m| u32::from_str_radix(m.trim(), 8).ok()) {
+			return m;
+		}
+	}
+
+	unsafe {
+		let m = libc::umask(0o022);
+		libc::umask(m);
+		m as u32
+	}
+}
+
+// Get the filesystem type of `path`, e.g. "ext4", "cifs" or "exfat"
+#[cfg(target_os = "linux")]
+pub fn fs_type(path: &Path) -> Option<String> {
+	let path = std::fs::canonicalize(path).ok()?;
+	let mounts = std::fs::read_to_string("/proc/self/mounts").ok()?;
+
+	let mut best: Option<(PathBuf, &str)> = None;
+	for line in mounts.lines() {
+		let mut it = line.split(' ').skip(1);
+		let (Some(point), Some(kind)) = (it.next(), it.next()) else { continue };
+
+		let point = PathBuf::from(point.replace("\\040", " ").replace("\\011", "\t"));
+		// The deepest mount point wins, or the last one if mounted over
+		let depth = best.as_ref().map_or(0, |(p, _)| p.as_os_str().len());
+		if path.starts_with(&point) && point.as_os_str().len() >= depth {
+			best = Some((point, kind));
+		}
+	}
+	best.map(|(_, kind)| kind.to_owned())
+}
+
+#[cfg(target_os = "macos")]
+pub fn fs_type(path: &Path) -> Option<String> {
+	use std::os::unix::ffi::OsStrExt;
+
+	let path = std::ffi::CString::new(path.as_os_str().as_bytes()).ok()?;
+	let mut stat = std::mem::MaybeUninit::<libc::statfs>::uninit();
+	unsafe {
+		if libc::statfs(path.as_ptr(), stat.as_mut_ptr()) != 0 {
+			return None;
+		}
+		let name = std::ffi::CStr::from_ptr(stat.assume_init().f_fstypename.as_ptr());
+		Some(name.to_string_lossy().into_owned())
+	}
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+pub fn fs_type(_: &Path) -> Option<String> { None }
+
 // Find the max common root of a list of files
 // e.g. /a/b/c, /a/b/d       -> /a/b
 //      /aa/bb/cc, /aa/dd/ee -> /aa
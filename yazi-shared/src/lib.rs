@@ -14,7 +14,9 @@ mod ro_cell;
 mod term;
 mod throttle;
 mod time;
+mod translit;
 mod url;
+mod xattr;
 
 pub use cha::*;
 pub use chars::*;
@@ -30,4 +32,6 @@ pub use ro_cell::*;
 pub use term::*;
 pub use throttle::*;
 pub use time::*;
+pub use translit::*;
 pub use url::*;
+pub use xattr::*;
@@ -1,6 +1,6 @@
 pub const MIME_DIR: &str = "inode/directory";
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum MimeKind {
 	Empty,
 
@@ -8,9 +8,13 @@ pub enum MimeKind {
 
 	Image,
 	Video,
+	Audio,
 
+	Font,
 	JSON,
 	PDF,
+	SQLite,
+	Table,
 	Text,
 
 	Others,
@@ -18,9 +22,13 @@ pub enum MimeKind {
 
 impl MimeKind {
 	pub fn new(s: &str) -> Self {
-		if s.starts_with("text/")
+		if s == "text/csv" || s == "text/tab-separated-values" || s == "application/csv" {
+			Self::Table
+		} else if s.starts_with("text/")
 			|| s.ends_with("/xml")
 			|| s.ends_with("/javascript")
+			|| s.ends_with("/yaml")
+			|| s.ends_with("/x-yaml")
 			|| s.ends_with("/x-wine-extension-ini")
 		{
 			Self::Text
@@ -28,12 +36,22 @@ impl MimeKind {
 			Self::Image
 		} else if s.starts_with("video/") {
 			Self::Video
+		} else if s.starts_with("audio/") {
+			Self::Audio
+		} else if s.starts_with("font/")
+			|| s == "application/vnd.ms-opentype"
+			|| s == "application/x-font-ttf"
+			|| s == "application/font-woff"
+		{
+			Self::Font
 		} else if s == "inode/x-empty" {
 			Self::Empty
 		} else if s == "application/json" {
 			Self::JSON
 		} else if s == "application/pdf" {
 			Self::PDF
+		} else if s == "application/vnd.sqlite3" || s == "application/x-sqlite3" {
+			Self::SQLite
 		} else if s == "application/zip"
 			|| s == "application/gzip"
 			|| s == "application/x-tar"
@@ -60,6 +78,130 @@ impl MimeKind {
 	}
 
 	pub fn show_as_image(&self) -> bool {
-		matches!(self, MimeKind::Image | MimeKind::Video | MimeKind::PDF)
+		matches!(
+			self,
+			MimeKind::Image | MimeKind::Video | MimeKind::Audio | MimeKind::Font | MimeKind::PDF
+		)
+	}
+}
+
+#[rustfmt::skip]
+const MAGIC: &[(usize, &[u8], &str)] = &[
+	(0, b"\x89PNG\r\n\x1a\n", "image/png"),
+	(0, b"\xff\xd8\xff", "image/jpeg"),
+	(0, b"GIF87a", "image/gif"),
+	(0, b"GIF89a", "image/gif"),
+	(0, b"II*\0", "image/tiff"),
+	(0, b"MM\0*", "image/tiff"),
+	(4, b"ftypavif", "image/avif"),
+	(4, b"ftypheic", "image/heic"),
+	(4, b"ftypheix", "image/heic"),
+	(4, b"ftypmif1", "image/heic"),
+	(4, b"ftypqt  ", "video/quicktime"),
+	(4, b"ftypM4A ", "audio/x-m4a"),
+	(4, b"ftypM4V ", "video/mp4"),
+	(4, b"ftypisom", "video/mp4"),
+	(4, b"ftypiso2", "video/mp4"),
+	(4, b"ftypmp41", "video/mp4"),
+	(4, b"ftypmp42", "video/mp4"),
+	(4, b"ftypavc1", "video/mp4"),
+	(4, b"ftypdash", "video/mp4"),
+	(4, b"ftyp3gp4", "video/3gpp"),
+	(4, b"ftyp3gp5", "video/3gpp"),
+	(0, b"fLaC", "audio/flac"),
+	(0, b"OggS", "audio/ogg"),
+	(0, b"ID3", "audio/mpeg"),
+	(0, b"\xff\xfb", "audio/mpeg"),
+	(0, b"\xff\xf3", "audio/mpeg"),
+	(0, b"\xff\xf2", "audio/mpeg"),
+	(0, b"%PDF-", "application/pdf"),
+	(0, b"\x1f\x8b", "application/gzip"),
+	(0, b"BZh", "application/x-bzip2"),
+	(0, b"\xfd7zXZ\0", "application/x-xz"),
+	(0, b"7z\xbc\xaf\x27\x1c", "application/x-7z-compressed"),
+	(0, b"Rar!\x1a\x07", "application/x-rar"),
+	(0, b"\x28\xb5\x2f\xfd", "application/zstd"),
+	(257, b"ustar", "application/x-tar"),
+	(0, b"SQLite format 3\0", "application/vnd.sqlite3"),
+	(0, b"\0asm", "application/wasm"),
+	(0, b"\0\x01\0\0\0", "font/sfnt"),
+	(0, b"OTTO", "font/sfnt"),
+	(0, b"wOFF", "font/woff"),
+	(0, b"wOF2", "font/woff2"),
+];
+
+// Guess the mimetype from the first bytes of a file, and its extension if
+// it's text, without asking `file`. `None` if it's not one of the known types,
+// including the other `ftyp` brands, and text with an extension not listed,
+// where `file` tells more than `text/plain`, e.g. `text/x-rust`.
+pub fn mime_sniff(head: &[u8], ext: Option<&str>) -> Option<&'static str> {
+	let ext = ext.map(|e| e.to_ascii_lowercase());
+	let at = |i: usize, magic: &[u8]| head.get(i..i + magic.len()) == Some(magic);
+
+	if at(0, b"RIFF") {
+		return match head.get(8..12) {
+			Some(b"WEBP") => Some("image/webp"),
+			Some(b"WAVE") => Some("audio/x-wav"),
+			Some(b"AVI ") => Some("video/x-msvideo"),
+			_ => None,
+		};
+	} else if at(0, b"\x1a\x45\xdf\xa3") {
+		let webm = head.windows(4).any(|w| w == b"webm");
+		return Some(if webm { "video/webm" } else { "video/x-matroska" });
+	} else if at(0, b"PK\x03\x04") {
+		// Office documents, e-books and the like are zips too, left to `file`
+		return matches!(ext.as_deref(), None | Some("zip")).then_some("application/zip");
+	} else if at(0, b"BM") && at(6, b"\0\0\0\0") {
+		return Some("image/bmp");
+	}
+
+	MAGIC
+		.iter()
+		.find(|&&(i, magic, _)| at(i, magic))
+		.map(|&(_, _, mime)| mime)
+		.or_else(|| mime_sniff_text(head, ext.as_deref()))
+}
+
+fn mime_sniff_text(head: &[u8], ext: Option<&str>) -> Option<&'static str> {
+	// UTF-8 without any NUL, where the last character may be cut off
+	if head.contains(&0) {
+		return None;
+	}
+	if let Err(e) = std::str::from_utf8(head) {
+		if e.error_len().is_some() {
+			return None;
+		}
+	}
+
+	Some(match ext.unwrap_or_default() {
+		"json" => "application/json",
+		"js" | "mjs" | "cjs" => "application/javascript",
+		"yaml" | "yml" => "application/x-yaml",
+		"xml" => "text/xml",
+		"svg" => "image/svg+xml",
+		"csv" => "text/csv",
+		"tsv" => "text/tab-separated-values",
+		"html" | "htm" => "text/html",
+		"sh" | "bash" | "zsh" => "text/x-shellscript",
+		"txt" => "text/plain",
+		_ => return None,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::mime_sniff;
+
+	#[test]
+	fn test_mime_sniff() {
+		assert_eq!(mime_sniff(b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR", Some("png")), Some("image/png"));
+		assert_eq!(mime_sniff(b"PK\x03\x04\x14\0", Some("zip")), Some("application/zip"));
+		assert_eq!(mime_sniff(b"PK\x03\x04\x14\0", Some("docx")), None);
+		assert_eq!(mime_sniff(b"{\"a\": 1}\n", Some("JSON")), Some("application/json"));
+		assert_eq!(mime_sniff(b"fn main() {}\n", Some("rs")), None);
+		assert_eq!(mime_sniff("caf\u{e9}".as_bytes()[..4].as_ref(), Some("txt")), Some("text/plain"));
+		assert_eq!(mime_sniff(b"\0\0\0\x18ftypmp42", None), Some("video/mp4"));
+		assert_eq!(mime_sniff(b"\0\0\0\x18ftypcrx ", None), None);
+		assert_eq!(mime_sniff(b"\x7fELF\x02\x01\x01\0", None), None);
 	}
 }
@@ -12,7 +12,7 @@ pub fn current_cwd() -> Option<PathBuf> {
 		.or_else(|| env::current_dir().ok())
 }
 
-fn _expand_path(p: &Path) -> PathBuf {
+fn _expand_vars(p: &Path) -> PathBuf {
 	// ${HOME} or $HOME
 	#[cfg(unix)]
 	let re = regex::Regex::new(r"\$(?:\{([^}]+)\}|([a-zA-Z\d_]+))").unwrap();
@@ -36,13 +36,21 @@ fn _expand_path(p: &Path) -> PathBuf {
 
 		return if let Some(p) = home { PathBuf::from(p).join(rest) } else { rest.to_path_buf() };
 	}
+	p.to_path_buf()
+}
 
+fn _expand_path(p: &Path) -> PathBuf {
+	let p = _expand_vars(p);
 	if p.is_absolute() {
-		return p.to_path_buf();
+		return p;
 	}
-	current_cwd().map_or_else(|| p.to_path_buf(), |c| c.join(p))
+	current_cwd().map_or_else(|| p.clone(), |c| c.join(&p))
 }
 
+// Expand the variables and `~`, but leave a relative path relative
+#[inline]
+pub fn expand_vars(p: impl AsRef<Path>) -> PathBuf { _expand_vars(p.as_ref()) }
+
 #[inline]
 pub fn expand_path(p: impl AsRef<Path>) -> PathBuf { _expand_path(p.as_ref()) }
 
@@ -140,14 +148,199 @@ pub fn path_relative_to<'a>(path: &'a Path, root: &Path) -> Cow<'a, Path> {
 	buf.extend(walk_up);
 	buf.extend(p_comps);
 
+	if buf.as_os_str().is_empty() {
+		buf.push(Component::CurDir);
+	}
 	Cow::from(buf)
 }
 
+// Calculate the path of `src` relative to the directory containing `link`.
+//
+// Both parent directories are canonicalized first, so that the result stays
+// correct when either of them is reached through a symlink or a mount point.
+pub async fn relative_link_src(src: &Path, link: &Path) -> std::io::Result<PathBuf> {
+	let src = match (src.parent(), src.file_name()) {
+		(Some(p), Some(n)) => fs::canonicalize(p).await.map_or_else(|_| src.to_owned(), |p| p.join(n)),
+		_ => src.to_owned(),
+	};
+
+	let root = fs::canonicalize(link.parent().unwrap_or(link)).await?;
+	Ok(path_relative_to(&src, &root).into_owned())
+}
+
+// Make a file name portable to Windows, SMB shares and FAT/exFAT, by replacing
+// the characters they reject, dropping the trailing dots and spaces they strip,
+// and renaming the reserved device names. Each component of a nested name
+// (e.g. `a/b.txt` when creating) is handled on its own.
+pub fn sanitize_name(name: &str) -> String {
+	const RESERVED: [&str; 22] = [
+		"CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+		"COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+	];
+
+	let mut out = String::with_capacity(name.len());
+	for part in name.split_inclusive(std::path::is_separator) {
+		let (body, sep) = match part.char_indices().last() {
+			Some((i, c)) if std::path::is_separator(c) => (&part[..i], &part[i..]),
+			_ => (part, ""),
+		};
+		if body.is_empty() || body == "." || body == ".." {
+			out.push_str(part);
+			continue;
+		}
+
+		let mut body: String = body
+			.chars()
+			.map(|c| if c.is_control() || r#"<>:"\|?*"#.contains(c) { '_' } else { c })
+			.collect();
+
+		body.truncate(body.trim_end_matches(['.', ' ']).len());
+		if body.is_empty() {
+			body.push('_');
+		}
+
+		let stem = body.split('.').next().unwrap_or_default();
+		if RESERVED.iter().any(|r| r.eq_ignore_ascii_case(stem.trim_end())) {
+			body.insert(stem.len(), '_');
+		}
+
+		out.push_str(&body);
+		out.push_str(sep);
+	}
+	out
+}
+
+// Split the names separated by spaces, where a quoted one can contain spaces,
+// and expand the braces in each, e.g. `src/{a,b}.rs Makefile` makes three.
+// Backslashes are kept as is, since they're the separator on Windows, and a
+// quote without a closing one is kept too, as in `don't.txt`.
+pub fn expand_names(s: &str) -> Vec<String> {
+	let (mut words, mut word) = (vec![], String::new());
+	let (mut quote, mut depth) = (None, 0usize);
+	for (i, c) in s.char_indices() {
+		match c {
+			'"' | '\'' if quote.is_none() && !s[i + 1..].contains(c) => {
+				// Quoted by the other one, for the later passes to keep it
+				let other = if c == '"' { '\'' } else { '"' };
+				word.extend([other, c, other]);
+				continue;
+			}
+			'"' | '\'' if quote.is_none() => quote = Some(c),
+			_ if quote == Some(c) => quote = None,
+			'{' if quote.is_none() => depth += 1,
+			'}' if quote.is_none() => depth = depth.saturating_sub(1),
+			_ if c.is_whitespace() && quote.is_none() && depth == 0 => {
+				if !word.is_empty() {
+					words.push(std::mem::take(&mut word));
+				}
+				continue;
+			}
+			_ => {}
+		}
+		word.push(c);
+	}
+	if !word.is_empty() {
+		words.push(word);
+	}
+
+	words
+		.iter()
+		.flat_map(|w| expand_braces(w))
+		.map(|w| {
+			let mut quote = None;
+			w.chars()
+				.filter(|&c| match c {
+					'"' | '\'' if quote.is_none() => quote.replace(c).is_some(),
+					_ if quote == Some(c) => quote.take().is_none(),
+					_ => true,
+				})
+				.collect::<String>()
+		})
+		.filter(|w| !w.is_empty())
+		.collect()
+}
+
+// Expand the first brace pair with commas in it, then the rest in the results
+fn expand_braces(s: &str) -> Vec<String> {
+	let b = s.as_bytes();
+	let mut quote = None;
+	for (start, &c) in b.iter().enumerate() {
+		match c {
+			b'"' | b'\'' if quote.is_none() => {
+				quote = Some(c);
+				continue;
+			}
+			_ if quote == Some(c) => {
+				quote = None;
+				continue;
+			}
+			b'{' if quote.is_none() => {}
+			_ => continue,
+		}
+
+		let (mut depth, mut inner_quote, mut commas) = (0, None, vec![]);
+		for (i, &c) in b.iter().enumerate().skip(start) {
+			match c {
+				b'"' | b'\'' if inner_quote.is_none() => inner_quote = Some(c),
+				_ if inner_quote == Some(c) => inner_quote = None,
+				_ if inner_quote.is_some() => {}
+				b'{' => depth += 1,
+				b',' if depth == 1 => commas.push(i),
+				b'}' => {
+					depth -= 1;
+					if depth > 0 {
+						continue;
+					} else if commas.is_empty() {
+						break;
+					}
+
+					let bounds: Vec<_> =
+						[start].into_iter().chain(commas.iter().copied()).chain([i]).collect();
+					return bounds
+						.windows(2)
+						.flat_map(|w| {
+							expand_braces(&format!("{}{}{}", &s[..start], &s[w[0] + 1..w[1]], &s[i + 1..]))
+						})
+						.collect();
+				}
+				_ => {}
+			}
+		}
+	}
+	vec![s.to_owned()]
+}
+
 #[cfg(test)]
 mod tests {
 	use std::{borrow::Cow, path::Path};
 
-	use super::path_relative_to;
+	use super::{expand_names, path_relative_to, sanitize_name};
+
+	#[test]
+	fn test_expand_names() {
+		assert_eq!(expand_names("a.rs"), ["a.rs"]);
+		assert_eq!(expand_names(" a  b/ "), ["a", "b/"]);
+		assert_eq!(expand_names("src/{a,b}.rs Makefile"), ["src/a.rs", "src/b.rs", "Makefile"]);
+		assert_eq!(expand_names("{a,b{1,2}}"), ["a", "b1", "b2"]);
+		assert_eq!(expand_names("{x,y}{1,2}"), ["x1", "x2", "y1", "y2"]);
+		assert_eq!(expand_names("{a, b}"), ["a", " b"]);
+		assert_eq!(expand_names("a{b}c {,.bak}"), ["a{b}c", ".bak"]);
+		assert_eq!(expand_names(r#""my notes.md" '{a,b}' "it's""#), ["my notes.md", "{a,b}", "it's"]);
+		assert_eq!(expand_names("don't.txt"), ["don't.txt"]);
+		assert_eq!(expand_names(r#"5".txt {a,b}.md"#), ["5\".txt", "a.md", "b.md"]);
+	}
+
+	#[test]
+	fn test_sanitize_name() {
+		assert_eq!(sanitize_name("notes.txt"), "notes.txt");
+		assert_eq!(sanitize_name("a:b?c*.txt"), "a_b_c_.txt");
+		assert_eq!(sanitize_name("draft. "), "draft");
+		assert_eq!(sanitize_name("..."), "_");
+		assert_eq!(sanitize_name("con.txt"), "con_.txt");
+		assert_eq!(sanitize_name("console.txt"), "console.txt");
+		assert_eq!(sanitize_name("dir/what?/"), "dir/what_/");
+		assert_eq!(sanitize_name("../a|b"), "../a_b");
+	}
 
 	#[cfg(unix)]
 	#[test]
@@ -161,6 +354,10 @@ mod tests {
 		assert("/a/b/c", "/a/b/d", "../c");
 		assert("/a", "/a/b/c", "../../");
 		assert("/a/a/b", "/a/b/b", "../../a/b");
+		assert("/a/b", "/a/b", ".");
+		assert("/", "/a/b", "../../");
+		assert("/a/b/c/d", "/a/b", "c/d");
+		assert("/mnt/usb/a", "/home/user/b", "../../../mnt/usb/a");
 	}
 
 	#[cfg(windows)]
@@ -175,5 +372,7 @@ mod tests {
 		assert("C:\\a\\b\\c", "C:\\a\\b\\d", "..\\c");
 		assert("C:\\a", "C:\\a\\b\\c", "..\\..\\");
 		assert("C:\\a\\a\\b", "C:\\a\\b\\b", "..\\..\\a\\b");
+		assert("C:\\a\\b", "C:\\a\\b", ".");
+		assert("D:\\a\\b", "C:\\a\\b", "D:\\a\\b");
 	}
 }
@@ -1,20 +1,25 @@
 use std::{io::{stdout, Stdout, Write}, mem, ops::{Deref, DerefMut}};
 
 use anyhow::Result;
-use crossterm::{event::{DisableBracketedPaste, DisableFocusChange, EnableBracketedPaste, EnableFocusChange, KeyboardEnhancementFlags, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags}, execute, queue, terminal::{disable_raw_mode, enable_raw_mode, supports_keyboard_enhancement, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen, WindowSize}};
+use crossterm::{event::{DisableBracketedPaste, DisableFocusChange, DisableMouseCapture, EnableBracketedPaste, EnableFocusChange, EnableMouseCapture, KeyboardEnhancementFlags, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags}, execute, queue, terminal::{disable_raw_mode, enable_raw_mode, supports_keyboard_enhancement, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen, WindowSize}};
 use ratatui::{backend::CrosstermBackend, Terminal};
 
 pub struct Term {
 	inner: Terminal<CrosstermBackend<Stdout>>,
 	csi_u: bool,
+	mouse: bool,
 }
 
 impl Term {
-	pub fn start() -> Result<Self> {
-		let mut term = Self { inner: Terminal::new(CrosstermBackend::new(stdout()))?, csi_u: false };
+	pub fn start(mouse: bool) -> Result<Self> {
+		let mut term =
+			Self { inner: Terminal::new(CrosstermBackend::new(stdout()))?, csi_u: false, mouse };
 
 		enable_raw_mode()?;
 		execute!(stdout(), EnterAlternateScreen, EnableBracketedPaste, EnableFocusChange)?;
+		if mouse {
+			execute!(stdout(), EnableMouseCapture)?;
+		}
 
 		term.csi_u = matches!(supports_keyboard_enhancement(), Ok(true));
 		if term.csi_u {
@@ -36,6 +41,9 @@ impl Term {
 		if self.csi_u {
 			execute!(stdout(), PopKeyboardEnhancementFlags)?;
 		}
+		if self.mouse {
+			execute!(stdout(), DisableMouseCapture)?;
+		}
 
 		execute!(stdout(), DisableFocusChange, DisableBracketedPaste, LeaveAlternateScreen)?;
 
@@ -48,6 +56,7 @@ impl Term {
 		execute!(
 			stdout(),
 			PopKeyboardEnhancementFlags,
+			DisableMouseCapture,
 			DisableFocusChange,
 			DisableBracketedPaste,
 			LeaveAlternateScreen,
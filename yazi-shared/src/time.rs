@@ -3,3 +3,29 @@ use std::time::{self, SystemTime};
 pub fn timestamp_ms() -> u64 {
 	SystemTime::now().duration_since(time::UNIX_EPOCH).unwrap().as_millis() as u64
 }
+
+// Format a time in the local timezone, e.g. "2023-11-05 14:03:27 +0800"
+#[cfg(unix)]
+pub fn local_time(t: SystemTime) -> String {
+	let secs = match t.duration_since(time::UNIX_EPOCH) {
+		Ok(d) => d.as_secs() as libc::time_t,
+		Err(e) => -(e.duration().as_secs() as libc::time_t),
+	};
+
+	let mut buf = [0u8; 64];
+	let mut tm = std::mem::MaybeUninit::<libc::tm>::uninit();
+	unsafe {
+		if libc::localtime_r(&secs, tm.as_mut_ptr()).is_null() {
+			return secs.to_string();
+		}
+
+		let fmt = b"%Y-%m-%d %H:%M:%S %z\0";
+		let n = libc::strftime(buf.as_mut_ptr().cast(), buf.len(), fmt.as_ptr().cast(), tm.as_ptr());
+		String::from_utf8_lossy(&buf[..n]).into_owned()
+	}
+}
+
+#[cfg(not(unix))]
+pub fn local_time(t: SystemTime) -> String {
+	t.duration_since(time::UNIX_EPOCH).map(|d| d.as_secs().to_string()).unwrap_or_default()
+}
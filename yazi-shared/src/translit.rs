@@ -0,0 +1,84 @@
+use std::borrow::Cow;
+
+// The Latin letters with diacritics and ligatures, folded to the ASCII ones
+// they're collated with in most locales, e.g. "é" to "e" and "ß" to "ss".
+fn fold(c: char) -> Option<&'static str> {
+	Some(match c {
+		'À' | 'Á' | 'Â' | 'Ã' | 'Ä' | 'Å' | 'Ā' | 'Ă' | 'Ą' => "A",
+		'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+		'Æ' => "AE",
+		'æ' => "ae",
+		'Ç' | 'Ć' | 'Ĉ' | 'Ċ' | 'Č' => "C",
+		'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => "c",
+		'Ð' | 'Ď' | 'Đ' => "D",
+		'ð' | 'ď' | 'đ' => "d",
+		'È' | 'É' | 'Ê' | 'Ë' | 'Ē' | 'Ĕ' | 'Ė' | 'Ę' | 'Ě' => "E",
+		'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => "e",
+		'Ĝ' | 'Ğ' | 'Ġ' | 'Ģ' => "G",
+		'ĝ' | 'ğ' | 'ġ' | 'ģ' => "g",
+		'Ĥ' | 'Ħ' => "H",
+		'ĥ' | 'ħ' => "h",
+		'Ì' | 'Í' | 'Î' | 'Ï' | 'Ĩ' | 'Ī' | 'Ĭ' | 'Į' | 'İ' => "I",
+		'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => "i",
+		'Ĳ' => "IJ",
+		'ĳ' => "ij",
+		'Ĵ' => "J",
+		'ĵ' => "j",
+		'Ķ' => "K",
+		'ķ' | 'ĸ' => "k",
+		'Ĺ' | 'Ļ' | 'Ľ' | 'Ŀ' | 'Ł' => "L",
+		'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => "l",
+		'Ñ' | 'Ń' | 'Ņ' | 'Ň' | 'Ŋ' => "N",
+		'ñ' | 'ń' | 'ņ' | 'ň' | 'ŉ' | 'ŋ' => "n",
+		'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ö' | 'Ø' | 'Ō' | 'Ŏ' | 'Ő' => "O",
+		'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => "o",
+		'Œ' => "OE",
+		'œ' => "oe",
+		'Ŕ' | 'Ŗ' | 'Ř' => "R",
+		'ŕ' | 'ŗ' | 'ř' => "r",
+		'Ś' | 'Ŝ' | 'Ş' | 'Š' => "S",
+		'ś' | 'ŝ' | 'ş' | 'š' | 'ſ' => "s",
+		'ß' => "ss",
+		'Ţ' | 'Ť' | 'Ŧ' => "T",
+		'ţ' | 'ť' | 'ŧ' => "t",
+		'Þ' => "TH",
+		'þ' => "th",
+		'Ù' | 'Ú' | 'Û' | 'Ü' | 'Ũ' | 'Ū' | 'Ŭ' | 'Ů' | 'Ű' | 'Ų' => "U",
+		'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => "u",
+		'Ŵ' => "W",
+		'ŵ' => "w",
+		'Ý' | 'Ŷ' | 'Ÿ' => "Y",
+		'ý' | 'ÿ' | 'ŷ' => "y",
+		'Ź' | 'Ż' | 'Ž' => "Z",
+		'ź' | 'ż' | 'ž' => "z",
+		_ => return None,
+	})
+}
+
+pub fn translit(s: &str) -> Cow<'_, str> {
+	if s.is_ascii() {
+		return Cow::Borrowed(s);
+	}
+
+	let mut out = String::with_capacity(s.len());
+	for c in s.chars() {
+		match fold(c) {
+			Some(f) => out.push_str(f),
+			None => out.push(c),
+		}
+	}
+	Cow::Owned(out)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_translit() {
+		assert_eq!(translit("file.txt"), "file.txt");
+		assert_eq!(translit("Éclair à la crème"), "Eclair a la creme");
+		assert_eq!(translit("Straße Œuvre"), "Strasse OEuvre");
+		assert_eq!(translit("日本語"), "日本語");
+	}
+}
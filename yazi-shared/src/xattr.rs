@@ -0,0 +1,181 @@
+use std::{ffi::{OsStr, OsString}, io, path::Path};
+
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn cstr(s: impl AsRef<OsStr>) -> io::Result<std::ffi::CString> {
+	use std::os::unix::ffi::OsStrExt;
+	std::ffi::CString::new(s.as_ref().as_bytes())
+		.map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+}
+
+// Call `f` with a buffer of the size it reports for a null buffer first,
+// retrying when the attribute grows in between
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn sized(f: impl Fn(*mut libc::c_void, usize) -> isize) -> io::Result<Vec<u8>> {
+	loop {
+		let n = f(std::ptr::null_mut(), 0);
+		if n < 0 {
+			return Err(io::Error::last_os_error());
+		}
+
+		let mut buf = vec![0u8; n as usize];
+		let m = f(buf.as_mut_ptr().cast(), buf.len());
+		if m >= 0 {
+			buf.truncate(m as usize);
+			return Ok(buf);
+		}
+
+		let err = io::Error::last_os_error();
+		if err.raw_os_error() != Some(libc::ERANGE) {
+			return Err(err);
+		}
+	}
+}
+
+// List the names of the extended attributes of `path`
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+pub fn xattr_list(path: &Path) -> io::Result<Vec<OsString>> {
+	use std::os::unix::ffi::OsStrExt;
+
+	let p = cstr(path)?;
+	let buf = sized(|b, n| unsafe {
+		#[cfg(target_os = "linux")]
+		return libc::listxattr(p.as_ptr(), b.cast(), n);
+		#[cfg(target_os = "macos")]
+		return libc::listxattr(p.as_ptr(), b.cast(), n, 0);
+	})?;
+
+	Ok(
+		buf
+			.split(|&b| b == 0)
+			.filter(|s| !s.is_empty())
+			.map(|s| OsStr::from_bytes(s).to_owned())
+			.collect(),
+	)
+}
+
+// Get the value of the extended attribute `name` of `path`
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+pub fn xattr_get(path: &Path, name: &OsStr) -> io::Result<Vec<u8>> {
+	let (p, n) = (cstr(path)?, cstr(name)?);
+	sized(|b, len| unsafe {
+		#[cfg(target_os = "linux")]
+		return libc::getxattr(p.as_ptr(), n.as_ptr(), b, len);
+		#[cfg(target_os = "macos")]
+		return libc::getxattr(p.as_ptr(), n.as_ptr(), b, len, 0, 0);
+	})
+}
+
+// Set the extended attribute `name` of `path`, creating it if not there
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+pub fn xattr_set(path: &Path, name: &OsStr, value: &[u8]) -> io::Result<()> {
+	let (p, n) = (cstr(path)?, cstr(name)?);
+	let v = value.as_ptr().cast();
+
+	#[cfg(target_os = "linux")]
+	let r = unsafe { libc::setxattr(p.as_ptr(), n.as_ptr(), v, value.len(), 0) };
+	#[cfg(target_os = "macos")]
+	let r = unsafe { libc::setxattr(p.as_ptr(), n.as_ptr(), v, value.len(), 0, 0) };
+	if r < 0 {
+		return Err(io::Error::last_os_error());
+	}
+	Ok(())
+}
+
+// Remove the extended attribute `name` of `path`
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+pub fn xattr_remove(path: &Path, name: &OsStr) -> io::Result<()> {
+	let (p, n) = (cstr(path)?, cstr(name)?);
+
+	#[cfg(target_os = "linux")]
+	let r = unsafe { libc::removexattr(p.as_ptr(), n.as_ptr()) };
+	#[cfg(target_os = "macos")]
+	let r = unsafe { libc::removexattr(p.as_ptr(), n.as_ptr(), 0) };
+	if r < 0 {
+		return Err(io::Error::last_os_error());
+	}
+	Ok(())
+}
+
+// On Windows, the attributes are the alternate data streams of NTFS, which
+// live at `path:name`
+#[cfg(windows)]
+fn stream(path: &Path, name: &OsStr) -> std::path::PathBuf {
+	let mut s = path.as_os_str().to_owned();
+	s.push(":");
+	s.push(name);
+	s.into()
+}
+
+// List the names of the alternate data streams of `path`, leaving out the
+// unnamed main one
+#[cfg(windows)]
+pub fn xattr_list(path: &Path) -> io::Result<Vec<OsString>> {
+	use std::os::windows::ffi::{OsStrExt, OsStringExt};
+
+	use windows_sys::Win32::{Foundation::{ERROR_HANDLE_EOF, INVALID_HANDLE_VALUE}, Storage::FileSystem::{FindClose, FindFirstStreamW, FindNextStreamW, FindStreamInfoStandard, WIN32_FIND_STREAM_DATA}};
+
+	let p: Vec<u16> = path.as_os_str().encode_wide().chain([0]).collect();
+	let mut data: WIN32_FIND_STREAM_DATA = unsafe { std::mem::zeroed() };
+	let ptr = (&mut data as *mut WIN32_FIND_STREAM_DATA).cast();
+
+	let handle = unsafe { FindFirstStreamW(p.as_ptr(), FindStreamInfoStandard, ptr, 0) };
+	if handle == INVALID_HANDLE_VALUE {
+		let err = io::Error::last_os_error();
+		return if err.raw_os_error() == Some(ERROR_HANDLE_EOF as i32) { Ok(vec![]) } else { Err(err) };
+	}
+
+	// Each one is named as `:name:$DATA`
+	let suffix: Vec<u16> = ":$DATA".encode_utf16().collect();
+	let mut names = vec![];
+	loop {
+		let len = data.cStreamName.iter().position(|&c| c == 0).unwrap_or(data.cStreamName.len());
+		let name = data.cStreamName[..len].strip_prefix(&[b':' as u16]);
+		if let Some(name) = name.and_then(|n| n.strip_suffix(&suffix[..])).filter(|n| !n.is_empty()) {
+			names.push(OsString::from_wide(name));
+		}
+
+		if unsafe { FindNextStreamW(handle, ptr) } == 0 {
+			break;
+		}
+	}
+
+	let err = io::Error::last_os_error();
+	unsafe { FindClose(handle) };
+	if err.raw_os_error() != Some(ERROR_HANDLE_EOF as i32) {
+		return Err(err);
+	}
+	Ok(names)
+}
+
+#[cfg(windows)]
+pub fn xattr_get(path: &Path, name: &OsStr) -> io::Result<Vec<u8>> {
+	std::fs::read(stream(path, name))
+}
+
+#[cfg(windows)]
+pub fn xattr_set(path: &Path, name: &OsStr, value: &[u8]) -> io::Result<()> {
+	std::fs::write(stream(path, name), value)
+}
+
+#[cfg(windows)]
+pub fn xattr_remove(path: &Path, name: &OsStr) -> io::Result<()> {
+	std::fs::remove_file(stream(path, name))
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+pub fn xattr_list(_: &Path) -> io::Result<Vec<OsString>> { Err(io::ErrorKind::Unsupported.into()) }
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+pub fn xattr_get(_: &Path, _: &OsStr) -> io::Result<Vec<u8>> {
+	Err(io::ErrorKind::Unsupported.into())
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+pub fn xattr_set(_: &Path, _: &OsStr, _: &[u8]) -> io::Result<()> {
+	Err(io::ErrorKind::Unsupported.into())
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+pub fn xattr_remove(_: &Path, _: &OsStr) -> io::Result<()> {
+	Err(io::ErrorKind::Unsupported.into())
+}